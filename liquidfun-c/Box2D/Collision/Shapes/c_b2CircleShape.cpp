@@ -0,0 +1,33 @@
+#include "c_b2CircleShape.h"
+
+extern "C" {
+
+    b2CircleShape* b2CircleShape_New() {
+        return new b2CircleShape;
+    }
+
+    void b2CircleShape_Delete(b2CircleShape* self) {
+        delete self;
+    }
+
+    b2Shape* b2CircleShape_Upcast(b2CircleShape* self) {
+        return static_cast<b2Shape*>(reinterpret_cast<b2CircleShape*>(self));
+    }
+
+    float32 b2CircleShape_GetRadius(const b2CircleShape* self) {
+        return self->m_radius;
+    }
+
+    void b2CircleShape_SetRadius(b2CircleShape* self, float32 radius) {
+        self->m_radius = radius;
+    }
+
+    const b2Vec2* b2CircleShape_GetPosition(const b2CircleShape* self) {
+        return &self->m_p;
+    }
+
+    void b2CircleShape_SetPosition(b2CircleShape* self, const b2Vec2* position) {
+        self->m_p = *position;
+    }
+
+} // extern C
@@ -0,0 +1,20 @@
+#ifndef C_BOX2D_CIRCLESHAPE
+#define C_BOX2D_CIRCLESHAPE
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+	b2CircleShape* b2CircleShape_New();
+	void b2CircleShape_Delete(b2CircleShape* self);
+	b2Shape* b2CircleShape_Upcast(b2CircleShape* self);
+	float32 b2CircleShape_GetRadius(const b2CircleShape* self);
+	void b2CircleShape_SetRadius(b2CircleShape* self, float32 radius);
+	const b2Vec2* b2CircleShape_GetPosition(const b2CircleShape* self);
+	void b2CircleShape_SetPosition(b2CircleShape* self, const b2Vec2* position);
+
+#ifdef __cplusplus
+} // extern C
+#endif
+
+#endif
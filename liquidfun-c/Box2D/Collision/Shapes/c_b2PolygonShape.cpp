@@ -34,4 +34,12 @@ extern "C" {
         self->Set(vertices, count);
     }
 
+    float32 b2PolygonShape_GetRadius(const b2PolygonShape* self) {
+        return self->m_radius;
+    }
+
+    void b2PolygonShape_SetRadius(b2PolygonShape* self, float32 radius) {
+        self->m_radius = radius;
+    }
+
 } // extern C
\ No newline at end of file
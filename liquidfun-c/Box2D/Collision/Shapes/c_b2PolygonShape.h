@@ -13,6 +13,8 @@ extern "C" {
 	void b2PolygonShape_SetAsBox_Oriented(b2PolygonShape* self, float32 hx, float32 hy, const b2Vec2& center, float32 angle);
 	b2Shape* b2PolygonShape_Upcast(b2PolygonShape* self);
 	void b2PolygonShape_Set(b2PolygonShape* self, const b2Vec2* vertices, int32 count);
+	float32 b2PolygonShape_GetRadius(const b2PolygonShape* self);
+	void b2PolygonShape_SetRadius(b2PolygonShape* self, float32 radius);
 
 #ifdef __cplusplus
 } // extern C
@@ -0,0 +1,88 @@
+#include <Box2D/Box2D.h>
+#include "c_b2DynamicTree.h"
+
+// Adapts a C function pointer to the callback class expected by b2DynamicTree::Query.
+struct c_b2DynamicTreeQueryCallback {
+	b2DynamicTree_QueryCallback callback;
+	void* userData;
+
+	bool QueryCallback(int32 proxyId) {
+		return callback(userData, proxyId);
+	}
+};
+
+// Adapts a C function pointer to the callback class expected by b2DynamicTree::RayCast.
+struct c_b2DynamicTreeRayCastCallback {
+	b2DynamicTree_RayCastCallback callback;
+	void* userData;
+
+	float32 RayCastCallback(const b2RayCastInput& input, int32 proxyId) {
+		return callback(userData, &input, proxyId);
+	}
+};
+
+extern "C" {
+
+	b2DynamicTree* b2DynamicTree_New() {
+		return new b2DynamicTree;
+	}
+
+	void b2DynamicTree_Delete(b2DynamicTree* self) {
+		delete self;
+	}
+
+	int32 b2DynamicTree_CreateProxy(b2DynamicTree* self, const b2AABB* aabb, void* userData) {
+		return self->CreateProxy(*aabb, userData);
+	}
+
+	void b2DynamicTree_DestroyProxy(b2DynamicTree* self, int32 proxyId) {
+		self->DestroyProxy(proxyId);
+	}
+
+	bool b2DynamicTree_MoveProxy(b2DynamicTree* self, int32 proxyId, const b2AABB* aabb, const b2Vec2* displacement) {
+		return self->MoveProxy(proxyId, *aabb, *displacement);
+	}
+
+	void* b2DynamicTree_GetUserData(const b2DynamicTree* self, int32 proxyId) {
+		return self->GetUserData(proxyId);
+	}
+
+	const b2AABB* b2DynamicTree_GetFatAABB(const b2DynamicTree* self, int32 proxyId) {
+		return &self->GetFatAABB(proxyId);
+	}
+
+	void b2DynamicTree_Query(const b2DynamicTree* self, b2DynamicTree_QueryCallback callback, void* userData, const b2AABB* aabb) {
+		c_b2DynamicTreeQueryCallback adapter = { callback, userData };
+		self->Query(&adapter, *aabb);
+	}
+
+	void b2DynamicTree_RayCast(const b2DynamicTree* self, b2DynamicTree_RayCastCallback callback, void* userData, const b2RayCastInput* input) {
+		c_b2DynamicTreeRayCastCallback adapter = { callback, userData };
+		self->RayCast(&adapter, *input);
+	}
+
+	void b2DynamicTree_Validate(const b2DynamicTree* self) {
+		self->Validate();
+	}
+
+	int32 b2DynamicTree_GetHeight(const b2DynamicTree* self) {
+		return self->GetHeight();
+	}
+
+	int32 b2DynamicTree_GetMaxBalance(const b2DynamicTree* self) {
+		return self->GetMaxBalance();
+	}
+
+	float32 b2DynamicTree_GetAreaRatio(const b2DynamicTree* self) {
+		return self->GetAreaRatio();
+	}
+
+	void b2DynamicTree_RebuildBottomUp(b2DynamicTree* self) {
+		self->RebuildBottomUp();
+	}
+
+	void b2DynamicTree_ShiftOrigin(b2DynamicTree* self, const b2Vec2* newOrigin) {
+		self->ShiftOrigin(*newOrigin);
+	}
+
+} // extern C
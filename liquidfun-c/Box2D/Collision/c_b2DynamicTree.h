@@ -0,0 +1,30 @@
+#ifndef C_B2_DYNAMIC_TREE
+#define C_B2_DYNAMIC_TREE
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+	typedef bool (*b2DynamicTree_QueryCallback)(void* userData, int32 proxyId);
+	typedef float32 (*b2DynamicTree_RayCastCallback)(void* userData, const b2RayCastInput* input, int32 proxyId);
+
+	b2DynamicTree* b2DynamicTree_New();
+	void b2DynamicTree_Delete(b2DynamicTree* self);
+	int32 b2DynamicTree_CreateProxy(b2DynamicTree* self, const b2AABB* aabb, void* userData);
+	void b2DynamicTree_DestroyProxy(b2DynamicTree* self, int32 proxyId);
+	bool b2DynamicTree_MoveProxy(b2DynamicTree* self, int32 proxyId, const b2AABB* aabb, const b2Vec2* displacement);
+	void* b2DynamicTree_GetUserData(const b2DynamicTree* self, int32 proxyId);
+	const b2AABB* b2DynamicTree_GetFatAABB(const b2DynamicTree* self, int32 proxyId);
+	void b2DynamicTree_Query(const b2DynamicTree* self, b2DynamicTree_QueryCallback callback, void* userData, const b2AABB* aabb);
+	void b2DynamicTree_RayCast(const b2DynamicTree* self, b2DynamicTree_RayCastCallback callback, void* userData, const b2RayCastInput* input);
+	void b2DynamicTree_Validate(const b2DynamicTree* self);
+	int32 b2DynamicTree_GetHeight(const b2DynamicTree* self);
+	int32 b2DynamicTree_GetMaxBalance(const b2DynamicTree* self);
+	float32 b2DynamicTree_GetAreaRatio(const b2DynamicTree* self);
+	void b2DynamicTree_RebuildBottomUp(b2DynamicTree* self);
+	void b2DynamicTree_ShiftOrigin(b2DynamicTree* self, const b2Vec2* newOrigin);
+
+#ifdef __cplusplus
+} // extern C
+#endif
+#endif
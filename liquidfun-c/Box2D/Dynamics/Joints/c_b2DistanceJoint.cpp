@@ -0,0 +1,42 @@
+#include <Box2D/Box2D.h>
+#include "c_b2DistanceJoint.h"
+
+extern "C" {
+
+	b2Joint* b2DistanceJoint_Upcast(b2DistanceJoint* self) {
+	    return static_cast<b2Joint*>(self);
+	}
+
+	const b2Vec2* b2DistanceJoint_GetLocalAnchorA(const b2DistanceJoint* self) {
+	    return &self->GetLocalAnchorA();
+	}
+
+	const b2Vec2* b2DistanceJoint_GetLocalAnchorB(const b2DistanceJoint* self) {
+	    return &self->GetLocalAnchorB();
+	}
+
+	void b2DistanceJoint_SetLength(b2DistanceJoint* self, float32 length) {
+	    self->SetLength(length);
+	}
+
+	float32 b2DistanceJoint_GetLength(const b2DistanceJoint* self) {
+	    return self->GetLength();
+	}
+
+	void b2DistanceJoint_SetFrequency(b2DistanceJoint* self, float32 hz) {
+	    self->SetFrequency(hz);
+	}
+
+	float32 b2DistanceJoint_GetFrequency(const b2DistanceJoint* self) {
+	    return self->GetFrequency();
+	}
+
+	void b2DistanceJoint_SetDampingRatio(b2DistanceJoint* self, float32 ratio) {
+	    self->SetDampingRatio(ratio);
+	}
+
+	float32 b2DistanceJoint_GetDampingRatio(const b2DistanceJoint* self) {
+	    return self->GetDampingRatio();
+	}
+
+} // extern C
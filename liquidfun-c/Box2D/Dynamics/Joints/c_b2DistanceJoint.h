@@ -0,0 +1,21 @@
+#ifndef C_B2_DISTANCE_JOINT
+#define C_B2_DISTANCE_JOINT
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+	b2Joint* b2DistanceJoint_Upcast(b2DistanceJoint* self);
+	const b2Vec2* b2DistanceJoint_GetLocalAnchorA(const b2DistanceJoint* self);
+	const b2Vec2* b2DistanceJoint_GetLocalAnchorB(const b2DistanceJoint* self);
+	void b2DistanceJoint_SetLength(b2DistanceJoint* self, float32 length);
+	float32 b2DistanceJoint_GetLength(const b2DistanceJoint* self);
+	void b2DistanceJoint_SetFrequency(b2DistanceJoint* self, float32 hz);
+	float32 b2DistanceJoint_GetFrequency(const b2DistanceJoint* self);
+	void b2DistanceJoint_SetDampingRatio(b2DistanceJoint* self, float32 ratio);
+	float32 b2DistanceJoint_GetDampingRatio(const b2DistanceJoint* self);
+
+#ifdef __cplusplus
+} // extern C
+#endif
+#endif
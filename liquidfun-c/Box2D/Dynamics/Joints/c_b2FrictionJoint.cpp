@@ -0,0 +1,34 @@
+#include <Box2D/Box2D.h>
+#include "c_b2FrictionJoint.h"
+
+extern "C" {
+
+	b2Joint* b2FrictionJoint_Upcast(b2FrictionJoint* self) {
+	    return static_cast<b2Joint*>(self);
+	}
+
+	const b2Vec2* b2FrictionJoint_GetLocalAnchorA(const b2FrictionJoint* self) {
+	    return &self->GetLocalAnchorA();
+	}
+
+	const b2Vec2* b2FrictionJoint_GetLocalAnchorB(const b2FrictionJoint* self) {
+	    return &self->GetLocalAnchorB();
+	}
+
+	void b2FrictionJoint_SetMaxForce(b2FrictionJoint* self, float32 force) {
+	    self->SetMaxForce(force);
+	}
+
+	float32 b2FrictionJoint_GetMaxForce(const b2FrictionJoint* self) {
+	    return self->GetMaxForce();
+	}
+
+	void b2FrictionJoint_SetMaxTorque(b2FrictionJoint* self, float32 torque) {
+	    self->SetMaxTorque(torque);
+	}
+
+	float32 b2FrictionJoint_GetMaxTorque(const b2FrictionJoint* self) {
+	    return self->GetMaxTorque();
+	}
+
+} // extern C
@@ -0,0 +1,19 @@
+#ifndef C_B2_FRICTION_JOINT
+#define C_B2_FRICTION_JOINT
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+	b2Joint* b2FrictionJoint_Upcast(b2FrictionJoint* self);
+	const b2Vec2* b2FrictionJoint_GetLocalAnchorA(const b2FrictionJoint* self);
+	const b2Vec2* b2FrictionJoint_GetLocalAnchorB(const b2FrictionJoint* self);
+	void b2FrictionJoint_SetMaxForce(b2FrictionJoint* self, float32 force);
+	float32 b2FrictionJoint_GetMaxForce(const b2FrictionJoint* self);
+	void b2FrictionJoint_SetMaxTorque(b2FrictionJoint* self, float32 torque);
+	float32 b2FrictionJoint_GetMaxTorque(const b2FrictionJoint* self);
+
+#ifdef __cplusplus
+} // extern C
+#endif
+#endif
@@ -0,0 +1,26 @@
+#include <Box2D/Box2D.h>
+#include "c_b2GearJoint.h"
+
+extern "C" {
+
+	b2Joint* b2GearJoint_Upcast(b2GearJoint* self) {
+	    return static_cast<b2Joint*>(self);
+	}
+
+	b2Joint* b2GearJoint_GetJoint1(b2GearJoint* self) {
+	    return self->GetJoint1();
+	}
+
+	b2Joint* b2GearJoint_GetJoint2(b2GearJoint* self) {
+	    return self->GetJoint2();
+	}
+
+	void b2GearJoint_SetRatio(b2GearJoint* self, float32 ratio) {
+	    self->SetRatio(ratio);
+	}
+
+	float32 b2GearJoint_GetRatio(const b2GearJoint* self) {
+	    return self->GetRatio();
+	}
+
+} // extern C
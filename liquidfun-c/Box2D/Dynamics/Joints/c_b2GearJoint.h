@@ -0,0 +1,17 @@
+#ifndef C_B2_GEAR_JOINT
+#define C_B2_GEAR_JOINT
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+	b2Joint* b2GearJoint_Upcast(b2GearJoint* self);
+	b2Joint* b2GearJoint_GetJoint1(b2GearJoint* self);
+	b2Joint* b2GearJoint_GetJoint2(b2GearJoint* self);
+	void b2GearJoint_SetRatio(b2GearJoint* self, float32 ratio);
+	float32 b2GearJoint_GetRatio(const b2GearJoint* self);
+
+#ifdef __cplusplus
+} // extern C
+#endif
+#endif
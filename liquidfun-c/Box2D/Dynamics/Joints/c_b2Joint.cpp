@@ -0,0 +1,50 @@
+#include <Box2D/Box2D.h>
+#include "c_b2Joint.h"
+
+extern "C" {
+
+	b2JointType b2Joint_GetType(const b2Joint* self) {
+	    return self->GetType();
+	}
+
+	b2Body* b2Joint_GetBodyA(b2Joint* self) {
+	    return self->GetBodyA();
+	}
+
+	b2Body* b2Joint_GetBodyB(b2Joint* self) {
+	    return self->GetBodyB();
+	}
+
+	b2Vec2 b2Joint_GetAnchorA(const b2Joint* self) {
+	    return self->GetAnchorA();
+	}
+
+	b2Vec2 b2Joint_GetAnchorB(const b2Joint* self) {
+	    return self->GetAnchorB();
+	}
+
+	b2Vec2 b2Joint_GetReactionForce(const b2Joint* self, float32 inv_dt) {
+	    return self->GetReactionForce(inv_dt);
+	}
+
+	float32 b2Joint_GetReactionTorque(const b2Joint* self, float32 inv_dt) {
+	    return self->GetReactionTorque(inv_dt);
+	}
+
+	b2Joint* b2Joint_GetNext(b2Joint* self) {
+	    return self->GetNext();
+	}
+
+	void* b2Joint_GetUserData(const b2Joint* self) {
+	    return self->GetUserData();
+	}
+
+	bool b2Joint_IsActive(const b2Joint* self) {
+	    return self->IsActive();
+	}
+
+	bool b2Joint_GetCollideConnected(const b2Joint* self) {
+	    return self->GetCollideConnected();
+	}
+
+} // extern C
@@ -0,0 +1,23 @@
+#ifndef C_B2_JOINT
+#define C_B2_JOINT
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+	b2JointType b2Joint_GetType(const b2Joint* self);
+	b2Body* b2Joint_GetBodyA(b2Joint* self);
+	b2Body* b2Joint_GetBodyB(b2Joint* self);
+	b2Vec2 b2Joint_GetAnchorA(const b2Joint* self);
+	b2Vec2 b2Joint_GetAnchorB(const b2Joint* self);
+	b2Vec2 b2Joint_GetReactionForce(const b2Joint* self, float32 inv_dt);
+	float32 b2Joint_GetReactionTorque(const b2Joint* self, float32 inv_dt);
+	b2Joint* b2Joint_GetNext(b2Joint* self);
+	void* b2Joint_GetUserData(const b2Joint* self);
+	bool b2Joint_IsActive(const b2Joint* self);
+	bool b2Joint_GetCollideConnected(const b2Joint* self);
+
+#ifdef __cplusplus
+} // extern C
+#endif
+#endif
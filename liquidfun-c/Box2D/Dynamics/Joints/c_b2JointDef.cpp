@@ -0,0 +1,146 @@
+#include <Box2D/Box2D.h>
+#include "c_b2JointDef.h"
+
+static void c_b2JointDef_CopyBase(const c_b2JointDef& base, b2JointDef* def) {
+    def->userData = base.userData;
+    def->bodyA = base.bodyA;
+    def->bodyB = base.bodyB;
+    def->collideConnected = base.collideConnected;
+}
+
+b2Joint* c_b2JointDef_CreateJoint(b2World* world, const c_b2JointDef* def) {
+    switch (def->type) {
+    case e_distanceJoint: {
+        const c_b2DistanceJointDef* c = reinterpret_cast<const c_b2DistanceJointDef*>(def);
+        b2DistanceJointDef d;
+        c_b2JointDef_CopyBase(c->base, &d);
+        d.localAnchorA = c->localAnchorA;
+        d.localAnchorB = c->localAnchorB;
+        d.length = c->length;
+        d.frequencyHz = c->frequencyHz;
+        d.dampingRatio = c->dampingRatio;
+        return world->CreateJoint(&d);
+    }
+    case e_frictionJoint: {
+        const c_b2FrictionJointDef* c = reinterpret_cast<const c_b2FrictionJointDef*>(def);
+        b2FrictionJointDef d;
+        c_b2JointDef_CopyBase(c->base, &d);
+        d.localAnchorA = c->localAnchorA;
+        d.localAnchorB = c->localAnchorB;
+        d.maxForce = c->maxForce;
+        d.maxTorque = c->maxTorque;
+        return world->CreateJoint(&d);
+    }
+    case e_gearJoint: {
+        const c_b2GearJointDef* c = reinterpret_cast<const c_b2GearJointDef*>(def);
+        b2GearJointDef d;
+        c_b2JointDef_CopyBase(c->base, &d);
+        d.joint1 = c->joint1;
+        d.joint2 = c->joint2;
+        d.ratio = c->ratio;
+        return world->CreateJoint(&d);
+    }
+    case e_motorJoint: {
+        const c_b2MotorJointDef* c = reinterpret_cast<const c_b2MotorJointDef*>(def);
+        b2MotorJointDef d;
+        c_b2JointDef_CopyBase(c->base, &d);
+        d.linearOffset = c->linearOffset;
+        d.angularOffset = c->angularOffset;
+        d.maxForce = c->maxForce;
+        d.maxTorque = c->maxTorque;
+        d.correctionFactor = c->correctionFactor;
+        return world->CreateJoint(&d);
+    }
+    case e_mouseJoint: {
+        const c_b2MouseJointDef* c = reinterpret_cast<const c_b2MouseJointDef*>(def);
+        b2MouseJointDef d;
+        c_b2JointDef_CopyBase(c->base, &d);
+        d.target = c->target;
+        d.maxForce = c->maxForce;
+        d.frequencyHz = c->frequencyHz;
+        d.dampingRatio = c->dampingRatio;
+        return world->CreateJoint(&d);
+    }
+    case e_prismaticJoint: {
+        const c_b2PrismaticJointDef* c = reinterpret_cast<const c_b2PrismaticJointDef*>(def);
+        b2PrismaticJointDef d;
+        c_b2JointDef_CopyBase(c->base, &d);
+        d.localAnchorA = c->localAnchorA;
+        d.localAnchorB = c->localAnchorB;
+        d.localAxisA = c->localAxisA;
+        d.referenceAngle = c->referenceAngle;
+        d.enableLimit = c->enableLimit;
+        d.lowerTranslation = c->lowerTranslation;
+        d.upperTranslation = c->upperTranslation;
+        d.enableMotor = c->enableMotor;
+        d.maxMotorForce = c->maxMotorForce;
+        d.motorSpeed = c->motorSpeed;
+        return world->CreateJoint(&d);
+    }
+    case e_pulleyJoint: {
+        const c_b2PulleyJointDef* c = reinterpret_cast<const c_b2PulleyJointDef*>(def);
+        b2PulleyJointDef d;
+        c_b2JointDef_CopyBase(c->base, &d);
+        d.groundAnchorA = c->groundAnchorA;
+        d.groundAnchorB = c->groundAnchorB;
+        d.localAnchorA = c->localAnchorA;
+        d.localAnchorB = c->localAnchorB;
+        d.lengthA = c->lengthA;
+        d.lengthB = c->lengthB;
+        d.ratio = c->ratio;
+        return world->CreateJoint(&d);
+    }
+    case e_revoluteJoint: {
+        const c_b2RevoluteJointDef* c = reinterpret_cast<const c_b2RevoluteJointDef*>(def);
+        b2RevoluteJointDef d;
+        c_b2JointDef_CopyBase(c->base, &d);
+        d.localAnchorA = c->localAnchorA;
+        d.localAnchorB = c->localAnchorB;
+        d.referenceAngle = c->referenceAngle;
+        d.enableLimit = c->enableLimit;
+        d.lowerAngle = c->lowerAngle;
+        d.upperAngle = c->upperAngle;
+        d.enableMotor = c->enableMotor;
+        d.motorSpeed = c->motorSpeed;
+        d.maxMotorTorque = c->maxMotorTorque;
+        return world->CreateJoint(&d);
+    }
+    case e_ropeJoint: {
+        const c_b2RopeJointDef* c = reinterpret_cast<const c_b2RopeJointDef*>(def);
+        b2RopeJointDef d;
+        c_b2JointDef_CopyBase(c->base, &d);
+        d.localAnchorA = c->localAnchorA;
+        d.localAnchorB = c->localAnchorB;
+        d.maxLength = c->maxLength;
+        return world->CreateJoint(&d);
+    }
+    case e_weldJoint: {
+        const c_b2WeldJointDef* c = reinterpret_cast<const c_b2WeldJointDef*>(def);
+        b2WeldJointDef d;
+        c_b2JointDef_CopyBase(c->base, &d);
+        d.localAnchorA = c->localAnchorA;
+        d.localAnchorB = c->localAnchorB;
+        d.referenceAngle = c->referenceAngle;
+        d.frequencyHz = c->frequencyHz;
+        d.dampingRatio = c->dampingRatio;
+        return world->CreateJoint(&d);
+    }
+    case e_wheelJoint: {
+        const c_b2WheelJointDef* c = reinterpret_cast<const c_b2WheelJointDef*>(def);
+        b2WheelJointDef d;
+        c_b2JointDef_CopyBase(c->base, &d);
+        d.localAnchorA = c->localAnchorA;
+        d.localAnchorB = c->localAnchorB;
+        d.localAxisA = c->localAxisA;
+        d.enableMotor = c->enableMotor;
+        d.maxMotorTorque = c->maxMotorTorque;
+        d.motorSpeed = c->motorSpeed;
+        d.frequencyHz = c->frequencyHz;
+        d.dampingRatio = c->dampingRatio;
+        return world->CreateJoint(&d);
+    }
+    default:
+        b2Assert(false);
+        return NULL;
+    }
+}
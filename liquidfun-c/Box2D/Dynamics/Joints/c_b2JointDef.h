@@ -0,0 +1,125 @@
+#ifndef C_B2_JOINT_DEF
+#define C_B2_JOINT_DEF
+
+// Plain C layouts of the joint definitions. The C++ definitions derive from
+// b2JointDef, and where the compiler places the derived members depends on
+// the C++ ABI, so the definitions are built from these structs instead.
+
+typedef struct c_b2JointDef {
+    b2JointType type;
+    void* userData;
+    b2Body* bodyA;
+    b2Body* bodyB;
+    bool collideConnected;
+} c_b2JointDef;
+
+typedef struct c_b2DistanceJointDef {
+    c_b2JointDef base;
+    b2Vec2 localAnchorA;
+    b2Vec2 localAnchorB;
+    float32 length;
+    float32 frequencyHz;
+    float32 dampingRatio;
+} c_b2DistanceJointDef;
+
+typedef struct c_b2FrictionJointDef {
+    c_b2JointDef base;
+    b2Vec2 localAnchorA;
+    b2Vec2 localAnchorB;
+    float32 maxForce;
+    float32 maxTorque;
+} c_b2FrictionJointDef;
+
+typedef struct c_b2GearJointDef {
+    c_b2JointDef base;
+    b2Joint* joint1;
+    b2Joint* joint2;
+    float32 ratio;
+} c_b2GearJointDef;
+
+typedef struct c_b2MotorJointDef {
+    c_b2JointDef base;
+    b2Vec2 linearOffset;
+    float32 angularOffset;
+    float32 maxForce;
+    float32 maxTorque;
+    float32 correctionFactor;
+} c_b2MotorJointDef;
+
+typedef struct c_b2MouseJointDef {
+    c_b2JointDef base;
+    b2Vec2 target;
+    float32 maxForce;
+    float32 frequencyHz;
+    float32 dampingRatio;
+} c_b2MouseJointDef;
+
+typedef struct c_b2PrismaticJointDef {
+    c_b2JointDef base;
+    b2Vec2 localAnchorA;
+    b2Vec2 localAnchorB;
+    b2Vec2 localAxisA;
+    float32 referenceAngle;
+    bool enableLimit;
+    float32 lowerTranslation;
+    float32 upperTranslation;
+    bool enableMotor;
+    float32 maxMotorForce;
+    float32 motorSpeed;
+} c_b2PrismaticJointDef;
+
+typedef struct c_b2PulleyJointDef {
+    c_b2JointDef base;
+    b2Vec2 groundAnchorA;
+    b2Vec2 groundAnchorB;
+    b2Vec2 localAnchorA;
+    b2Vec2 localAnchorB;
+    float32 lengthA;
+    float32 lengthB;
+    float32 ratio;
+} c_b2PulleyJointDef;
+
+typedef struct c_b2RevoluteJointDef {
+    c_b2JointDef base;
+    b2Vec2 localAnchorA;
+    b2Vec2 localAnchorB;
+    float32 referenceAngle;
+    bool enableLimit;
+    float32 lowerAngle;
+    float32 upperAngle;
+    bool enableMotor;
+    float32 motorSpeed;
+    float32 maxMotorTorque;
+} c_b2RevoluteJointDef;
+
+typedef struct c_b2RopeJointDef {
+    c_b2JointDef base;
+    b2Vec2 localAnchorA;
+    b2Vec2 localAnchorB;
+    float32 maxLength;
+} c_b2RopeJointDef;
+
+typedef struct c_b2WeldJointDef {
+    c_b2JointDef base;
+    b2Vec2 localAnchorA;
+    b2Vec2 localAnchorB;
+    float32 referenceAngle;
+    float32 frequencyHz;
+    float32 dampingRatio;
+} c_b2WeldJointDef;
+
+typedef struct c_b2WheelJointDef {
+    c_b2JointDef base;
+    b2Vec2 localAnchorA;
+    b2Vec2 localAnchorB;
+    b2Vec2 localAxisA;
+    bool enableMotor;
+    float32 maxMotorTorque;
+    float32 motorSpeed;
+    float32 frequencyHz;
+    float32 dampingRatio;
+} c_b2WheelJointDef;
+
+b2Joint* c_b2JointDef_CreateJoint(b2World* world, const c_b2JointDef* def);
+
+#endif
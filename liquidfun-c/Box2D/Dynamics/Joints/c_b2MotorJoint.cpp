@@ -0,0 +1,50 @@
+#include <Box2D/Box2D.h>
+#include "c_b2MotorJoint.h"
+
+extern "C" {
+
+	b2Joint* b2MotorJoint_Upcast(b2MotorJoint* self) {
+	    return static_cast<b2Joint*>(self);
+	}
+
+	void b2MotorJoint_SetLinearOffset(b2MotorJoint* self, const b2Vec2* linearOffset) {
+	    self->SetLinearOffset(*linearOffset);
+	}
+
+	const b2Vec2* b2MotorJoint_GetLinearOffset(const b2MotorJoint* self) {
+	    return &self->GetLinearOffset();
+	}
+
+	void b2MotorJoint_SetAngularOffset(b2MotorJoint* self, float32 angularOffset) {
+	    self->SetAngularOffset(angularOffset);
+	}
+
+	float32 b2MotorJoint_GetAngularOffset(const b2MotorJoint* self) {
+	    return self->GetAngularOffset();
+	}
+
+	void b2MotorJoint_SetMaxForce(b2MotorJoint* self, float32 force) {
+	    self->SetMaxForce(force);
+	}
+
+	float32 b2MotorJoint_GetMaxForce(const b2MotorJoint* self) {
+	    return self->GetMaxForce();
+	}
+
+	void b2MotorJoint_SetMaxTorque(b2MotorJoint* self, float32 torque) {
+	    self->SetMaxTorque(torque);
+	}
+
+	float32 b2MotorJoint_GetMaxTorque(const b2MotorJoint* self) {
+	    return self->GetMaxTorque();
+	}
+
+	void b2MotorJoint_SetCorrectionFactor(b2MotorJoint* self, float32 factor) {
+	    self->SetCorrectionFactor(factor);
+	}
+
+	float32 b2MotorJoint_GetCorrectionFactor(const b2MotorJoint* self) {
+	    return self->GetCorrectionFactor();
+	}
+
+} // extern C
@@ -0,0 +1,23 @@
+#ifndef C_B2_MOTOR_JOINT
+#define C_B2_MOTOR_JOINT
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+	b2Joint* b2MotorJoint_Upcast(b2MotorJoint* self);
+	void b2MotorJoint_SetLinearOffset(b2MotorJoint* self, const b2Vec2* linearOffset);
+	const b2Vec2* b2MotorJoint_GetLinearOffset(const b2MotorJoint* self);
+	void b2MotorJoint_SetAngularOffset(b2MotorJoint* self, float32 angularOffset);
+	float32 b2MotorJoint_GetAngularOffset(const b2MotorJoint* self);
+	void b2MotorJoint_SetMaxForce(b2MotorJoint* self, float32 force);
+	float32 b2MotorJoint_GetMaxForce(const b2MotorJoint* self);
+	void b2MotorJoint_SetMaxTorque(b2MotorJoint* self, float32 torque);
+	float32 b2MotorJoint_GetMaxTorque(const b2MotorJoint* self);
+	void b2MotorJoint_SetCorrectionFactor(b2MotorJoint* self, float32 factor);
+	float32 b2MotorJoint_GetCorrectionFactor(const b2MotorJoint* self);
+
+#ifdef __cplusplus
+} // extern C
+#endif
+#endif
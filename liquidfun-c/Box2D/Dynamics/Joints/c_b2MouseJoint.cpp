@@ -0,0 +1,42 @@
+#include <Box2D/Box2D.h>
+#include "c_b2MouseJoint.h"
+
+extern "C" {
+
+	b2Joint* b2MouseJoint_Upcast(b2MouseJoint* self) {
+	    return static_cast<b2Joint*>(self);
+	}
+
+	void b2MouseJoint_SetTarget(b2MouseJoint* self, const b2Vec2* target) {
+	    self->SetTarget(*target);
+	}
+
+	const b2Vec2* b2MouseJoint_GetTarget(const b2MouseJoint* self) {
+	    return &self->GetTarget();
+	}
+
+	void b2MouseJoint_SetMaxForce(b2MouseJoint* self, float32 force) {
+	    self->SetMaxForce(force);
+	}
+
+	float32 b2MouseJoint_GetMaxForce(const b2MouseJoint* self) {
+	    return self->GetMaxForce();
+	}
+
+	void b2MouseJoint_SetFrequency(b2MouseJoint* self, float32 hz) {
+	    self->SetFrequency(hz);
+	}
+
+	float32 b2MouseJoint_GetFrequency(const b2MouseJoint* self) {
+	    return self->GetFrequency();
+	}
+
+	void b2MouseJoint_SetDampingRatio(b2MouseJoint* self, float32 ratio) {
+	    self->SetDampingRatio(ratio);
+	}
+
+	float32 b2MouseJoint_GetDampingRatio(const b2MouseJoint* self) {
+	    return self->GetDampingRatio();
+	}
+
+} // extern C
@@ -0,0 +1,21 @@
+#ifndef C_B2_MOUSE_JOINT
+#define C_B2_MOUSE_JOINT
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+	b2Joint* b2MouseJoint_Upcast(b2MouseJoint* self);
+	void b2MouseJoint_SetTarget(b2MouseJoint* self, const b2Vec2* target);
+	const b2Vec2* b2MouseJoint_GetTarget(const b2MouseJoint* self);
+	void b2MouseJoint_SetMaxForce(b2MouseJoint* self, float32 force);
+	float32 b2MouseJoint_GetMaxForce(const b2MouseJoint* self);
+	void b2MouseJoint_SetFrequency(b2MouseJoint* self, float32 hz);
+	float32 b2MouseJoint_GetFrequency(const b2MouseJoint* self);
+	void b2MouseJoint_SetDampingRatio(b2MouseJoint* self, float32 ratio);
+	float32 b2MouseJoint_GetDampingRatio(const b2MouseJoint* self);
+
+#ifdef __cplusplus
+} // extern C
+#endif
+#endif
@@ -0,0 +1,82 @@
+#include <Box2D/Box2D.h>
+#include "c_b2PrismaticJoint.h"
+
+extern "C" {
+
+	b2Joint* b2PrismaticJoint_Upcast(b2PrismaticJoint* self) {
+	    return static_cast<b2Joint*>(self);
+	}
+
+	const b2Vec2* b2PrismaticJoint_GetLocalAnchorA(const b2PrismaticJoint* self) {
+	    return &self->GetLocalAnchorA();
+	}
+
+	const b2Vec2* b2PrismaticJoint_GetLocalAnchorB(const b2PrismaticJoint* self) {
+	    return &self->GetLocalAnchorB();
+	}
+
+	const b2Vec2* b2PrismaticJoint_GetLocalAxisA(const b2PrismaticJoint* self) {
+	    return &self->GetLocalAxisA();
+	}
+
+	float32 b2PrismaticJoint_GetReferenceAngle(const b2PrismaticJoint* self) {
+	    return self->GetReferenceAngle();
+	}
+
+	float32 b2PrismaticJoint_GetJointTranslation(const b2PrismaticJoint* self) {
+	    return self->GetJointTranslation();
+	}
+
+	float32 b2PrismaticJoint_GetJointSpeed(const b2PrismaticJoint* self) {
+	    return self->GetJointSpeed();
+	}
+
+	bool b2PrismaticJoint_IsLimitEnabled(const b2PrismaticJoint* self) {
+	    return self->IsLimitEnabled();
+	}
+
+	void b2PrismaticJoint_EnableLimit(b2PrismaticJoint* self, bool flag) {
+	    self->EnableLimit(flag);
+	}
+
+	float32 b2PrismaticJoint_GetLowerLimit(const b2PrismaticJoint* self) {
+	    return self->GetLowerLimit();
+	}
+
+	float32 b2PrismaticJoint_GetUpperLimit(const b2PrismaticJoint* self) {
+	    return self->GetUpperLimit();
+	}
+
+	void b2PrismaticJoint_SetLimits(b2PrismaticJoint* self, float32 lower, float32 upper) {
+	    self->SetLimits(lower, upper);
+	}
+
+	bool b2PrismaticJoint_IsMotorEnabled(const b2PrismaticJoint* self) {
+	    return self->IsMotorEnabled();
+	}
+
+	void b2PrismaticJoint_EnableMotor(b2PrismaticJoint* self, bool flag) {
+	    self->EnableMotor(flag);
+	}
+
+	void b2PrismaticJoint_SetMotorSpeed(b2PrismaticJoint* self, float32 speed) {
+	    self->SetMotorSpeed(speed);
+	}
+
+	float32 b2PrismaticJoint_GetMotorSpeed(const b2PrismaticJoint* self) {
+	    return self->GetMotorSpeed();
+	}
+
+	void b2PrismaticJoint_SetMaxMotorForce(b2PrismaticJoint* self, float32 force) {
+	    self->SetMaxMotorForce(force);
+	}
+
+	float32 b2PrismaticJoint_GetMaxMotorForce(const b2PrismaticJoint* self) {
+	    return self->GetMaxMotorForce();
+	}
+
+	float32 b2PrismaticJoint_GetMotorForce(const b2PrismaticJoint* self, float32 inv_dt) {
+	    return self->GetMotorForce(inv_dt);
+	}
+
+} // extern C
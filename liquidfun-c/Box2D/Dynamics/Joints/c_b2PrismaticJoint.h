@@ -0,0 +1,31 @@
+#ifndef C_B2_PRISMATIC_JOINT
+#define C_B2_PRISMATIC_JOINT
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+	b2Joint* b2PrismaticJoint_Upcast(b2PrismaticJoint* self);
+	const b2Vec2* b2PrismaticJoint_GetLocalAnchorA(const b2PrismaticJoint* self);
+	const b2Vec2* b2PrismaticJoint_GetLocalAnchorB(const b2PrismaticJoint* self);
+	const b2Vec2* b2PrismaticJoint_GetLocalAxisA(const b2PrismaticJoint* self);
+	float32 b2PrismaticJoint_GetReferenceAngle(const b2PrismaticJoint* self);
+	float32 b2PrismaticJoint_GetJointTranslation(const b2PrismaticJoint* self);
+	float32 b2PrismaticJoint_GetJointSpeed(const b2PrismaticJoint* self);
+	bool b2PrismaticJoint_IsLimitEnabled(const b2PrismaticJoint* self);
+	void b2PrismaticJoint_EnableLimit(b2PrismaticJoint* self, bool flag);
+	float32 b2PrismaticJoint_GetLowerLimit(const b2PrismaticJoint* self);
+	float32 b2PrismaticJoint_GetUpperLimit(const b2PrismaticJoint* self);
+	void b2PrismaticJoint_SetLimits(b2PrismaticJoint* self, float32 lower, float32 upper);
+	bool b2PrismaticJoint_IsMotorEnabled(const b2PrismaticJoint* self);
+	void b2PrismaticJoint_EnableMotor(b2PrismaticJoint* self, bool flag);
+	void b2PrismaticJoint_SetMotorSpeed(b2PrismaticJoint* self, float32 speed);
+	float32 b2PrismaticJoint_GetMotorSpeed(const b2PrismaticJoint* self);
+	void b2PrismaticJoint_SetMaxMotorForce(b2PrismaticJoint* self, float32 force);
+	float32 b2PrismaticJoint_GetMaxMotorForce(const b2PrismaticJoint* self);
+	float32 b2PrismaticJoint_GetMotorForce(const b2PrismaticJoint* self, float32 inv_dt);
+
+#ifdef __cplusplus
+} // extern C
+#endif
+#endif
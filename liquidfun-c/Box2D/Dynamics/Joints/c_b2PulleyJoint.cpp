@@ -0,0 +1,38 @@
+#include <Box2D/Box2D.h>
+#include "c_b2PulleyJoint.h"
+
+extern "C" {
+
+	b2Joint* b2PulleyJoint_Upcast(b2PulleyJoint* self) {
+	    return static_cast<b2Joint*>(self);
+	}
+
+	b2Vec2 b2PulleyJoint_GetGroundAnchorA(const b2PulleyJoint* self) {
+	    return self->GetGroundAnchorA();
+	}
+
+	b2Vec2 b2PulleyJoint_GetGroundAnchorB(const b2PulleyJoint* self) {
+	    return self->GetGroundAnchorB();
+	}
+
+	float32 b2PulleyJoint_GetLengthA(const b2PulleyJoint* self) {
+	    return self->GetLengthA();
+	}
+
+	float32 b2PulleyJoint_GetLengthB(const b2PulleyJoint* self) {
+	    return self->GetLengthB();
+	}
+
+	float32 b2PulleyJoint_GetRatio(const b2PulleyJoint* self) {
+	    return self->GetRatio();
+	}
+
+	float32 b2PulleyJoint_GetCurrentLengthA(const b2PulleyJoint* self) {
+	    return self->GetCurrentLengthA();
+	}
+
+	float32 b2PulleyJoint_GetCurrentLengthB(const b2PulleyJoint* self) {
+	    return self->GetCurrentLengthB();
+	}
+
+} // extern C
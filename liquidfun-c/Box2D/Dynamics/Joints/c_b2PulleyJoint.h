@@ -0,0 +1,20 @@
+#ifndef C_B2_PULLEY_JOINT
+#define C_B2_PULLEY_JOINT
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+	b2Joint* b2PulleyJoint_Upcast(b2PulleyJoint* self);
+	b2Vec2 b2PulleyJoint_GetGroundAnchorA(const b2PulleyJoint* self);
+	b2Vec2 b2PulleyJoint_GetGroundAnchorB(const b2PulleyJoint* self);
+	float32 b2PulleyJoint_GetLengthA(const b2PulleyJoint* self);
+	float32 b2PulleyJoint_GetLengthB(const b2PulleyJoint* self);
+	float32 b2PulleyJoint_GetRatio(const b2PulleyJoint* self);
+	float32 b2PulleyJoint_GetCurrentLengthA(const b2PulleyJoint* self);
+	float32 b2PulleyJoint_GetCurrentLengthB(const b2PulleyJoint* self);
+
+#ifdef __cplusplus
+} // extern C
+#endif
+#endif
@@ -3,9 +3,77 @@
 
 extern "C" {
 
+	b2Joint* b2RevoluteJoint_Upcast(b2RevoluteJoint* self) {
+	    return static_cast<b2Joint*>(self);
+	}
+
+	const b2Vec2* b2RevoluteJoint_GetLocalAnchorA(const b2RevoluteJoint* self) {
+	    return &self->GetLocalAnchorA();
+	}
+
+	const b2Vec2* b2RevoluteJoint_GetLocalAnchorB(const b2RevoluteJoint* self) {
+	    return &self->GetLocalAnchorB();
+	}
+
+	float32 b2RevoluteJoint_GetReferenceAngle(const b2RevoluteJoint* self) {
+	    return self->GetReferenceAngle();
+	}
+
+	float32 b2RevoluteJoint_GetJointAngle(const b2RevoluteJoint* self) {
+	    return self->GetJointAngle();
+	}
+
+	float32 b2RevoluteJoint_GetJointSpeed(const b2RevoluteJoint* self) {
+	    return self->GetJointSpeed();
+	}
+
+	bool b2RevoluteJoint_IsLimitEnabled(const b2RevoluteJoint* self) {
+	    return self->IsLimitEnabled();
+	}
+
+	void b2RevoluteJoint_EnableLimit(b2RevoluteJoint* self, bool flag) {
+	    self->EnableLimit(flag);
+	}
+
+	float32 b2RevoluteJoint_GetLowerLimit(const b2RevoluteJoint* self) {
+	    return self->GetLowerLimit();
+	}
+
+	float32 b2RevoluteJoint_GetUpperLimit(const b2RevoluteJoint* self) {
+	    return self->GetUpperLimit();
+	}
+
+	void b2RevoluteJoint_SetLimits(b2RevoluteJoint* self, float32 lower, float32 upper) {
+	    self->SetLimits(lower, upper);
+	}
+
+	bool b2RevoluteJoint_IsMotorEnabled(const b2RevoluteJoint* self) {
+	    return self->IsMotorEnabled();
+	}
+
+	void b2RevoluteJoint_EnableMotor(b2RevoluteJoint* self, bool flag) {
+	    self->EnableMotor(flag);
+	}
+
 	void b2RevoluteJoint_SetMotorSpeed(b2RevoluteJoint* self, float32 speed) {
 	    self->SetMotorSpeed(speed);
 	}
 
+	float32 b2RevoluteJoint_GetMotorSpeed(const b2RevoluteJoint* self) {
+	    return self->GetMotorSpeed();
+	}
+
+	void b2RevoluteJoint_SetMaxMotorTorque(b2RevoluteJoint* self, float32 torque) {
+	    self->SetMaxMotorTorque(torque);
+	}
+
+	float32 b2RevoluteJoint_GetMaxMotorTorque(const b2RevoluteJoint* self) {
+	    return self->GetMaxMotorTorque();
+	}
+
+	float32 b2RevoluteJoint_GetMotorTorque(const b2RevoluteJoint* self, float32 inv_dt) {
+	    return self->GetMotorTorque(inv_dt);
+	}
+
 } // extern C
 
@@ -5,7 +5,24 @@
 extern "C" {
 #endif
 
+	b2Joint* b2RevoluteJoint_Upcast(b2RevoluteJoint* self);
+	const b2Vec2* b2RevoluteJoint_GetLocalAnchorA(const b2RevoluteJoint* self);
+	const b2Vec2* b2RevoluteJoint_GetLocalAnchorB(const b2RevoluteJoint* self);
+	float32 b2RevoluteJoint_GetReferenceAngle(const b2RevoluteJoint* self);
+	float32 b2RevoluteJoint_GetJointAngle(const b2RevoluteJoint* self);
+	float32 b2RevoluteJoint_GetJointSpeed(const b2RevoluteJoint* self);
+	bool b2RevoluteJoint_IsLimitEnabled(const b2RevoluteJoint* self);
+	void b2RevoluteJoint_EnableLimit(b2RevoluteJoint* self, bool flag);
+	float32 b2RevoluteJoint_GetLowerLimit(const b2RevoluteJoint* self);
+	float32 b2RevoluteJoint_GetUpperLimit(const b2RevoluteJoint* self);
+	void b2RevoluteJoint_SetLimits(b2RevoluteJoint* self, float32 lower, float32 upper);
+	bool b2RevoluteJoint_IsMotorEnabled(const b2RevoluteJoint* self);
+	void b2RevoluteJoint_EnableMotor(b2RevoluteJoint* self, bool flag);
 	void b2RevoluteJoint_SetMotorSpeed(b2RevoluteJoint* self, float32 speed);
+	float32 b2RevoluteJoint_GetMotorSpeed(const b2RevoluteJoint* self);
+	void b2RevoluteJoint_SetMaxMotorTorque(b2RevoluteJoint* self, float32 torque);
+	float32 b2RevoluteJoint_GetMaxMotorTorque(const b2RevoluteJoint* self);
+	float32 b2RevoluteJoint_GetMotorTorque(const b2RevoluteJoint* self, float32 inv_dt);
 
 #ifdef __cplusplus
 } // extern C
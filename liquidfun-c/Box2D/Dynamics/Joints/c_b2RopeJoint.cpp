@@ -0,0 +1,30 @@
+#include <Box2D/Box2D.h>
+#include "c_b2RopeJoint.h"
+
+extern "C" {
+
+	b2Joint* b2RopeJoint_Upcast(b2RopeJoint* self) {
+	    return static_cast<b2Joint*>(self);
+	}
+
+	const b2Vec2* b2RopeJoint_GetLocalAnchorA(const b2RopeJoint* self) {
+	    return &self->GetLocalAnchorA();
+	}
+
+	const b2Vec2* b2RopeJoint_GetLocalAnchorB(const b2RopeJoint* self) {
+	    return &self->GetLocalAnchorB();
+	}
+
+	void b2RopeJoint_SetMaxLength(b2RopeJoint* self, float32 length) {
+	    self->SetMaxLength(length);
+	}
+
+	float32 b2RopeJoint_GetMaxLength(const b2RopeJoint* self) {
+	    return self->GetMaxLength();
+	}
+
+	b2LimitState b2RopeJoint_GetLimitState(const b2RopeJoint* self) {
+	    return self->GetLimitState();
+	}
+
+} // extern C
@@ -0,0 +1,18 @@
+#ifndef C_B2_ROPE_JOINT
+#define C_B2_ROPE_JOINT
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+	b2Joint* b2RopeJoint_Upcast(b2RopeJoint* self);
+	const b2Vec2* b2RopeJoint_GetLocalAnchorA(const b2RopeJoint* self);
+	const b2Vec2* b2RopeJoint_GetLocalAnchorB(const b2RopeJoint* self);
+	void b2RopeJoint_SetMaxLength(b2RopeJoint* self, float32 length);
+	float32 b2RopeJoint_GetMaxLength(const b2RopeJoint* self);
+	b2LimitState b2RopeJoint_GetLimitState(const b2RopeJoint* self);
+
+#ifdef __cplusplus
+} // extern C
+#endif
+#endif
@@ -0,0 +1,38 @@
+#include <Box2D/Box2D.h>
+#include "c_b2WeldJoint.h"
+
+extern "C" {
+
+	b2Joint* b2WeldJoint_Upcast(b2WeldJoint* self) {
+	    return static_cast<b2Joint*>(self);
+	}
+
+	const b2Vec2* b2WeldJoint_GetLocalAnchorA(const b2WeldJoint* self) {
+	    return &self->GetLocalAnchorA();
+	}
+
+	const b2Vec2* b2WeldJoint_GetLocalAnchorB(const b2WeldJoint* self) {
+	    return &self->GetLocalAnchorB();
+	}
+
+	float32 b2WeldJoint_GetReferenceAngle(const b2WeldJoint* self) {
+	    return self->GetReferenceAngle();
+	}
+
+	void b2WeldJoint_SetFrequency(b2WeldJoint* self, float32 hz) {
+	    self->SetFrequency(hz);
+	}
+
+	float32 b2WeldJoint_GetFrequency(const b2WeldJoint* self) {
+	    return self->GetFrequency();
+	}
+
+	void b2WeldJoint_SetDampingRatio(b2WeldJoint* self, float32 ratio) {
+	    self->SetDampingRatio(ratio);
+	}
+
+	float32 b2WeldJoint_GetDampingRatio(const b2WeldJoint* self) {
+	    return self->GetDampingRatio();
+	}
+
+} // extern C
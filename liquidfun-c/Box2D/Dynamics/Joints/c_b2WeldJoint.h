@@ -0,0 +1,20 @@
+#ifndef C_B2_WELD_JOINT
+#define C_B2_WELD_JOINT
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+	b2Joint* b2WeldJoint_Upcast(b2WeldJoint* self);
+	const b2Vec2* b2WeldJoint_GetLocalAnchorA(const b2WeldJoint* self);
+	const b2Vec2* b2WeldJoint_GetLocalAnchorB(const b2WeldJoint* self);
+	float32 b2WeldJoint_GetReferenceAngle(const b2WeldJoint* self);
+	void b2WeldJoint_SetFrequency(b2WeldJoint* self, float32 hz);
+	float32 b2WeldJoint_GetFrequency(const b2WeldJoint* self);
+	void b2WeldJoint_SetDampingRatio(b2WeldJoint* self, float32 ratio);
+	float32 b2WeldJoint_GetDampingRatio(const b2WeldJoint* self);
+
+#ifdef __cplusplus
+} // extern C
+#endif
+#endif
@@ -0,0 +1,74 @@
+#include <Box2D/Box2D.h>
+#include "c_b2WheelJoint.h"
+
+extern "C" {
+
+	b2Joint* b2WheelJoint_Upcast(b2WheelJoint* self) {
+	    return static_cast<b2Joint*>(self);
+	}
+
+	const b2Vec2* b2WheelJoint_GetLocalAnchorA(const b2WheelJoint* self) {
+	    return &self->GetLocalAnchorA();
+	}
+
+	const b2Vec2* b2WheelJoint_GetLocalAnchorB(const b2WheelJoint* self) {
+	    return &self->GetLocalAnchorB();
+	}
+
+	const b2Vec2* b2WheelJoint_GetLocalAxisA(const b2WheelJoint* self) {
+	    return &self->GetLocalAxisA();
+	}
+
+	float32 b2WheelJoint_GetJointTranslation(const b2WheelJoint* self) {
+	    return self->GetJointTranslation();
+	}
+
+	float32 b2WheelJoint_GetJointSpeed(const b2WheelJoint* self) {
+	    return self->GetJointSpeed();
+	}
+
+	bool b2WheelJoint_IsMotorEnabled(const b2WheelJoint* self) {
+	    return self->IsMotorEnabled();
+	}
+
+	void b2WheelJoint_EnableMotor(b2WheelJoint* self, bool flag) {
+	    self->EnableMotor(flag);
+	}
+
+	void b2WheelJoint_SetMotorSpeed(b2WheelJoint* self, float32 speed) {
+	    self->SetMotorSpeed(speed);
+	}
+
+	float32 b2WheelJoint_GetMotorSpeed(const b2WheelJoint* self) {
+	    return self->GetMotorSpeed();
+	}
+
+	void b2WheelJoint_SetMaxMotorTorque(b2WheelJoint* self, float32 torque) {
+	    self->SetMaxMotorTorque(torque);
+	}
+
+	float32 b2WheelJoint_GetMaxMotorTorque(const b2WheelJoint* self) {
+	    return self->GetMaxMotorTorque();
+	}
+
+	float32 b2WheelJoint_GetMotorTorque(const b2WheelJoint* self, float32 inv_dt) {
+	    return self->GetMotorTorque(inv_dt);
+	}
+
+	void b2WheelJoint_SetSpringFrequencyHz(b2WheelJoint* self, float32 hz) {
+	    self->SetSpringFrequencyHz(hz);
+	}
+
+	float32 b2WheelJoint_GetSpringFrequencyHz(const b2WheelJoint* self) {
+	    return self->GetSpringFrequencyHz();
+	}
+
+	void b2WheelJoint_SetSpringDampingRatio(b2WheelJoint* self, float32 ratio) {
+	    self->SetSpringDampingRatio(ratio);
+	}
+
+	float32 b2WheelJoint_GetSpringDampingRatio(const b2WheelJoint* self) {
+	    return self->GetSpringDampingRatio();
+	}
+
+} // extern C
@@ -0,0 +1,29 @@
+#ifndef C_B2_WHEEL_JOINT
+#define C_B2_WHEEL_JOINT
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+	b2Joint* b2WheelJoint_Upcast(b2WheelJoint* self);
+	const b2Vec2* b2WheelJoint_GetLocalAnchorA(const b2WheelJoint* self);
+	const b2Vec2* b2WheelJoint_GetLocalAnchorB(const b2WheelJoint* self);
+	const b2Vec2* b2WheelJoint_GetLocalAxisA(const b2WheelJoint* self);
+	float32 b2WheelJoint_GetJointTranslation(const b2WheelJoint* self);
+	float32 b2WheelJoint_GetJointSpeed(const b2WheelJoint* self);
+	bool b2WheelJoint_IsMotorEnabled(const b2WheelJoint* self);
+	void b2WheelJoint_EnableMotor(b2WheelJoint* self, bool flag);
+	void b2WheelJoint_SetMotorSpeed(b2WheelJoint* self, float32 speed);
+	float32 b2WheelJoint_GetMotorSpeed(const b2WheelJoint* self);
+	void b2WheelJoint_SetMaxMotorTorque(b2WheelJoint* self, float32 torque);
+	float32 b2WheelJoint_GetMaxMotorTorque(const b2WheelJoint* self);
+	float32 b2WheelJoint_GetMotorTorque(const b2WheelJoint* self, float32 inv_dt);
+	void b2WheelJoint_SetSpringFrequencyHz(b2WheelJoint* self, float32 hz);
+	float32 b2WheelJoint_GetSpringFrequencyHz(const b2WheelJoint* self);
+	void b2WheelJoint_SetSpringDampingRatio(b2WheelJoint* self, float32 ratio);
+	float32 b2WheelJoint_GetSpringDampingRatio(const b2WheelJoint* self);
+
+#ifdef __cplusplus
+} // extern C
+#endif
+#endif
@@ -15,10 +15,18 @@ extern "C" {
 	    return self->GetAngle();
 	}	
 
+	const b2Transform* b2Body_GetTransform(const b2Body* self) {
+	    return &self->GetTransform();
+	}
+
 	b2Fixture* b2Body_GetFixtureList(b2Body* self) {
 		return self->GetFixtureList();
 	}
 
+	b2JointEdge* b2Body_GetJointList(b2Body* self) {
+		return self->GetJointList();
+	}
+
 	b2Body* b2Body_GetNext(b2Body* self) {
 		return self->GetNext();
 	}
@@ -39,6 +47,10 @@ extern "C" {
 		return self->GetLocalPoint(worldPoint);
 	}
 
+	b2Vec2 b2Body_GetLocalVector(const b2Body* self, const b2Vec2& worldVector) {
+		return self->GetLocalVector(worldVector);
+	}
+
 	void b2Body_SetTransform(b2Body* self, const b2Vec2& position, float32 angle) {
 		self->SetTransform(position, angle);
 	}
@@ -9,11 +9,14 @@ extern "C" {
 	b2Fixture* b2Body_CreateFixture_FromShape(b2Body* self, const b2Shape* shape, float32 density);
 	const b2Vec2& b2Body_GetPosition(const b2Body* self);
 	float32 b2Body_GetAngle(const b2Body* self);
+	const b2Transform* b2Body_GetTransform(const b2Body* self);
 	b2Fixture* b2Body_GetFixtureList(b2Body* self);
+	b2JointEdge* b2Body_GetJointList(b2Body* self);
 	b2Body* b2Body_GetNext(b2Body* self);
 	void* b2Body_GetUserData(const b2Body* self);
 	b2World* b2Body_GetWorld(b2Body* self);
 	b2Vec2 b2Body_GetLocalPoint(const b2Body* self, const b2Vec2& worldPoint);
+	b2Vec2 b2Body_GetLocalVector(const b2Body* self, const b2Vec2& worldVector);
     void b2Body_SetTransform(b2Body* self, const b2Vec2& position, float32 angle);
     void b2Body_SetLinearVelocity(b2Body* self, const b2Vec2& v);
     const b2Vec2& b2Body_GetLinearVelocity(const b2Body* self);
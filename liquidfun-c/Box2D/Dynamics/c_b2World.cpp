@@ -1,5 +1,6 @@
 #include <Box2D/Box2D.h>
 #include "../Common/c_b2Math.h"
+#include "Joints/c_b2JointDef.h"
 #include "c_b2World.h"
 
 extern "C" {
@@ -24,6 +25,10 @@ extern "C" {
         return self->GetBodyList();
     }
 
+    b2Joint* b2World_GetJointList(b2World* self) {
+        return self->GetJointList();
+    }
+
     c_b2Vec2 b2World_GetGravity(const b2World* self) {
     	b2Vec2 tmp = self->GetGravity();
         return *cast(&tmp);
@@ -37,46 +42,12 @@ extern "C" {
     	return self->CreateBody(bd);
     }
 
-    b2RevoluteJoint* b2World_CreateRevoluteJoint(
-        b2World* self,
-
-        // b2JointDef
-        const b2JointType type,
-        void* userData,
-        b2Body* bodyA,
-        b2Body* bodyB,
-        const bool collideConnected,
-
-        // b2RevoluteJointDef
-        const b2Vec2 localAnchorA,
-        const b2Vec2 localAnchorB,
-        const float32 referenceAngle,
-        const bool enableLimit,
-        const float32 lowerAngle,
-        const float32 upperAngle,
-        const bool enableMotor,
-        const float32 motorSpeed,
-        const float32 maxMotorTorque
-    ) {
-        
-        b2RevoluteJointDef jd;
-
-        jd.type = type;
-        jd.userData = userData;
-        jd.bodyA = bodyA;
-        jd.bodyB = bodyB;
-        jd.collideConnected = collideConnected;
-        jd.localAnchorA = localAnchorA;
-        jd.localAnchorB = localAnchorB;
-        jd.referenceAngle = referenceAngle;
-        jd.enableLimit = enableLimit;
-        jd.lowerAngle = lowerAngle;
-        jd.upperAngle = upperAngle;
-        jd.enableMotor = enableMotor;
-        jd.motorSpeed = motorSpeed;
-        jd.maxMotorTorque = maxMotorTorque;
-
-        return (b2RevoluteJoint*)self->CreateJoint(&jd);
+    b2Joint* b2World_CreateJoint(b2World* self, const c_b2JointDef* def) {
+        return c_b2JointDef_CreateJoint(self, def);
+    }
+
+    void b2World_DestroyJoint(b2World* self, b2Joint* joint) {
+        self->DestroyJoint(joint);
     }
 
     b2ParticleSystem* b2World_CreateParticleSystem(b2World* self, const b2ParticleSystemDef* def) {
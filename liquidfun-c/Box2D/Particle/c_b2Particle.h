@@ -5,12 +5,9 @@
 extern "C" {
 #endif
 
-	b2ParticleColor* b2ParticleColor_New(uint8 r, uint8 g, uint8 b, uint8 a);
-	void b2ParticleColor_Delete(b2ParticleColor* p);
-	bool b2ParticleColor_IsZero(b2ParticleColor* p);
-	void b2ParticleColor_Set(b2ParticleColor* p, uint8 r, uint8 g, uint8 b, uint8 a);
+	int32 b2ParticleHandle_GetIndex(const b2ParticleHandle* self);
 
 #ifdef __cplusplus
 } // extern C
 #endif
-#endif
\ No newline at end of file
+#endif
@@ -0,0 +1,94 @@
+#include <Box2D/Box2D.h>
+#include "c_b2ParticleGroup.h"
+
+extern "C" {
+
+	b2ParticleGroup* b2ParticleGroup_GetNext(b2ParticleGroup* self) {
+		return self->GetNext();
+	}
+
+	b2ParticleSystem* b2ParticleGroup_GetParticleSystem(b2ParticleGroup* self) {
+		return self->GetParticleSystem();
+	}
+
+	int32 b2ParticleGroup_GetParticleCount(const b2ParticleGroup* self) {
+		return self->GetParticleCount();
+	}
+
+	int32 b2ParticleGroup_GetBufferIndex(const b2ParticleGroup* self) {
+		return self->GetBufferIndex();
+	}
+
+	bool b2ParticleGroup_ContainsParticle(const b2ParticleGroup* self, int32 index) {
+		return self->ContainsParticle(index);
+	}
+
+	uint32 b2ParticleGroup_GetAllParticleFlags(const b2ParticleGroup* self) {
+		return self->GetAllParticleFlags();
+	}
+
+	uint32 b2ParticleGroup_GetGroupFlags(const b2ParticleGroup* self) {
+		return self->GetGroupFlags();
+	}
+
+	void b2ParticleGroup_SetGroupFlags(b2ParticleGroup* self, uint32 flags) {
+		self->SetGroupFlags(flags);
+	}
+
+	float32 b2ParticleGroup_GetMass(const b2ParticleGroup* self) {
+		return self->GetMass();
+	}
+
+	float32 b2ParticleGroup_GetInertia(const b2ParticleGroup* self) {
+		return self->GetInertia();
+	}
+
+	b2Vec2 b2ParticleGroup_GetCenter(const b2ParticleGroup* self) {
+		return self->GetCenter();
+	}
+
+	b2Vec2 b2ParticleGroup_GetLinearVelocity(const b2ParticleGroup* self) {
+		return self->GetLinearVelocity();
+	}
+
+	float32 b2ParticleGroup_GetAngularVelocity(const b2ParticleGroup* self) {
+		return self->GetAngularVelocity();
+	}
+
+	const b2Transform* b2ParticleGroup_GetTransform(const b2ParticleGroup* self) {
+		return &self->GetTransform();
+	}
+
+	const b2Vec2* b2ParticleGroup_GetPosition(const b2ParticleGroup* self) {
+		return &self->GetPosition();
+	}
+
+	float32 b2ParticleGroup_GetAngle(const b2ParticleGroup* self) {
+		return self->GetAngle();
+	}
+
+	b2Vec2 b2ParticleGroup_GetLinearVelocityFromWorldPoint(const b2ParticleGroup* self, const b2Vec2& worldPoint) {
+		return self->GetLinearVelocityFromWorldPoint(worldPoint);
+	}
+
+	void* b2ParticleGroup_GetUserData(const b2ParticleGroup* self) {
+		return self->GetUserData();
+	}
+
+	void b2ParticleGroup_SetUserData(b2ParticleGroup* self, void* data) {
+		self->SetUserData(data);
+	}
+
+	void b2ParticleGroup_ApplyForce(b2ParticleGroup* self, const b2Vec2& force) {
+		self->ApplyForce(force);
+	}
+
+	void b2ParticleGroup_ApplyLinearImpulse(b2ParticleGroup* self, const b2Vec2& impulse) {
+		self->ApplyLinearImpulse(impulse);
+	}
+
+	void b2ParticleGroup_DestroyParticles(b2ParticleGroup* self, bool callDestructionListener) {
+		self->DestroyParticles(callDestructionListener);
+	}
+
+} // extern C
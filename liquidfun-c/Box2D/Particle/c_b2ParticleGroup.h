@@ -0,0 +1,34 @@
+#ifndef C_B2_PARTICLE_GROUP
+#define C_B2_PARTICLE_GROUP
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+	b2ParticleGroup* b2ParticleGroup_GetNext(b2ParticleGroup* self);
+	b2ParticleSystem* b2ParticleGroup_GetParticleSystem(b2ParticleGroup* self);
+	int32 b2ParticleGroup_GetParticleCount(const b2ParticleGroup* self);
+	int32 b2ParticleGroup_GetBufferIndex(const b2ParticleGroup* self);
+	bool b2ParticleGroup_ContainsParticle(const b2ParticleGroup* self, int32 index);
+	uint32 b2ParticleGroup_GetAllParticleFlags(const b2ParticleGroup* self);
+	uint32 b2ParticleGroup_GetGroupFlags(const b2ParticleGroup* self);
+	void b2ParticleGroup_SetGroupFlags(b2ParticleGroup* self, uint32 flags);
+	float32 b2ParticleGroup_GetMass(const b2ParticleGroup* self);
+	float32 b2ParticleGroup_GetInertia(const b2ParticleGroup* self);
+	b2Vec2 b2ParticleGroup_GetCenter(const b2ParticleGroup* self);
+	b2Vec2 b2ParticleGroup_GetLinearVelocity(const b2ParticleGroup* self);
+	float32 b2ParticleGroup_GetAngularVelocity(const b2ParticleGroup* self);
+	const b2Transform* b2ParticleGroup_GetTransform(const b2ParticleGroup* self);
+	const b2Vec2* b2ParticleGroup_GetPosition(const b2ParticleGroup* self);
+	float32 b2ParticleGroup_GetAngle(const b2ParticleGroup* self);
+	b2Vec2 b2ParticleGroup_GetLinearVelocityFromWorldPoint(const b2ParticleGroup* self, const b2Vec2& worldPoint);
+	void* b2ParticleGroup_GetUserData(const b2ParticleGroup* self);
+	void b2ParticleGroup_SetUserData(b2ParticleGroup* self, void* data);
+	void b2ParticleGroup_ApplyForce(b2ParticleGroup* self, const b2Vec2& force);
+	void b2ParticleGroup_ApplyLinearImpulse(b2ParticleGroup* self, const b2Vec2& impulse);
+	void b2ParticleGroup_DestroyParticles(b2ParticleGroup* self, bool callDestructionListener);
+
+#ifdef __cplusplus
+} // extern C
+#endif
+#endif
@@ -7,6 +7,34 @@ extern "C" {
 		return self->CreateParticle(def);
 	}
 
+	b2ParticleGroup* b2ParticleSystem_CreateParticleGroup(b2ParticleSystem* self, const b2ParticleGroupDef& def) {
+		return self->CreateParticleGroup(def);
+	}
+
+	void b2ParticleSystem_JoinParticleGroups(b2ParticleSystem* self, b2ParticleGroup* groupA, b2ParticleGroup* groupB) {
+		self->JoinParticleGroups(groupA, groupB);
+	}
+
+	void b2ParticleSystem_SplitParticleGroup(b2ParticleSystem* self, b2ParticleGroup* group) {
+		self->SplitParticleGroup(group);
+
+		// The particles moved out of the group are marked as zombies without
+		// updating the flags of the system, so they would never be removed.
+		const uint32* flags = self->GetFlagsBuffer();
+		int32 first = group->GetBufferIndex();
+		int32 last = first + group->GetParticleCount();
+		for (int32 i = first; i < last; i++) {
+			if (flags[i] & b2_zombieParticle) {
+				self->SetParticleFlags(i, flags[i]);
+				break;
+			}
+		}
+	}
+
+	const b2ParticleHandle* b2ParticleSystem_GetParticleHandleFromIndex(b2ParticleSystem* self, const int32 index) {
+		return self->GetParticleHandleFromIndex(index);
+	}
+
 	void b2ParticleSystem_DestroyParticle(b2ParticleSystem* self, int32 index) {
 		self->DestroyParticle(index);
 	}
@@ -15,6 +43,22 @@ extern "C" {
 		return self->GetParticleCount();
 	}
 
+	b2ParticleGroup* b2ParticleSystem_GetParticleGroupList(b2ParticleSystem* self) {
+		return self->GetParticleGroupList();
+	}
+
+	int32 b2ParticleSystem_GetParticleGroupCount(const b2ParticleSystem* self) {
+		return self->GetParticleGroupCount();
+	}
+
+	void b2ParticleSystem_DestroyOldestParticle(b2ParticleSystem* self, const int32 index, const bool callDestructionListener) {
+		self->DestroyOldestParticle(index, callDestructionListener);
+	}
+
+	int32 b2ParticleSystem_DestroyParticlesInShape(b2ParticleSystem* self, const b2Shape* shape, const b2Transform& xf, bool callDestructionListener) {
+		return self->DestroyParticlesInShape(*shape, xf, callDestructionListener);
+	}
+
 	b2ParticleSystem* b2ParticleSystem_GetNext(b2ParticleSystem* self) {
 		return self->GetNext();
 	}
@@ -23,9 +67,141 @@ extern "C" {
 		return self->GetParticleFlags(index);
 	}
 
+	void b2ParticleSystem_SetParticleFlags(b2ParticleSystem* self, int32 index, uint32 flags) {
+		self->SetParticleFlags(index, flags);
+	}
+
+	void b2ParticleSystem_SetParticleVelocity(b2ParticleSystem* self, int32 index, const b2Vec2& velocity) {
+		self->GetVelocityBuffer()[index] = velocity;
+	}
+
+	float32 b2ParticleSystem_GetParticleLifetime(b2ParticleSystem* self, const int32 index) {
+		return self->GetParticleLifetime(index);
+	}
+
+	void b2ParticleSystem_SetParticleLifetime(b2ParticleSystem* self, const int32 index, const float32 lifetime) {
+		self->SetParticleLifetime(index, lifetime);
+	}
+
+	void b2ParticleSystem_ParticleApplyForce(b2ParticleSystem* self, int32 index, const b2Vec2& force) {
+		self->ParticleApplyForce(index, force);
+	}
+
+	void b2ParticleSystem_ParticleApplyLinearImpulse(b2ParticleSystem* self, int32 index, const b2Vec2& impulse) {
+		self->ParticleApplyLinearImpulse(index, impulse);
+	}
+
+	void b2ParticleSystem_ApplyForce(b2ParticleSystem* self, int32 firstIndex, int32 lastIndex, const b2Vec2& force) {
+		self->ApplyForce(firstIndex, lastIndex, force);
+	}
+
+	void b2ParticleSystem_ApplyLinearImpulse(b2ParticleSystem* self, int32 firstIndex, int32 lastIndex, const b2Vec2& impulse) {
+		self->ApplyLinearImpulse(firstIndex, lastIndex, impulse);
+	}
+
+	int32 b2ParticleSystem_GetMaxParticleCount(const b2ParticleSystem* self) {
+		return self->GetMaxParticleCount();
+	}
+
+	void b2ParticleSystem_SetMaxParticleCount(b2ParticleSystem* self, int32 count) {
+		self->SetMaxParticleCount(count);
+	}
+
+	bool b2ParticleSystem_GetDestructionByAge(const b2ParticleSystem* self) {
+		return self->GetDestructionByAge();
+	}
+
+	void b2ParticleSystem_SetDestructionByAge(b2ParticleSystem* self, const bool enable) {
+		self->SetDestructionByAge(enable);
+	}
+
 	b2Vec2* b2ParticleSystem_GetPositionBuffer(b2ParticleSystem* self) {
 		return self->GetPositionBuffer();
 	}
 
+	b2Vec2* b2ParticleSystem_GetVelocityBuffer(b2ParticleSystem* self) {
+		return self->GetVelocityBuffer();
+	}
+
+	b2ParticleColor* b2ParticleSystem_GetColorBuffer(b2ParticleSystem* self) {
+		return self->GetColorBuffer();
+	}
+
+	b2ParticleGroup* const* b2ParticleSystem_GetGroupBuffer(b2ParticleSystem* self) {
+		return self->GetGroupBuffer();
+	}
+
+	float32* b2ParticleSystem_GetWeightBuffer(b2ParticleSystem* self) {
+		return self->GetWeightBuffer();
+	}
+
+	void** b2ParticleSystem_GetUserDataBuffer(b2ParticleSystem* self) {
+		return self->GetUserDataBuffer();
+	}
+
+	const uint32* b2ParticleSystem_GetFlagsBuffer(const b2ParticleSystem* self) {
+		return self->GetFlagsBuffer();
+	}
+
+	const int32* b2ParticleSystem_GetExpirationTimeBuffer(b2ParticleSystem* self) {
+		return self->GetExpirationTimeBuffer();
+	}
+
+	const int32* b2ParticleSystem_GetIndexByExpirationTimeBuffer(b2ParticleSystem* self) {
+		return self->GetIndexByExpirationTimeBuffer();
+	}
+
+	const b2ParticleContact* b2ParticleSystem_GetContacts(const b2ParticleSystem* self) {
+		return self->GetContacts();
+	}
+
+	int32 b2ParticleSystem_GetContactCount(const b2ParticleSystem* self) {
+		return self->GetContactCount();
+	}
+
+	const b2ParticleBodyContact* b2ParticleSystem_GetBodyContacts(const b2ParticleSystem* self) {
+		return self->GetBodyContacts();
+	}
+
+	int32 b2ParticleSystem_GetBodyContactCount(const b2ParticleSystem* self) {
+		return self->GetBodyContactCount();
+	}
+
+	const b2ParticlePair* b2ParticleSystem_GetPairs(const b2ParticleSystem* self) {
+		return self->GetPairs();
+	}
+
+	int32 b2ParticleSystem_GetPairCount(const b2ParticleSystem* self) {
+		return self->GetPairCount();
+	}
+
+	const b2ParticleTriad* b2ParticleSystem_GetTriads(const b2ParticleSystem* self) {
+		return self->GetTriads();
+	}
+
+	int32 b2ParticleSystem_GetTriadCount(const b2ParticleSystem* self) {
+		return self->GetTriadCount();
+	}
+
+	void b2ParticleSystem_SetFlagsBuffer(b2ParticleSystem* self, uint32* buffer, int32 capacity) {
+		self->SetFlagsBuffer(buffer, capacity);
+	}
+
+	void b2ParticleSystem_SetPositionBuffer(b2ParticleSystem* self, b2Vec2* buffer, int32 capacity) {
+		self->SetPositionBuffer(buffer, capacity);
+	}
+
+	void b2ParticleSystem_SetVelocityBuffer(b2ParticleSystem* self, b2Vec2* buffer, int32 capacity) {
+		self->SetVelocityBuffer(buffer, capacity);
+	}
+
+	void b2ParticleSystem_SetColorBuffer(b2ParticleSystem* self, b2ParticleColor* buffer, int32 capacity) {
+		self->SetColorBuffer(buffer, capacity);
+	}
+
+	void b2ParticleSystem_SetUserDataBuffer(b2ParticleSystem* self, void** buffer, int32 capacity) {
+		self->SetUserDataBuffer(buffer, capacity);
+	}
+
 } // extern C
 
@@ -6,11 +6,52 @@ extern "C" {
 #endif
 
 	int32 b2ParticleSystem_CreateParticle(b2ParticleSystem* self, const b2ParticleDef& def);
+	b2ParticleGroup* b2ParticleSystem_CreateParticleGroup(b2ParticleSystem* self, const b2ParticleGroupDef& def);
+	void b2ParticleSystem_JoinParticleGroups(b2ParticleSystem* self, b2ParticleGroup* groupA, b2ParticleGroup* groupB);
+	void b2ParticleSystem_SplitParticleGroup(b2ParticleSystem* self, b2ParticleGroup* group);
+	const b2ParticleHandle* b2ParticleSystem_GetParticleHandleFromIndex(b2ParticleSystem* self, const int32 index);
 	void b2ParticleSystem_DestroyParticle(b2ParticleSystem* self, int32 index);
+	void b2ParticleSystem_DestroyOldestParticle(b2ParticleSystem* self, const int32 index, const bool callDestructionListener);
+	int32 b2ParticleSystem_DestroyParticlesInShape(b2ParticleSystem* self, const b2Shape* shape, const b2Transform& xf, bool callDestructionListener);
 	b2ParticleSystem* b2ParticleSystem_GetNext(b2ParticleSystem* self);
 	int32 b2ParticleSystem_GetParticleCount(b2ParticleSystem* self);
+	b2ParticleGroup* b2ParticleSystem_GetParticleGroupList(b2ParticleSystem* self);
+	int32 b2ParticleSystem_GetParticleGroupCount(const b2ParticleSystem* self);
 	uint32 b2ParticleSystem_GetParticleFlags(b2ParticleSystem* self, const int32 index);
+	void b2ParticleSystem_SetParticleFlags(b2ParticleSystem* self, int32 index, uint32 flags);
+	void b2ParticleSystem_SetParticleVelocity(b2ParticleSystem* self, int32 index, const b2Vec2& velocity);
+	float32 b2ParticleSystem_GetParticleLifetime(b2ParticleSystem* self, const int32 index);
+	void b2ParticleSystem_SetParticleLifetime(b2ParticleSystem* self, const int32 index, const float32 lifetime);
+	void b2ParticleSystem_ParticleApplyForce(b2ParticleSystem* self, int32 index, const b2Vec2& force);
+	void b2ParticleSystem_ParticleApplyLinearImpulse(b2ParticleSystem* self, int32 index, const b2Vec2& impulse);
+	void b2ParticleSystem_ApplyForce(b2ParticleSystem* self, int32 firstIndex, int32 lastIndex, const b2Vec2& force);
+	void b2ParticleSystem_ApplyLinearImpulse(b2ParticleSystem* self, int32 firstIndex, int32 lastIndex, const b2Vec2& impulse);
+	int32 b2ParticleSystem_GetMaxParticleCount(const b2ParticleSystem* self);
+	void b2ParticleSystem_SetMaxParticleCount(b2ParticleSystem* self, int32 count);
+	bool b2ParticleSystem_GetDestructionByAge(const b2ParticleSystem* self);
+	void b2ParticleSystem_SetDestructionByAge(b2ParticleSystem* self, const bool enable);
 	b2Vec2* b2ParticleSystem_GetPositionBuffer(b2ParticleSystem* self);
+	b2Vec2* b2ParticleSystem_GetVelocityBuffer(b2ParticleSystem* self);
+	b2ParticleColor* b2ParticleSystem_GetColorBuffer(b2ParticleSystem* self);
+	b2ParticleGroup* const* b2ParticleSystem_GetGroupBuffer(b2ParticleSystem* self);
+	float32* b2ParticleSystem_GetWeightBuffer(b2ParticleSystem* self);
+	void** b2ParticleSystem_GetUserDataBuffer(b2ParticleSystem* self);
+	const uint32* b2ParticleSystem_GetFlagsBuffer(const b2ParticleSystem* self);
+	const int32* b2ParticleSystem_GetExpirationTimeBuffer(b2ParticleSystem* self);
+	const int32* b2ParticleSystem_GetIndexByExpirationTimeBuffer(b2ParticleSystem* self);
+	const b2ParticleContact* b2ParticleSystem_GetContacts(const b2ParticleSystem* self);
+	int32 b2ParticleSystem_GetContactCount(const b2ParticleSystem* self);
+	const b2ParticleBodyContact* b2ParticleSystem_GetBodyContacts(const b2ParticleSystem* self);
+	int32 b2ParticleSystem_GetBodyContactCount(const b2ParticleSystem* self);
+	const b2ParticlePair* b2ParticleSystem_GetPairs(const b2ParticleSystem* self);
+	int32 b2ParticleSystem_GetPairCount(const b2ParticleSystem* self);
+	const b2ParticleTriad* b2ParticleSystem_GetTriads(const b2ParticleSystem* self);
+	int32 b2ParticleSystem_GetTriadCount(const b2ParticleSystem* self);
+	void b2ParticleSystem_SetFlagsBuffer(b2ParticleSystem* self, uint32* buffer, int32 capacity);
+	void b2ParticleSystem_SetPositionBuffer(b2ParticleSystem* self, b2Vec2* buffer, int32 capacity);
+	void b2ParticleSystem_SetVelocityBuffer(b2ParticleSystem* self, b2Vec2* buffer, int32 capacity);
+	void b2ParticleSystem_SetColorBuffer(b2ParticleSystem* self, b2ParticleColor* buffer, int32 capacity);
+	void b2ParticleSystem_SetUserDataBuffer(b2ParticleSystem* self, void** buffer, int32 capacity);
 
 
 #ifdef __cplusplus
@@ -54,12 +54,27 @@
 
 #include <Box2D/Common/b2GrowableStack.h>
 
+#include "Box2D/Collision/c_b2DynamicTree.cpp"
 #include "Box2D/Collision/Shapes/c_b2ChainShape.cpp"
+#include "Box2D/Collision/Shapes/c_b2CircleShape.cpp"
 #include "Box2D/Collision/Shapes/c_b2PolygonShape.cpp"
 #include "Box2D/Common/c_b2Math.cpp"
 #include "Box2D/Dynamics/c_b2Body.cpp"
 #include "Box2D/Dynamics/c_b2Fixture.cpp"
 #include "Box2D/Dynamics/c_b2World.cpp"
+#include "Box2D/Dynamics/Joints/c_b2DistanceJoint.cpp"
+#include "Box2D/Dynamics/Joints/c_b2FrictionJoint.cpp"
+#include "Box2D/Dynamics/Joints/c_b2GearJoint.cpp"
+#include "Box2D/Dynamics/Joints/c_b2Joint.cpp"
+#include "Box2D/Dynamics/Joints/c_b2JointDef.cpp"
+#include "Box2D/Dynamics/Joints/c_b2MotorJoint.cpp"
+#include "Box2D/Dynamics/Joints/c_b2MouseJoint.cpp"
+#include "Box2D/Dynamics/Joints/c_b2PrismaticJoint.cpp"
+#include "Box2D/Dynamics/Joints/c_b2PulleyJoint.cpp"
 #include "Box2D/Dynamics/Joints/c_b2RevoluteJoint.cpp"
+#include "Box2D/Dynamics/Joints/c_b2RopeJoint.cpp"
+#include "Box2D/Dynamics/Joints/c_b2WeldJoint.cpp"
+#include "Box2D/Dynamics/Joints/c_b2WheelJoint.cpp"
 #include "Box2D/Particle/c_b2Particle.cpp"
+#include "Box2D/Particle/c_b2ParticleGroup.cpp"
 #include "Box2D/Particle/c_b2ParticleSystem.cpp"
@@ -0,0 +1,250 @@
+use libc::c_void;
+use std::any::Any;
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use super::*;
+use super::super::common::math::*;
+use super::super::common::settings::*;
+
+pub enum B2DynamicTree {}
+
+type B2QueryCallback = extern fn(user_data: *mut c_void, proxy_id: Int32) -> bool;
+type B2RayCastCallback = extern fn(user_data: *mut c_void, input: *const RayCastInput, proxy_id: Int32) -> Float32;
+
+extern {
+    fn b2DynamicTree_New() -> *mut B2DynamicTree;
+    fn b2DynamicTree_Delete(this: *mut B2DynamicTree);
+    fn b2DynamicTree_CreateProxy(this: *mut B2DynamicTree, aabb: *const AABB, user_data: *mut c_void) -> Int32;
+    fn b2DynamicTree_DestroyProxy(this: *mut B2DynamicTree, proxy_id: Int32);
+    fn b2DynamicTree_MoveProxy(this: *mut B2DynamicTree, proxy_id: Int32, aabb: *const AABB, displacement: *const Vec2) -> bool;
+    fn b2DynamicTree_GetFatAABB(this: *const B2DynamicTree, proxy_id: Int32) -> *const AABB;
+    fn b2DynamicTree_Query(this: *const B2DynamicTree, callback: B2QueryCallback, user_data: *mut c_void, aabb: *const AABB);
+    fn b2DynamicTree_RayCast(this: *const B2DynamicTree, callback: B2RayCastCallback, user_data: *mut c_void, input: *const RayCastInput);
+    fn b2DynamicTree_Validate(this: *const B2DynamicTree);
+    fn b2DynamicTree_GetHeight(this: *const B2DynamicTree) -> Int32;
+    fn b2DynamicTree_GetMaxBalance(this: *const B2DynamicTree) -> Int32;
+    fn b2DynamicTree_GetAreaRatio(this: *const B2DynamicTree) -> Float32;
+    fn b2DynamicTree_RebuildBottomUp(this: *mut B2DynamicTree);
+    fn b2DynamicTree_ShiftOrigin(this: *mut B2DynamicTree, new_origin: *const Vec2);
+}
+
+/// A user callback passed through C++. Unwinding across the FFI boundary is
+/// undefined, so a panic in the callback is caught, stops the query or ray
+/// cast and is resumed once C++ returns.
+struct Callback<F> {
+    callback: F,
+    panic: Option<Box<Any + Send>>,
+}
+
+impl<F> Callback<F> {
+
+    fn new(callback: F) -> Callback<F> {
+        Callback { callback: callback, panic: None }
+    }
+
+    fn call<R, C: FnOnce(&mut F) -> R>(&mut self, call: C, stop: R) -> R {
+        let callback = &mut self.callback;
+        match panic::catch_unwind(AssertUnwindSafe(|| call(callback))) {
+            Ok(result) => result,
+            Err(panic) => {
+                self.panic = Some(panic);
+                stop
+            }
+        }
+    }
+
+    fn resume(self) {
+        if let Some(panic) = self.panic {
+            panic::resume_unwind(panic);
+        }
+    }
+}
+
+extern fn query_callback<F: FnMut(Int32) -> bool>(user_data: *mut c_void, proxy_id: Int32) -> bool {
+    let callback = unsafe { &mut *(user_data as *mut Callback<F>) };
+    callback.call(|f| f(proxy_id), false)
+}
+
+extern fn ray_cast_callback<F: FnMut(&RayCastInput, Int32) -> f32>(user_data: *mut c_void, input: *const RayCastInput, proxy_id: Int32) -> Float32 {
+    let callback = unsafe { &mut *(user_data as *mut Callback<F>) };
+    callback.call(|f| f(unsafe { &*input }, proxy_id), 0.0)
+}
+
+/// A dynamic AABB tree broad-phase, inspired by Nathanael Presson's btDbvt.
+/// A dynamic tree arranges data in a binary tree to accelerate
+/// queries such as volume queries and ray casts. Leafs are proxies
+/// with an AABB. In the tree we expand the proxy AABB by b2_fatAABBFactor
+/// so that the proxy AABB is bigger than the client object. This allows the client
+/// object to move by small amounts without triggering a tree update.
+///
+/// Unlike the C++ API, the user data of each proxy is an owned `T` kept on the
+/// Rust side and dropped together with its proxy.
+pub struct DynamicTree<T> {
+    ptr: *mut B2DynamicTree,
+    user_data: HashMap<Int32, T>,
+}
+
+impl<T> DynamicTree<T> {
+
+    /// Constructing the tree initializes the node pool.
+    pub fn new() -> DynamicTree<T> {
+        unsafe {
+            DynamicTree { ptr: b2DynamicTree_New(), user_data: HashMap::new() }
+        }
+    }
+
+    /// Create a proxy. Provide a tight fitting AABB and the user data owned by the proxy.
+    pub fn create_proxy(&mut self, aabb: &AABB, user_data: T) -> Int32 {
+        let proxy_id = unsafe {
+            b2DynamicTree_CreateProxy(self.ptr, aabb, ptr::null_mut())
+        };
+        self.user_data.insert(proxy_id, user_data);
+        proxy_id
+    }
+
+    /// Destroy a proxy.
+    /// @return the user data of the proxy or None if the id is invalid.
+    pub fn destroy_proxy(&mut self, proxy_id: Int32) -> Option<T> {
+        let user_data = self.user_data.remove(&proxy_id);
+        if user_data.is_some() {
+            unsafe {
+                b2DynamicTree_DestroyProxy(self.ptr, proxy_id);
+            }
+        }
+        user_data
+    }
+
+    /// Move a proxy with a swepted AABB. If the proxy has moved outside of its fattened AABB,
+    /// then the proxy is removed from the tree and re-inserted. Otherwise
+    /// the function returns immediately.
+    /// @return true if the proxy was re-inserted.
+    /// @panics if the id is invalid.
+    pub fn move_proxy(&mut self, proxy_id: Int32, aabb: &AABB, displacement: &Vec2) -> bool {
+        self.assert_proxy(proxy_id);
+        unsafe {
+            b2DynamicTree_MoveProxy(self.ptr, proxy_id, aabb, displacement)
+        }
+    }
+
+    /// Get proxy user data.
+    /// @return the proxy user data or None if the id is invalid.
+    pub fn get_user_data(&self, proxy_id: Int32) -> Option<&T> {
+        self.user_data.get(&proxy_id)
+    }
+
+    /// Get mutable proxy user data.
+    /// @return the proxy user data or None if the id is invalid.
+    pub fn get_user_data_mut(&mut self, proxy_id: Int32) -> Option<&mut T> {
+        self.user_data.get_mut(&proxy_id)
+    }
+
+    /// Get the fat AABB for a proxy.
+    /// @panics if the id is invalid.
+    pub fn get_fat_aabb(&self, proxy_id: Int32) -> AABB {
+        self.assert_proxy(proxy_id);
+        unsafe {
+            *b2DynamicTree_GetFatAABB(self.ptr, proxy_id)
+        }
+    }
+
+    /// Query an AABB for overlapping proxies. The callback is called
+    /// with the id of each proxy that overlaps the supplied AABB.
+    /// Return false from the callback to terminate the query.
+    /// A panic in the callback terminates the query and is resumed after it.
+    pub fn query<F: FnMut(Int32) -> bool>(&self, aabb: &AABB, callback: F) {
+        let mut callback = Callback::new(callback);
+        unsafe {
+            b2DynamicTree_Query(self.ptr, query_callback::<F>, &mut callback as *mut Callback<F> as *mut c_void, aabb);
+        }
+        callback.resume();
+    }
+
+    /// Ray-cast against the proxies in the tree. This relies on the callback
+    /// to perform a exact ray-cast in the case were the proxy contains a shape.
+    /// The callback also performs the any collision filtering. This has performance
+    /// roughly equal to k * log(n), where k is the number of collisions and n is the
+    /// number of proxies in the tree.
+    /// The callback returns the new max fraction of the ray: 0 terminates the
+    /// ray cast, the input max fraction continues it unclipped.
+    /// @param input the ray-cast input data. The ray extends from p1 to p1 + maxFraction * (p2 - p1).
+    /// @param callback a callback that is called for each proxy that is hit by the ray.
+    /// A panic in the callback terminates the ray cast and is resumed after it.
+    pub fn ray_cast<F: FnMut(&RayCastInput, Int32) -> f32>(&self, input: &RayCastInput, callback: F) {
+        let mut callback = Callback::new(callback);
+        unsafe {
+            b2DynamicTree_RayCast(self.ptr, ray_cast_callback::<F>, &mut callback as *mut Callback<F> as *mut c_void, input);
+        }
+        callback.resume();
+    }
+
+    /// Validate this tree. For testing.
+    pub fn validate(&self) {
+        unsafe {
+            b2DynamicTree_Validate(self.ptr);
+        }
+    }
+
+    /// Compute the height of the binary tree in O(N) time. Should not be
+    /// called often.
+    pub fn get_height(&self) -> i32 {
+        unsafe {
+            b2DynamicTree_GetHeight(self.ptr)
+        }
+    }
+
+    /// Get the maximum balance of an node in the tree. The balance is the difference
+    /// in height of the two children of a node.
+    pub fn get_max_balance(&self) -> i32 {
+        unsafe {
+            b2DynamicTree_GetMaxBalance(self.ptr)
+        }
+    }
+
+    /// Get the ratio of the sum of the node areas to the root area.
+    pub fn get_area_ratio(&self) -> f32 {
+        unsafe {
+            b2DynamicTree_GetAreaRatio(self.ptr)
+        }
+    }
+
+    /// Build an optimal tree. Very expensive. For testing.
+    pub fn rebuild_bottom_up(&mut self) {
+        unsafe {
+            b2DynamicTree_RebuildBottomUp(self.ptr);
+        }
+    }
+
+    /// Shift the world origin. Useful for large worlds.
+    /// The shift formula is: position -= newOrigin
+    /// @param newOrigin the new origin with respect to the old origin
+    pub fn shift_origin(&mut self, new_origin: &Vec2) {
+        unsafe {
+            b2DynamicTree_ShiftOrigin(self.ptr, new_origin);
+        }
+    }
+
+    /// Get the number of proxies in the tree.
+    pub fn get_proxy_count(&self) -> i32 {
+        self.user_data.len() as i32
+    }
+
+    fn assert_proxy(&self, proxy_id: Int32) {
+        assert!(self.user_data.contains_key(&proxy_id), "invalid proxy id {}", proxy_id);
+    }
+}
+
+impl<T> Default for DynamicTree<T> {
+    fn default() -> DynamicTree<T> {
+        DynamicTree::new()
+    }
+}
+
+/// Destroy the tree, freeing the node pool.
+impl<T> Drop for DynamicTree<T> {
+    fn drop(&mut self) {
+        unsafe {
+            b2DynamicTree_Delete(self.ptr);
+        }
+    }
+}
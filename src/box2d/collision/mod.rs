@@ -1 +1,154 @@
-pub mod shapes;
\ No newline at end of file
+pub mod dynamic_tree;
+pub mod shapes;
+
+use super::common::math::*;
+use super::common::settings::*;
+
+/// Ray-cast input data. The ray extends from p1 to p1 + maxFraction * (p2 - p1).
+#[repr(C)]
+#[derive(Debug, PartialEq, Default, Copy, Clone)]
+pub struct RayCastInput {
+	pub p1: Vec2,
+	pub p2: Vec2,
+	pub max_fraction: Float32,
+}
+
+/// Ray-cast output data. The ray hits at p1 + fraction * (p2 - p1), where p1 and p2
+/// come from b2RayCastInput.
+#[repr(C)]
+#[derive(Debug, PartialEq, Default, Copy, Clone)]
+pub struct RayCastOutput {
+	pub normal: Vec2,
+	pub fraction: Float32,
+}
+
+/// An axis aligned bounding box.
+#[repr(C)]
+#[derive(Debug, PartialEq, Default, Copy, Clone)]
+pub struct AABB {
+	/// the lower vertex
+	pub lower_bound: Vec2,
+
+	/// the upper vertex
+	pub upper_bound: Vec2,
+}
+
+impl AABB {
+
+	/// Construct using the lower and upper vertices.
+	pub fn new(lower_bound: Vec2, upper_bound: Vec2) -> AABB {
+		AABB { lower_bound: lower_bound, upper_bound: upper_bound }
+	}
+
+	/// Verify that the bounds are sorted.
+	pub fn is_valid(&self) -> bool {
+		let d = self.upper_bound - self.lower_bound;
+		d.x >= 0.0 && d.y >= 0.0 && self.lower_bound.is_valid() && self.upper_bound.is_valid()
+	}
+
+	/// Get the center of the AABB.
+	pub fn get_center(&self) -> Vec2 {
+		0.5 * (self.lower_bound + self.upper_bound)
+	}
+
+	/// Get the extents of the AABB (half-widths).
+	pub fn get_extents(&self) -> Vec2 {
+		0.5 * (self.upper_bound - self.lower_bound)
+	}
+
+	/// Get the perimeter length
+	pub fn get_perimeter(&self) -> f32 {
+		let wx = self.upper_bound.x - self.lower_bound.x;
+		let wy = self.upper_bound.y - self.lower_bound.y;
+		2.0 * (wx + wy)
+	}
+
+	/// Combine an AABB into this one.
+	pub fn combine(&mut self, aabb: &AABB) {
+		let combined = AABB::combined(self, aabb);
+		*self = combined;
+	}
+
+	/// Combine two AABBs into a new one.
+	pub fn combined(aabb1: &AABB, aabb2: &AABB) -> AABB {
+		AABB {
+			lower_bound: aabb1.lower_bound.min(&aabb2.lower_bound),
+			upper_bound: aabb1.upper_bound.max(&aabb2.upper_bound),
+		}
+	}
+
+	/// Does this aabb contain the provided AABB.
+	pub fn contains(&self, aabb: &AABB) -> bool {
+		self.lower_bound.x <= aabb.lower_bound.x
+			&& self.lower_bound.y <= aabb.lower_bound.y
+			&& aabb.upper_bound.x <= self.upper_bound.x
+			&& aabb.upper_bound.y <= self.upper_bound.y
+	}
+
+	/// Does this aabb overlap the provided AABB.
+	pub fn test_overlap(&self, aabb: &AABB) -> bool {
+		!(aabb.lower_bound.x - self.upper_bound.x > 0.0
+			|| aabb.lower_bound.y - self.upper_bound.y > 0.0
+			|| self.lower_bound.x - aabb.upper_bound.x > 0.0
+			|| self.lower_bound.y - aabb.upper_bound.y > 0.0)
+	}
+
+	/// Cast a ray against this AABB.
+	/// @return the hit normal and fraction, or None if the ray misses
+	/// or starts inside the box.
+	pub fn ray_cast(&self, input: &RayCastInput) -> Option<RayCastOutput> {
+		let mut tmin = -MAX_FLOAT;
+		let mut tmax = MAX_FLOAT;
+
+		let p = [input.p1.x, input.p1.y];
+		let d = [input.p2.x - input.p1.x, input.p2.y - input.p1.y];
+		let lower = [self.lower_bound.x, self.lower_bound.y];
+		let upper = [self.upper_bound.x, self.upper_bound.y];
+
+		let mut normal = [0.0, 0.0];
+
+		for i in 0..2 {
+			if d[i].abs() < EPSILON {
+				// Parallel.
+				if p[i] < lower[i] || upper[i] < p[i] {
+					return None;
+				}
+			} else {
+				let inv_d = 1.0 / d[i];
+				let mut t1 = (lower[i] - p[i]) * inv_d;
+				let mut t2 = (upper[i] - p[i]) * inv_d;
+
+				// Sign of the normal vector.
+				let mut s = -1.0;
+
+				if t1 > t2 {
+					::std::mem::swap(&mut t1, &mut t2);
+					s = 1.0;
+				}
+
+				// Push the min up
+				if t1 > tmin {
+					normal = [0.0, 0.0];
+					normal[i] = s;
+					tmin = t1;
+				}
+
+				// Pull the max down
+				tmax = tmax.min(t2);
+
+				if tmin > tmax {
+					return None;
+				}
+			}
+		}
+
+		// Does the ray start inside the box?
+		// Does the ray intersect beyond the max fraction?
+		if tmin < 0.0 || input.max_fraction < tmin {
+			return None;
+		}
+
+		// Intersection.
+		Some(RayCastOutput { normal: Vec2::new(normal[0], normal[1]), fraction: tmin })
+	}
+}
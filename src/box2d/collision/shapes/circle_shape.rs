@@ -0,0 +1,82 @@
+use super::shape::*;
+use super::super::super::common::settings::*;
+use super::super::super::common::math::*;
+
+enum B2CircleShape {}
+
+extern {
+    fn b2CircleShape_Delete(ptr: *mut B2CircleShape);
+    fn b2CircleShape_New() -> *mut B2CircleShape;
+    fn b2CircleShape_Upcast(ptr: *mut B2CircleShape) -> *mut B2Shape;
+    fn b2CircleShape_GetRadius(ptr: *const B2CircleShape) -> Float32;
+    fn b2CircleShape_SetRadius(ptr: *mut B2CircleShape, radius: Float32);
+    fn b2CircleShape_GetPosition(ptr: *const B2CircleShape) -> *const Vec2;
+    fn b2CircleShape_SetPosition(ptr: *mut B2CircleShape, position: *const Vec2);
+}
+
+/// A circle shape.
+pub struct CircleShape {
+    ptr: *mut B2CircleShape,
+    owned: bool,
+}
+
+/// Cast a CircleShape from a B2Shape.
+pub fn from_shape(ptr: *mut B2Shape) -> CircleShape {
+    CircleShape { ptr: ptr as *mut B2CircleShape, owned: false}
+}
+
+impl Shape for CircleShape {
+    fn handle(&self) -> *mut B2Shape {
+        unsafe {
+            b2CircleShape_Upcast(self.ptr)
+        }
+    }
+}
+
+impl CircleShape {
+
+    /// Create a new CircleShape.
+    pub fn new() -> CircleShape {
+        unsafe {
+            CircleShape { ptr: b2CircleShape_New(), owned: true }
+        }
+    }
+
+    /// Get the radius.
+    pub fn get_radius(&self) -> f32 {
+        unsafe {
+            b2CircleShape_GetRadius(self.ptr)
+        }
+    }
+
+    /// Set the radius.
+    pub fn set_radius(&mut self, radius: f32) {
+        unsafe {
+            b2CircleShape_SetRadius(self.ptr, radius);
+        }
+    }
+
+    /// Get the position in local coordinates.
+    pub fn get_position(&self) -> Vec2 {
+        unsafe {
+            *b2CircleShape_GetPosition(self.ptr)
+        }
+    }
+
+    /// Set the position in local coordinates.
+    pub fn set_position(&mut self, position: &Vec2) {
+        unsafe {
+            b2CircleShape_SetPosition(self.ptr, position);
+        }
+    }
+}
+
+impl Drop for CircleShape {
+    fn drop(&mut self) {
+        if self.owned {
+            unsafe {
+                b2CircleShape_Delete(self.ptr);
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
 pub mod chain_shape;
+pub mod circle_shape;
 pub mod polygon_shape;
 pub mod shape;
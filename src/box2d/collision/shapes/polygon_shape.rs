@@ -6,13 +6,15 @@ enum B2PolygonShape {}
 
 extern {
     fn b2PolygonShape_Delete(ptr: *mut B2PolygonShape);
-    fn b2PolygonShape_GetVertex(ptr: *mut B2PolygonShape, index: Int32) -> &Vec2;
+    fn b2PolygonShape_GetVertex(ptr: *mut B2PolygonShape, index: Int32) -> &'static Vec2;
     fn b2PolygonShape_GetVertexCount(ptr: *const B2PolygonShape) -> Int32;
     fn b2PolygonShape_New() -> *mut B2PolygonShape;
     fn b2PolygonShape_SetAsBox(ptr: *mut B2PolygonShape, hx: Float32, hy: Float32);
     fn b2PolygonShape_SetAsBox_Oriented(ptr: *mut B2PolygonShape, hx: Float32, hy: Float32, center: &Vec2, angle: Float32);
     fn b2PolygonShape_Upcast(ptr: *mut B2PolygonShape) -> *mut B2Shape;
     fn b2PolygonShape_Set(ptr: *mut B2PolygonShape, vertices: *const Vec2, count: Int32);
+    fn b2PolygonShape_GetRadius(ptr: *const B2PolygonShape) -> Float32;
+    fn b2PolygonShape_SetRadius(ptr: *mut B2PolygonShape, radius: Float32);
 }
 
 /// A convex polygon. It is assumed that the interior of the polygon is to
@@ -89,6 +91,20 @@ impl PolygonShape {
             b2PolygonShape_Set(self.ptr, vertices.as_ptr(), vertices.len() as Int32);
         }
     }
+
+    /// Get the radius of the polygon skin.
+    pub fn get_radius(&self) -> f32 {
+        unsafe {
+            b2PolygonShape_GetRadius(self.ptr)
+        }
+    }
+
+    /// Set the radius of the polygon skin.
+    pub fn set_radius(&mut self, radius: f32) {
+        unsafe {
+            b2PolygonShape_SetRadius(self.ptr, radius);
+        }
+    }
 }
 
 impl Drop for PolygonShape {
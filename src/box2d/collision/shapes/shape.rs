@@ -2,6 +2,12 @@
 //! Shapes used for simulation in b2World are created automatically when a b2Fixture
 //! is created. Shapes may encapsulate a one or more child shapes.
 
+use super::chain_shape::ChainShape;
+use super::circle_shape::CircleShape;
+use super::polygon_shape::PolygonShape;
+use super::super::super::common::math::*;
+use super::super::super::common::settings::*;
+
 #[repr(C)]
 #[derive(Debug)]
 pub enum Type
@@ -18,3 +24,167 @@ pub enum B2Shape {}
 pub trait Shape {
 	fn handle(&self) -> *mut B2Shape;
 }
+
+/// An owned description of a shape. Unlike the shape classes it holds its own
+/// data rather than a pointer into LiquidFun, so it can be stored in definitions,
+/// cloned and serialized. Use `to_shape` to build the LiquidFun shape.
+/// Definitions read from untrusted data should be checked with `validate`,
+/// LiquidFun asserts on invalid shapes.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ShapeDef {
+	/// A circle with a radius and a position in local coordinates.
+	Circle { radius: Float32, position: Vec2 },
+
+	/// An oriented box, see PolygonShape::set_as_box_oriented.
+	Box { hx: Float32, hy: Float32, center: Vec2, angle: Float32 },
+
+	/// A convex polygon with 3 to MAX_POLYGON_VERTICES vertices and the radius
+	/// of its skin, see PolygonShape::set.
+	Polygon {
+		vertices: Vec<Vec2>,
+		#[cfg_attr(feature = "serde", serde(default = "polygon_radius"))]
+		radius: Float32,
+	},
+
+	/// A chain with isolated end vertices and at least 2 vertices, see
+	/// ChainShape::create_chain.
+	Chain { vertices: Vec<Vec2> },
+}
+
+impl ShapeDef {
+
+	/// Check that this definition describes a shape LiquidFun accepts.
+	/// @return a description of the first problem found.
+	pub fn validate(&self) -> Result<(), String> {
+		match *self {
+			ShapeDef::Circle { radius, ref position } => {
+				if !radius.is_finite() || radius < 0.0 || !position.is_valid() {
+					return Err(format!("invalid circle radius {} or position {:?}", radius, position));
+				}
+			},
+			ShapeDef::Box { hx, hy, ref center, angle } => {
+				if !hx.is_finite() || !hy.is_finite() || hx <= 0.0 || hy <= 0.0 || !center.is_valid() || !angle.is_finite() {
+					return Err(format!("invalid box extents {}, {} or transform", hx, hy));
+				}
+			},
+			ShapeDef::Polygon { ref vertices, radius } => {
+				let count = vertices.len();
+				if count < 3 || count > MAX_POLYGON_VERTICES as usize {
+					return Err(format!("polygon has {} vertices, expected 3 to {}", count, MAX_POLYGON_VERTICES));
+				}
+				if !vertices.iter().all(Vec2::is_valid) || !radius.is_finite() || radius < 0.0 {
+					return Err(String::from("polygon vertices or radius are not finite"));
+				}
+				if hull_area(vertices) <= EPSILON {
+					return Err(String::from("polygon is degenerate"));
+				}
+			},
+			ShapeDef::Chain { ref vertices } => {
+				if vertices.len() < 2 {
+					return Err(format!("chain has {} vertices, expected at least 2", vertices.len()));
+				}
+				if !vertices.iter().all(Vec2::is_valid) {
+					return Err(String::from("chain vertices are not finite"));
+				}
+				if vertices.windows(2).any(|v| (v[1] - v[0]).length_squared() <= LINEAR_SLOP * LINEAR_SLOP) {
+					return Err(String::from("chain vertices are too close together"));
+				}
+			},
+		}
+		Ok(())
+	}
+
+	/// Build the LiquidFun shape described by this definition.
+	/// @return the shape, or the problem found by validate.
+	pub fn to_shape(&self) -> Result<Box<Shape>, String> {
+		self.validate()?;
+		Ok(match *self {
+			ShapeDef::Circle { radius, ref position } => {
+				let mut shape = CircleShape::new();
+				shape.set_radius(radius);
+				shape.set_position(position);
+				Box::new(shape)
+			},
+			ShapeDef::Box { hx, hy, ref center, angle } => {
+				let mut shape = PolygonShape::new();
+				shape.set_as_box_oriented(hx, hy, center, angle);
+				Box::new(shape)
+			},
+			ShapeDef::Polygon { ref vertices, radius } => {
+				let mut shape = PolygonShape::new();
+				shape.set(vertices);
+				shape.set_radius(radius);
+				Box::new(shape)
+			},
+			ShapeDef::Chain { ref vertices } => {
+				let mut shape = ChainShape::new();
+				shape.create_chain(vertices, vertices.len() as Int32);
+				Box::new(shape)
+			},
+		})
+	}
+}
+
+#[cfg(feature = "serde")]
+fn polygon_radius() -> Float32 {
+	POLYGON_RADIUS
+}
+
+/// The area of the convex hull of the vertices after welding close vertices
+/// together, as b2PolygonShape::Set computes it.
+fn hull_area(vertices: &[Vec2]) -> Float32 {
+	let mut points: Vec<Vec2> = Vec::new();
+	for v in vertices {
+		if points.iter().all(|p| (*v - *p).length_squared() >= 0.5 * LINEAR_SLOP) {
+			points.push(*v);
+		}
+	}
+	if points.len() < 3 {
+		return 0.0;
+	}
+
+	// Andrew's monotone chain.
+	points.sort_by(|a, b| (a.x, a.y).partial_cmp(&(b.x, b.y)).unwrap());
+	let cross = |o: &Vec2, a: &Vec2, b: &Vec2| (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x);
+	let mut hull: Vec<Vec2> = Vec::new();
+	for pass in 0..2 {
+		let start = hull.len();
+		for i in 0..points.len() {
+			let p = if pass == 0 { points[i] } else { points[points.len() - 1 - i] };
+			while hull.len() >= start + 2 && cross(&hull[hull.len() - 2], &hull[hull.len() - 1], &p) <= 0.0 {
+				hull.pop();
+			}
+			hull.push(p);
+		}
+		hull.pop();
+	}
+
+	let mut area = 0.0;
+	for i in 0..hull.len() {
+		let (a, b) = (hull[i], hull[(i + 1) % hull.len()]);
+		area += a.x * b.y - b.x * a.y;
+	}
+	0.5 * area
+}
+
+impl<'a> From<&'a CircleShape> for ShapeDef {
+	fn from(shape: &'a CircleShape) -> ShapeDef {
+		ShapeDef::Circle { radius: shape.get_radius(), position: shape.get_position() }
+	}
+}
+
+impl<'a> From<&'a PolygonShape> for ShapeDef {
+	fn from(shape: &'a PolygonShape) -> ShapeDef {
+		ShapeDef::Polygon {
+			vertices: (0..shape.get_vertex_count()).map(|i| *shape.get_vertex(i)).collect(),
+			radius: shape.get_radius(),
+		}
+	}
+}
+
+impl<'a> From<&'a ChainShape> for ShapeDef {
+	fn from(shape: &'a ChainShape) -> ShapeDef {
+		ShapeDef::Chain { vertices: shape.get_vertices().to_vec() }
+	}
+}
@@ -1,8 +1,10 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use super::settings::*;
 
 /// A 2D column vector.
 #[repr(C)]
 #[derive(Debug, PartialEq, Default, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Vec2 {
     pub x: Float32,
     pub y: Float32,
@@ -25,5 +27,703 @@ impl Vec2 {
 	pub fn zero() -> Vec2 {
 		Vec2::default()
 	}
+
+	/// Set this vector to all zeros.
+	pub fn set_zero(&mut self) {
+		self.x = 0.0;
+		self.y = 0.0;
+	}
+
+	/// Get the length of this vector (the norm).
+	pub fn length(&self) -> f32 {
+		self.length_squared().sqrt()
+	}
+
+	/// Get the length squared. For performance, use this instead of
+	/// Vec2::length (if possible).
+	pub fn length_squared(&self) -> f32 {
+		self.x * self.x + self.y * self.y
+	}
+
+	/// Convert this vector into a unit vector. Returns the length.
+	pub fn normalize(&mut self) -> f32 {
+		let length = self.length();
+		if length < EPSILON {
+			return 0.0;
+		}
+		let inv_length = 1.0 / length;
+		self.x *= inv_length;
+		self.y *= inv_length;
+		length
+	}
+
+	/// Does this vector contain finite coordinates?
+	pub fn is_valid(&self) -> bool {
+		self.x.is_finite() && self.y.is_finite()
+	}
+
+	/// Get the skew vector such that dot(skew_vec, other) == cross(vec, other)
+	pub fn skew(&self) -> Vec2 {
+		Vec2::new(-self.y, self.x)
+	}
+
+	/// Perform the dot product on two vectors.
+	pub fn dot(&self, b: &Vec2) -> f32 {
+		self.x * b.x + self.y * b.y
+	}
+
+	/// Perform the cross product on two vectors. In 2D this produces a scalar.
+	pub fn cross(&self, b: &Vec2) -> f32 {
+		self.x * b.y - self.y * b.x
+	}
+
+	/// Perform the cross product on a vector and a scalar. In 2D this produces
+	/// a vector.
+	pub fn cross_scalar(&self, s: f32) -> Vec2 {
+		Vec2::new(s * self.y, -s * self.x)
+	}
+
+	/// Perform the cross product on a scalar and a vector. In 2D this produces
+	/// a vector.
+	pub fn scalar_cross(s: f32, a: &Vec2) -> Vec2 {
+		Vec2::new(-s * a.y, s * a.x)
+	}
+
+	/// Get the distance between two points.
+	pub fn distance(&self, b: &Vec2) -> f32 {
+		(*self - *b).length()
+	}
+
+	/// Get the squared distance between two points.
+	pub fn distance_squared(&self, b: &Vec2) -> f32 {
+		(*self - *b).length_squared()
+	}
+
+	/// Linearly interpolate between this vector (t = 0) and b (t = 1).
+	pub fn lerp(&self, b: &Vec2, t: f32) -> Vec2 {
+		*self + (*b - *self) * t
+	}
+
+	/// Rotate this vector by an angle in radians.
+	pub fn rotate(&self, angle: f32) -> Vec2 {
+		Rot::new(angle).mul_vec2(self)
+	}
+
+	/// Get the vector with the absolute value of each coordinate.
+	pub fn abs(&self) -> Vec2 {
+		Vec2::new(self.x.abs(), self.y.abs())
+	}
+
+	/// Get the component-wise minimum of two vectors.
+	pub fn min(&self, b: &Vec2) -> Vec2 {
+		Vec2::new(self.x.min(b.x), self.y.min(b.y))
+	}
+
+	/// Get the component-wise maximum of two vectors.
+	pub fn max(&self, b: &Vec2) -> Vec2 {
+		Vec2::new(self.x.max(b.x), self.y.max(b.y))
+	}
+}
+
+impl Neg for Vec2 {
+	type Output = Vec2;
+	fn neg(self) -> Vec2 {
+		Vec2::new(-self.x, -self.y)
+	}
+}
+
+impl Add for Vec2 {
+	type Output = Vec2;
+	fn add(self, b: Vec2) -> Vec2 {
+		Vec2::new(self.x + b.x, self.y + b.y)
+	}
+}
+
+impl Sub for Vec2 {
+	type Output = Vec2;
+	fn sub(self, b: Vec2) -> Vec2 {
+		Vec2::new(self.x - b.x, self.y - b.y)
+	}
+}
+
+impl Mul<f32> for Vec2 {
+	type Output = Vec2;
+	fn mul(self, s: f32) -> Vec2 {
+		Vec2::new(s * self.x, s * self.y)
+	}
+}
+
+impl Mul<Vec2> for f32 {
+	type Output = Vec2;
+	fn mul(self, a: Vec2) -> Vec2 {
+		a * self
+	}
+}
+
+impl Div<f32> for Vec2 {
+	type Output = Vec2;
+	fn div(self, s: f32) -> Vec2 {
+		Vec2::new(self.x / s, self.y / s)
+	}
+}
+
+impl AddAssign for Vec2 {
+	fn add_assign(&mut self, v: Vec2) {
+		self.x += v.x;
+		self.y += v.y;
+	}
+}
+
+impl SubAssign for Vec2 {
+	fn sub_assign(&mut self, v: Vec2) {
+		self.x -= v.x;
+		self.y -= v.y;
+	}
+}
+
+impl MulAssign<f32> for Vec2 {
+	fn mul_assign(&mut self, s: f32) {
+		self.x *= s;
+		self.y *= s;
+	}
+}
+
+impl DivAssign<f32> for Vec2 {
+	fn div_assign(&mut self, s: f32) {
+		self.x /= s;
+		self.y /= s;
+	}
+}
+
+impl From<(f32, f32)> for Vec2 {
+	fn from(v: (f32, f32)) -> Vec2 {
+		Vec2::new(v.0, v.1)
+	}
+}
+
+impl From<[f32; 2]> for Vec2 {
+	fn from(v: [f32; 2]) -> Vec2 {
+		Vec2::new(v[0], v[1])
+	}
+}
+
+impl From<Vec2> for (f32, f32) {
+	fn from(v: Vec2) -> (f32, f32) {
+		(v.x, v.y)
+	}
+}
+
+impl From<Vec2> for [f32; 2] {
+	fn from(v: Vec2) -> [f32; 2] {
+		[v.x, v.y]
+	}
+}
+
+/// A 2D column vector with 3 elements.
+#[repr(C)]
+#[derive(Debug, PartialEq, Default, Copy, Clone)]
+pub struct Vec3 {
+    pub x: Float32,
+    pub y: Float32,
+    pub z: Float32,
+}
+
+impl Vec3 {
+
+	/// Construct using coordinates.
+	pub fn new(x: f32, y: f32, z: f32) -> Vec3 {
+		Vec3 {x: x, y: y, z: z}
+	}
+
+	/// Set this vector to some specified coordinates.
+	pub fn set(&mut self, x: f32, y: f32, z: f32) {
+		self.x = x;
+		self.y = y;
+		self.z = z;
+	}
+
+	/// Construct a vector with all zero coordinates.
+	pub fn zero() -> Vec3 {
+		Vec3::default()
+	}
+
+	/// Get the length of this vector (the norm).
+	pub fn length(&self) -> f32 {
+		(self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+	}
+
+	/// Convert this vector into a unit vector. Returns the length.
+	pub fn normalize(&mut self) -> f32 {
+		let length = self.length();
+		if length < EPSILON {
+			return 0.0;
+		}
+		let inv_length = 1.0 / length;
+		self.x *= inv_length;
+		self.y *= inv_length;
+		self.z *= inv_length;
+		length
+	}
+
+	/// Perform the dot product on two vectors.
+	pub fn dot(&self, b: &Vec3) -> f32 {
+		self.x * b.x + self.y * b.y + self.z * b.z
+	}
+
+	/// Perform the cross product on two vectors.
+	pub fn cross(&self, b: &Vec3) -> Vec3 {
+		Vec3::new(self.y * b.z - self.z * b.y, self.z * b.x - self.x * b.z, self.x * b.y - self.y * b.x)
+	}
+}
+
+impl Neg for Vec3 {
+	type Output = Vec3;
+	fn neg(self) -> Vec3 {
+		Vec3::new(-self.x, -self.y, -self.z)
+	}
+}
+
+impl Add for Vec3 {
+	type Output = Vec3;
+	fn add(self, b: Vec3) -> Vec3 {
+		Vec3::new(self.x + b.x, self.y + b.y, self.z + b.z)
+	}
+}
+
+impl Sub for Vec3 {
+	type Output = Vec3;
+	fn sub(self, b: Vec3) -> Vec3 {
+		Vec3::new(self.x - b.x, self.y - b.y, self.z - b.z)
+	}
+}
+
+impl Mul<f32> for Vec3 {
+	type Output = Vec3;
+	fn mul(self, s: f32) -> Vec3 {
+		Vec3::new(s * self.x, s * self.y, s * self.z)
+	}
+}
+
+impl Mul<Vec3> for f32 {
+	type Output = Vec3;
+	fn mul(self, a: Vec3) -> Vec3 {
+		a * self
+	}
+}
+
+impl AddAssign for Vec3 {
+	fn add_assign(&mut self, v: Vec3) {
+		self.x += v.x;
+		self.y += v.y;
+		self.z += v.z;
+	}
+}
+
+impl SubAssign for Vec3 {
+	fn sub_assign(&mut self, v: Vec3) {
+		self.x -= v.x;
+		self.y -= v.y;
+		self.z -= v.z;
+	}
+}
+
+impl MulAssign<f32> for Vec3 {
+	fn mul_assign(&mut self, s: f32) {
+		self.x *= s;
+		self.y *= s;
+		self.z *= s;
+	}
+}
+
+/// A 2-by-2 matrix. Stored in column-major order.
+#[repr(C)]
+#[derive(Debug, PartialEq, Default, Copy, Clone)]
+pub struct Mat22 {
+    pub ex: Vec2,
+    pub ey: Vec2,
+}
+
+impl Mat22 {
+
+	/// Construct this matrix using columns.
+	pub fn new(c1: Vec2, c2: Vec2) -> Mat22 {
+		Mat22 { ex: c1, ey: c2 }
+	}
+
+	/// Construct this matrix using scalars.
+	pub fn from_scalars(a11: f32, a12: f32, a21: f32, a22: f32) -> Mat22 {
+		Mat22 { ex: Vec2::new(a11, a21), ey: Vec2::new(a12, a22) }
+	}
+
+	/// Construct the identity matrix.
+	pub fn identity() -> Mat22 {
+		Mat22::from_scalars(1.0, 0.0, 0.0, 1.0)
+	}
+
+	/// Initialize this matrix using columns.
+	pub fn set(&mut self, c1: Vec2, c2: Vec2) {
+		self.ex = c1;
+		self.ey = c2;
+	}
+
+	/// Set this to the identity matrix.
+	pub fn set_identity(&mut self) {
+		*self = Mat22::identity();
+	}
+
+	/// Set this matrix to all zeros.
+	pub fn set_zero(&mut self) {
+		*self = Mat22::default();
+	}
+
+	/// Compute the inverse of this matrix. Returns the zero matrix if singular.
+	pub fn get_inverse(&self) -> Mat22 {
+		let (a, b, c, d) = (self.ex.x, self.ey.x, self.ex.y, self.ey.y);
+		let mut det = a * d - b * c;
+		if det != 0.0 {
+			det = 1.0 / det;
+		}
+		Mat22::from_scalars(det * d, -det * b, -det * c, det * a)
+	}
+
+	/// Solve A * x = b, where b is a column vector. This is more efficient
+	/// than computing the inverse in one-shot cases.
+	pub fn solve(&self, b: &Vec2) -> Vec2 {
+		let (a11, a12, a21, a22) = (self.ex.x, self.ey.x, self.ex.y, self.ey.y);
+		let mut det = a11 * a22 - a12 * a21;
+		if det != 0.0 {
+			det = 1.0 / det;
+		}
+		Vec2::new(det * (a22 * b.x - a12 * b.y), det * (a11 * b.y - a21 * b.x))
+	}
+
+	/// Multiply a matrix times a vector. If a rotation matrix is provided,
+	/// then this transforms the vector from one frame to another.
+	pub fn mul_vec2(&self, v: &Vec2) -> Vec2 {
+		Vec2::new(self.ex.x * v.x + self.ey.x * v.y, self.ex.y * v.x + self.ey.y * v.y)
+	}
+
+	/// Multiply a matrix transpose times a vector. If a rotation matrix is provided,
+	/// then this transforms the vector from one frame to another (inverse transform).
+	pub fn mul_t_vec2(&self, v: &Vec2) -> Vec2 {
+		Vec2::new(v.dot(&self.ex), v.dot(&self.ey))
+	}
+
+	/// A * B
+	pub fn mul(&self, b: &Mat22) -> Mat22 {
+		Mat22::new(self.mul_vec2(&b.ex), self.mul_vec2(&b.ey))
+	}
+
+	/// A^T * B
+	pub fn mul_t(&self, b: &Mat22) -> Mat22 {
+		Mat22::new(self.mul_t_vec2(&b.ex), self.mul_t_vec2(&b.ey))
+	}
+
+	/// Get the matrix with the absolute value of each element.
+	pub fn abs(&self) -> Mat22 {
+		Mat22::from_scalars(self.ex.x.abs(), self.ey.x.abs(), self.ex.y.abs(), self.ey.y.abs())
+	}
+}
+
+impl Add for Mat22 {
+	type Output = Mat22;
+	fn add(self, b: Mat22) -> Mat22 {
+		Mat22::new(self.ex + b.ex, self.ey + b.ey)
+	}
+}
+
+/// A 3-by-3 matrix. Stored in column-major order.
+#[repr(C)]
+#[derive(Debug, PartialEq, Default, Copy, Clone)]
+pub struct Mat33 {
+    pub ex: Vec3,
+    pub ey: Vec3,
+    pub ez: Vec3,
+}
+
+impl Mat33 {
+
+	/// Construct this matrix using columns.
+	pub fn new(c1: Vec3, c2: Vec3, c3: Vec3) -> Mat33 {
+		Mat33 { ex: c1, ey: c2, ez: c3 }
+	}
+
+	/// Set this matrix to all zeros.
+	pub fn set_zero(&mut self) {
+		*self = Mat33::default();
+	}
+
+	/// Solve A * x = b, where b is a column vector. This is more efficient
+	/// than computing the inverse in one-shot cases.
+	pub fn solve33(&self, b: &Vec3) -> Vec3 {
+		let mut det = self.ex.dot(&self.ey.cross(&self.ez));
+		if det != 0.0 {
+			det = 1.0 / det;
+		}
+		Vec3::new(
+			det * b.dot(&self.ey.cross(&self.ez)),
+			det * self.ex.dot(&b.cross(&self.ez)),
+			det * self.ex.dot(&self.ey.cross(b)))
+	}
+
+	/// Solve A * x = b, where b is a column vector. This is more efficient
+	/// than computing the inverse in one-shot cases. Solve only the upper
+	/// 2-by-2 matrix equation.
+	pub fn solve22(&self, b: &Vec2) -> Vec2 {
+		let (a11, a12, a21, a22) = (self.ex.x, self.ey.x, self.ex.y, self.ey.y);
+		let mut det = a11 * a22 - a12 * a21;
+		if det != 0.0 {
+			det = 1.0 / det;
+		}
+		Vec2::new(det * (a22 * b.x - a12 * b.y), det * (a11 * b.y - a21 * b.x))
+	}
+
+	/// Get the inverse of this matrix as a 2-by-2.
+	/// Returns the zero matrix if singular.
+	pub fn get_inverse22(&self) -> Mat33 {
+		let (a, b, c, d) = (self.ex.x, self.ey.x, self.ex.y, self.ey.y);
+		let mut det = a * d - b * c;
+		if det != 0.0 {
+			det = 1.0 / det;
+		}
+		Mat33::new(
+			Vec3::new(det * d, -det * c, 0.0),
+			Vec3::new(-det * b, det * a, 0.0),
+			Vec3::zero())
+	}
+
+	/// Get the symmetric inverse of this matrix as a 3-by-3.
+	/// Returns the zero matrix if singular.
+	pub fn get_sym_inverse33(&self) -> Mat33 {
+		let mut det = self.ex.dot(&self.ey.cross(&self.ez));
+		if det != 0.0 {
+			det = 1.0 / det;
+		}
+
+		let (a11, a12, a13) = (self.ex.x, self.ey.x, self.ez.x);
+		let (a22, a23) = (self.ey.y, self.ez.y);
+		let a33 = self.ez.z;
+
+		let ex = Vec3::new(
+			det * (a22 * a33 - a23 * a23),
+			det * (a13 * a23 - a12 * a33),
+			det * (a12 * a23 - a13 * a22));
+		let ey = Vec3::new(
+			ex.y,
+			det * (a11 * a33 - a13 * a13),
+			det * (a13 * a12 - a11 * a23));
+		let ez = Vec3::new(
+			ex.z,
+			ey.z,
+			det * (a11 * a22 - a12 * a12));
+		Mat33::new(ex, ey, ez)
+	}
+
+	/// Multiply a matrix times a vector.
+	pub fn mul_vec3(&self, v: &Vec3) -> Vec3 {
+		v.x * self.ex + v.y * self.ey + v.z * self.ez
+	}
+
+	/// Multiply a matrix times a vector.
+	pub fn mul22(&self, v: &Vec2) -> Vec2 {
+		Vec2::new(self.ex.x * v.x + self.ey.x * v.y, self.ex.y * v.x + self.ey.y * v.y)
+	}
+}
+
+/// Rotation
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Rot {
+    /// Sine and cosine
+    pub s: Float32,
+    pub c: Float32,
 }
 
+impl Default for Rot {
+	fn default() -> Rot {
+		Rot::identity()
+	}
+}
+
+impl Rot {
+
+	/// Initialize from an angle in radians
+	pub fn new(angle: f32) -> Rot {
+		Rot { s: angle.sin(), c: angle.cos() }
+	}
+
+	/// Construct the identity rotation.
+	pub fn identity() -> Rot {
+		Rot { s: 0.0, c: 1.0 }
+	}
+
+	/// Set using an angle in radians.
+	pub fn set(&mut self, angle: f32) {
+		*self = Rot::new(angle);
+	}
+
+	/// Set to the identity rotation
+	pub fn set_identity(&mut self) {
+		*self = Rot::identity();
+	}
+
+	/// Get the angle in radians
+	pub fn get_angle(&self) -> f32 {
+		self.s.atan2(self.c)
+	}
+
+	/// Get the x-axis
+	pub fn get_x_axis(&self) -> Vec2 {
+		Vec2::new(self.c, self.s)
+	}
+
+	/// Get the u-axis
+	pub fn get_y_axis(&self) -> Vec2 {
+		Vec2::new(-self.s, self.c)
+	}
+
+	/// Multiply two rotations: q * r
+	pub fn mul(&self, r: &Rot) -> Rot {
+		Rot {
+			s: self.s * r.c + self.c * r.s,
+			c: self.c * r.c - self.s * r.s,
+		}
+	}
+
+	/// Transpose multiply two rotations: qT * r
+	pub fn mul_t(&self, r: &Rot) -> Rot {
+		Rot {
+			s: self.c * r.s - self.s * r.c,
+			c: self.c * r.c + self.s * r.s,
+		}
+	}
+
+	/// Rotate a vector
+	pub fn mul_vec2(&self, v: &Vec2) -> Vec2 {
+		Vec2::new(self.c * v.x - self.s * v.y, self.s * v.x + self.c * v.y)
+	}
+
+	/// Inverse rotate a vector
+	pub fn mul_t_vec2(&self, v: &Vec2) -> Vec2 {
+		Vec2::new(self.c * v.x + self.s * v.y, -self.s * v.x + self.c * v.y)
+	}
+}
+
+/// A transform contains translation and rotation. It is used to represent
+/// the position and orientation of rigid frames.
+#[repr(C)]
+#[derive(Debug, PartialEq, Default, Copy, Clone)]
+pub struct Transform {
+    pub p: Vec2,
+    pub q: Rot,
+}
+
+impl Transform {
+
+	/// Initialize using a position vector and a rotation.
+	pub fn new(position: Vec2, rotation: Rot) -> Transform {
+		Transform { p: position, q: rotation }
+	}
+
+	/// Construct the identity transform.
+	pub fn identity() -> Transform {
+		Transform::default()
+	}
+
+	/// Set this to the identity transform.
+	pub fn set_identity(&mut self) {
+		*self = Transform::identity();
+	}
+
+	/// Set this based on the position and angle.
+	pub fn set(&mut self, position: Vec2, angle: f32) {
+		self.p = position;
+		self.q.set(angle);
+	}
+
+	/// Transform a point.
+	pub fn mul_vec2(&self, v: &Vec2) -> Vec2 {
+		Vec2::new(
+			(self.q.c * v.x - self.q.s * v.y) + self.p.x,
+			(self.q.s * v.x + self.q.c * v.y) + self.p.y)
+	}
+
+	/// Inverse transform a point.
+	pub fn mul_t_vec2(&self, v: &Vec2) -> Vec2 {
+		let px = v.x - self.p.x;
+		let py = v.y - self.p.y;
+		Vec2::new(self.q.c * px + self.q.s * py, -self.q.s * px + self.q.c * py)
+	}
+
+	/// v2 = A.q.Rot(B.q.Rot(v1) + B.p) + A.p
+	///    = (A.q * B.q).Rot(v1) + A.q.Rot(B.p) + A.p
+	pub fn mul(&self, b: &Transform) -> Transform {
+		Transform {
+			p: self.q.mul_vec2(&b.p) + self.p,
+			q: self.q.mul(&b.q),
+		}
+	}
+
+	/// v2 = A.q' * (B.q * v1 + B.p - A.p)
+	///    = A.q' * B.q * v1 + A.q' * (B.p - A.p)
+	pub fn mul_t(&self, b: &Transform) -> Transform {
+		Transform {
+			p: self.q.mul_t_vec2(&(b.p - self.p)),
+			q: self.q.mul_t(&b.q),
+		}
+	}
+}
+
+/// This describes the motion of a body/shape for TOI computation.
+/// Shapes are defined with respect to the body origin, which may
+/// no coincide with the center of mass. However, to support dynamics
+/// we must interpolate the center of mass position.
+#[repr(C)]
+#[derive(Debug, PartialEq, Default, Copy, Clone)]
+pub struct Sweep {
+    /// local center of mass position
+    pub local_center: Vec2,
+
+    /// center world positions
+    pub c0: Vec2,
+    pub c: Vec2,
+
+    /// world angles
+    pub a0: Float32,
+    pub a: Float32,
+
+    /// Fraction of the current time step in the range [0,1]
+    /// c0 and a0 are the positions at alpha0.
+    pub alpha0: Float32,
+}
+
+impl Sweep {
+
+	/// Get the interpolated transform at a specific time.
+	/// @param beta is a factor in [0,1], where 0 indicates alpha0.
+	pub fn get_transform(&self, beta: f32) -> Transform {
+		let mut xf = Transform::default();
+		xf.p = (1.0 - beta) * self.c0 + beta * self.c;
+		xf.q.set((1.0 - beta) * self.a0 + beta * self.a);
+		xf.p -= xf.q.mul_vec2(&self.local_center);
+		xf
+	}
+
+	/// Advance the sweep forward, yielding a new initial state.
+	/// @param alpha the new initial time.
+	pub fn advance(&mut self, alpha: f32) {
+		debug_assert!(self.alpha0 < 1.0);
+		let beta = (alpha - self.alpha0) / (1.0 - self.alpha0);
+		self.c0 += beta * (self.c - self.c0);
+		self.a0 += beta * (self.a - self.a0);
+		self.alpha0 = alpha;
+	}
+
+	/// Normalize the angles.
+	pub fn normalize(&mut self) {
+		let two_pi = 2.0 * PI;
+		let d = two_pi * (self.a0 / two_pi).floor();
+		self.a0 -= d;
+		self.a -= d;
+	}
+}
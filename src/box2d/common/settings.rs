@@ -5,4 +5,17 @@ pub type Int16 = c_short;
 pub type Int32 = c_int;
 pub type UInt16 = c_ushort;
 pub type UInt32 = c_uint;
-pub type UInt8 = c_uchar;
\ No newline at end of file
+pub type UInt8 = c_uchar;
+
+pub const MAX_FLOAT: Float32 = ::std::f32::MAX;
+pub const EPSILON: Float32 = ::std::f32::EPSILON;
+pub const PI: Float32 = ::std::f32::consts::PI;
+
+/// The maximum number of vertices on a convex polygon.
+pub const MAX_POLYGON_VERTICES: Int32 = 8;
+
+/// A small length used as a collision and constraint tolerance.
+pub const LINEAR_SLOP: Float32 = 0.005;
+
+/// The radius of the polygon/edge shape skin.
+pub const POLYGON_RADIUS: Float32 = 2.0 * LINEAR_SLOP;
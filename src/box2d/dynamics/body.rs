@@ -1,6 +1,6 @@
 use libc::size_t;
-use std::mem::transmute;
 use super::fixture::*;
+use super::joints::*;
 use super::super::collision::shapes::shape::*;
 use super::super::common::math::*;
 use super::super::common::settings::*;
@@ -12,6 +12,7 @@ use super::world::*;
 /// dynamic: positive mass, non-zero velocity determined by forces, moved by solver
 #[repr(C)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BodyType {
 	StaticBody = 0,
 	KinematicBody,
@@ -22,6 +23,8 @@ pub enum BodyType {
 /// You can safely re-use body definitions. Shapes are added to a body after construction.
 #[repr(C)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct BodyDef {
 
     /// The body type: static, kinematic, or dynamic.
@@ -72,6 +75,7 @@ pub struct BodyDef {
     pub active: bool,
 
     /// Use this to store application specific body data.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub user_data: size_t,
 
     /// Scale the gravity applied to this body.
@@ -103,17 +107,20 @@ pub enum B2Body {}
 
 extern {
     fn b2Body_CreateFixture_FromShape(this: *mut B2Body, shape: *const B2Shape, density: Float32) -> *mut B2Fixture;
-    fn b2Body_CreateFixture(this: *mut B2Body, def: *mut FixtureDef) -> *mut B2Fixture;
+    fn b2Body_CreateFixture(this: *mut B2Body, def: *const B2FixtureDef) -> *mut B2Fixture;
     fn b2Body_GetAngle(this: *const B2Body) -> Float32;
+    fn b2Body_GetTransform(this: *const B2Body) -> *const Transform;
     fn b2Body_GetFixtureList(this: *mut B2Body) -> *mut B2Fixture;
+    fn b2Body_GetJointList(this: *mut B2Body) -> *mut B2JointEdge;
     fn b2Body_GetNext(this: *mut B2Body) -> *mut B2Body;
-    fn b2Body_GetPosition(this: *const B2Body) -> &Vec2;
+    fn b2Body_GetPosition(this: *const B2Body) -> &'static Vec2;
     fn b2Body_GetUserData(this: *const B2Body) -> usize;
     fn b2Body_GetWorld(this: *const B2Body) -> *mut B2World;
     fn b2Body_GetLocalPoint(this: *const B2Body, worldPoint: &Vec2) -> Vec2;
+    fn b2Body_GetLocalVector(this: *const B2Body, worldVector: &Vec2) -> Vec2;
     fn b2Body_SetTransform(this: *mut B2Body, position: &Vec2, angle: Float32);
     fn b2Body_SetLinearVelocity(this: *mut B2Body, v: &Vec2);
-    fn b2Body_GetLinearVelocity(this: *const B2Body) -> &Vec2;
+    fn b2Body_GetLinearVelocity(this: *const B2Body) -> &'static Vec2;
 }
 
 /// A rigid body. These are created via b2World::CreateBody.
@@ -132,9 +139,11 @@ impl Body {
     /// Contacts are not created until the next time step.
     /// @param def the fixture definition.
     /// @warning This function is locked during callbacks.
+    /// @panics if the shape definition is invalid, see ShapeDef::validate.
     pub fn create_fixture(&self, def: &FixtureDef) -> Fixture {
+        let shape = def.shape.to_shape().unwrap_or_else(|e| panic!("invalid fixture shape: {}", e));
         unsafe {
-            Fixture { ptr: b2Body_CreateFixture(self.ptr, transmute(def)) }
+            Fixture { ptr: b2Body_CreateFixture(self.ptr, &B2FixtureDef::from(def, &*shape)) }
         }
     }
 
@@ -159,6 +168,14 @@ impl Body {
         }
     }
 
+    /// Get the body transform for the body's origin.
+    /// @return the world transform of the body's origin.
+    pub fn get_transform(&self) -> &Transform {
+        unsafe {
+            &*b2Body_GetTransform(self.ptr)
+        }
+    }
+
     /// Get the list of all fixtures attached to this body.
     pub fn get_fixture_list(&self) -> Option<Fixture> {
         let ptr;
@@ -173,6 +190,20 @@ impl Body {
         }
     }    
 
+    /// Get the list of all joints attached to this body.
+    pub fn get_joint_list(&self) -> Option<JointEdge> {
+        let ptr;
+        unsafe {
+            ptr = b2Body_GetJointList(self.ptr);
+        }
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(JointEdge { ptr: ptr })
+        }
+    }
+
     /// Get the next body in the world's body list.
     pub fn get_next(&self) -> Option<Body> {
         let ptr: *mut B2Body;
@@ -206,7 +237,7 @@ impl Body {
     /// Get the parent world of this body.
     pub fn get_world(&self) -> World {
         unsafe {
-            World { ptr: b2Body_GetWorld(self.ptr) }
+            World::from_ptr(b2Body_GetWorld(self.ptr))
         }
     }
 
@@ -216,6 +247,15 @@ impl Body {
         }
     }
 
+    /// Gets a local vector given a world vector.
+    /// @param a vector in world coordinates.
+    /// @return the corresponding local vector.
+    pub fn get_local_vector(&self, world_vector: &Vec2) -> Vec2 {
+        unsafe {
+            b2Body_GetLocalVector(self.ptr, world_vector)
+        }
+    }
+
     pub fn set_transform(&mut self, position: &Vec2, angle: f32) {
         unsafe {
             b2Body_SetTransform(self.ptr, position, angle)
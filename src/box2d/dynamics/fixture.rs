@@ -4,7 +4,9 @@ use super::super::common::settings::*;
 
 /// This holds contact filtering data.
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Filter {
     /// The collision category bits. Normally you would just set one bit.
     pub category_bits: UInt16,
@@ -31,36 +33,44 @@ impl Default for Filter {
 
 /// A fixture definition is used to create a fixture. This class defines an
 /// abstract fixture definition. You can reuse fixture definitions safely.
-#[repr(C)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FixtureDef {
-    /// The shape, this must be set. The shape will be cloned, so you
-    /// can create the shape on the stack.
-    pub shape: *mut shape::B2Shape,
+    /// The shape, this must be set. Unlike the C++ API the definition owns a
+    /// description of the shape, the LiquidFun shape is built from it when the
+    /// fixture is created.
+    pub shape: shape::ShapeDef,
 
     /// Use this to store application specific fixture data.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub user_data: size_t,
 
     /// The friction coefficient, usually in the range [0,1].    
+    #[cfg_attr(feature = "serde", serde(default))]
     pub friction: Float32,
 
     /// The restitution (elasticity) usually in the range [0,1].
+    #[cfg_attr(feature = "serde", serde(default))]
     pub restitution: Float32,
 
     /// The density, usually in kg/m^2.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub density: Float32,
 
     /// A sensor shape collects contact information but never generates a collision
     /// response.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub is_sensor: bool,
 
     /// Contact filtering data.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub filter: Filter
 }
 
 impl FixtureDef {
-    pub fn new(shape: &shape::Shape) -> FixtureDef {
+    pub fn new<S: Into<shape::ShapeDef>>(shape: S) -> FixtureDef {
         FixtureDef {
-            shape: shape.handle(),
+            shape: shape.into(),
             user_data: 0,
             friction: 0.0,
             restitution: 0.0,
@@ -71,6 +81,31 @@ impl FixtureDef {
     }
 }
 
+#[repr(C)]
+pub struct B2FixtureDef {
+    shape: *const shape::B2Shape,
+    user_data: size_t,
+    friction: Float32,
+    restitution: Float32,
+    density: Float32,
+    is_sensor: bool,
+    filter: Filter
+}
+
+impl B2FixtureDef {
+    pub fn from(fd: &FixtureDef, shape: &shape::Shape) -> B2FixtureDef {
+        B2FixtureDef {
+            shape: shape.handle(),
+            user_data: fd.user_data,
+            friction: fd.friction,
+            restitution: fd.restitution,
+            density: fd.density,
+            is_sensor: fd.is_sensor,
+            filter: fd.filter
+        }
+    }
+}
+
 pub enum B2Fixture {}
 
 extern {
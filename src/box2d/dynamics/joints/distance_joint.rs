@@ -0,0 +1,209 @@
+use libc::size_t;
+use super::super::super::common::math::*;
+use super::super::super::common::settings::*;
+use super::super::super::dynamics::body::Body;
+
+use super::{B2JointDef, Sealed, B2Joint, Joint, JointType, JointDef};
+
+/// Distance joint definition. This requires defining an
+/// anchor point on both bodies and the non-zero length of the
+/// distance joint. The definition uses local anchor points
+/// so that the initial configuration can violate the constraint
+/// slightly. This helps when saving and loading a game.
+/// @warning Do not use a zero or short length.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct DistanceJointDef {
+
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub user_data: size_t,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_a: Option<Body>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_b: Option<Body>,
+	pub collide_connected: bool,
+
+	/// The local anchor point relative to bodyA's origin.
+	pub local_anchor_a: Vec2,
+
+	/// The local anchor point relative to bodyB's origin.
+	pub local_anchor_b: Vec2,
+
+	/// The natural length between the anchor points.
+	pub length: Float32,
+
+	/// The mass-spring-damper frequency in Hertz. A value of 0
+	/// disables softness.
+	pub frequency_hz: Float32,
+
+	/// The damping ratio. 0 = no damping, 1 = critical damping.
+	pub damping_ratio: Float32,
+}
+
+impl Default for DistanceJointDef {
+	fn default() -> DistanceJointDef {
+		DistanceJointDef {
+			user_data: 0,
+			body_a: None,
+			body_b: None,
+			collide_connected: false,
+			local_anchor_a: Vec2::zero(),
+			local_anchor_b: Vec2::zero(),
+			length: 1.0,
+			frequency_hz: 0.0,
+			damping_ratio: 0.0,
+		}
+	}
+}
+
+impl DistanceJointDef {
+	pub fn new() -> DistanceJointDef {
+		DistanceJointDef::default()
+	}
+
+	/// Initialize the bodies, anchors, and length using the world
+	/// anchors.
+	pub fn initialize(&mut self, body_a: Body, body_b: Body, anchor_a: &Vec2, anchor_b: &Vec2) {
+		self.local_anchor_a = body_a.get_local_point(anchor_a);
+		self.local_anchor_b = body_b.get_local_point(anchor_b);
+		self.length = anchor_a.distance(anchor_b);
+		self.body_a = Some(body_a);
+		self.body_b = Some(body_b);
+	}
+}
+
+/// The layout of c_b2DistanceJointDef.
+#[repr(C)]
+pub struct B2DistanceJointDef {
+	base: B2JointDef,
+	local_anchor_a: Vec2,
+	local_anchor_b: Vec2,
+	length: Float32,
+	frequency_hz: Float32,
+	damping_ratio: Float32,
+}
+
+impl Sealed for DistanceJointDef {}
+
+impl JointDef for DistanceJointDef {
+	type B2Def = B2DistanceJointDef;
+
+	fn joint_type(&self) -> JointType {
+		JointType::DistanceJoint
+	}
+
+	fn to_b2(&self) -> B2DistanceJointDef {
+		B2DistanceJointDef {
+			base: B2JointDef::new(self.joint_type(), self.user_data, &self.body_a, &self.body_b, self.collide_connected),
+			local_anchor_a: self.local_anchor_a,
+			local_anchor_b: self.local_anchor_b,
+			length: self.length,
+			frequency_hz: self.frequency_hz,
+			damping_ratio: self.damping_ratio,
+		}
+	}
+}
+
+pub enum B2DistanceJoint {}
+
+extern {
+    fn b2DistanceJoint_Upcast(this: *mut B2DistanceJoint) -> *mut B2Joint;
+    fn b2DistanceJoint_GetLocalAnchorA(this: *const B2DistanceJoint) -> *const Vec2;
+    fn b2DistanceJoint_GetLocalAnchorB(this: *const B2DistanceJoint) -> *const Vec2;
+    fn b2DistanceJoint_SetLength(this: *mut B2DistanceJoint, length: Float32);
+    fn b2DistanceJoint_GetLength(this: *const B2DistanceJoint) -> Float32;
+    fn b2DistanceJoint_SetFrequency(this: *mut B2DistanceJoint, hz: Float32);
+    fn b2DistanceJoint_GetFrequency(this: *const B2DistanceJoint) -> Float32;
+    fn b2DistanceJoint_SetDampingRatio(this: *mut B2DistanceJoint, ratio: Float32);
+    fn b2DistanceJoint_GetDampingRatio(this: *const B2DistanceJoint) -> Float32;
+}
+
+/// A distance joint constrains two points on two bodies
+/// to remain at a fixed distance from each other. You can view
+/// this as a massless, rigid rod.
+#[allow(raw_pointer_derive)]
+#[derive(Clone, Debug)]
+pub struct DistanceJoint {
+	pub ptr: *mut B2DistanceJoint
+}
+
+impl DistanceJoint {
+
+	/// The local anchor point relative to bodyA's origin.
+    pub fn get_local_anchor_a(&self) -> Vec2 {
+        unsafe {
+            *b2DistanceJoint_GetLocalAnchorA(self.ptr)
+        }
+    }
+
+	/// The local anchor point relative to bodyB's origin.
+    pub fn get_local_anchor_b(&self) -> Vec2 {
+        unsafe {
+            *b2DistanceJoint_GetLocalAnchorB(self.ptr)
+        }
+    }
+
+	/// Set the natural length.
+	/// Manipulating the length can lead to non-physical behavior when the frequency is zero.
+    pub fn set_length(&self, length: f32) {
+        unsafe {
+            b2DistanceJoint_SetLength(self.ptr, length);
+        }
+    }
+
+	/// Get the natural length.
+    pub fn get_length(&self) -> f32 {
+        unsafe {
+            b2DistanceJoint_GetLength(self.ptr)
+        }
+    }
+
+	/// Set frequency in Hz.
+    pub fn set_frequency(&self, hz: f32) {
+        unsafe {
+            b2DistanceJoint_SetFrequency(self.ptr, hz);
+        }
+    }
+
+	/// Get frequency in Hz.
+    pub fn get_frequency(&self) -> f32 {
+        unsafe {
+            b2DistanceJoint_GetFrequency(self.ptr)
+        }
+    }
+
+	/// Set damping ratio.
+    pub fn set_damping_ratio(&self, ratio: f32) {
+        unsafe {
+            b2DistanceJoint_SetDampingRatio(self.ptr, ratio);
+        }
+    }
+
+	/// Get damping ratio.
+    pub fn get_damping_ratio(&self) -> f32 {
+        unsafe {
+            b2DistanceJoint_GetDampingRatio(self.ptr)
+        }
+    }
+
+}
+
+impl From<DistanceJoint> for Joint {
+	fn from(joint: DistanceJoint) -> Joint {
+		unsafe {
+			Joint { ptr: b2DistanceJoint_Upcast(joint.ptr) }
+		}
+	}
+}
+
+impl Joint {
+	/// Downcast to a distance joint.
+	/// @return the distance joint or None if this is another type of joint.
+	pub fn as_distance_joint(&self) -> Option<DistanceJoint> {
+		match self.get_type() {
+			JointType::DistanceJoint => Some(DistanceJoint { ptr: self.ptr as *mut B2DistanceJoint }),
+			_ => None
+		}
+	}
+}
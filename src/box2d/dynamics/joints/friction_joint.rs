@@ -0,0 +1,177 @@
+use libc::size_t;
+use super::super::super::common::math::*;
+use super::super::super::common::settings::*;
+use super::super::super::dynamics::body::Body;
+
+use super::{B2JointDef, Sealed, B2Joint, Joint, JointType, JointDef};
+
+/// Friction joint definition.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct FrictionJointDef {
+
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub user_data: size_t,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_a: Option<Body>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_b: Option<Body>,
+	pub collide_connected: bool,
+
+	/// The local anchor point relative to bodyA's origin.
+	pub local_anchor_a: Vec2,
+
+	/// The local anchor point relative to bodyB's origin.
+	pub local_anchor_b: Vec2,
+
+	/// The maximum friction force in N.
+	pub max_force: Float32,
+
+	/// The maximum friction torque in N-m.
+	pub max_torque: Float32,
+}
+
+impl Default for FrictionJointDef {
+	fn default() -> FrictionJointDef {
+		FrictionJointDef {
+			user_data: 0,
+			body_a: None,
+			body_b: None,
+			collide_connected: false,
+			local_anchor_a: Vec2::zero(),
+			local_anchor_b: Vec2::zero(),
+			max_force: 0.0,
+			max_torque: 0.0,
+		}
+	}
+}
+
+impl FrictionJointDef {
+	pub fn new() -> FrictionJointDef {
+		FrictionJointDef::default()
+	}
+
+	/// Initialize the bodies and anchors using a world anchor point.
+	pub fn initialize(&mut self, body_a: Body, body_b: Body, anchor: &Vec2) {
+		self.local_anchor_a = body_a.get_local_point(anchor);
+		self.local_anchor_b = body_b.get_local_point(anchor);
+		self.body_a = Some(body_a);
+		self.body_b = Some(body_b);
+	}
+}
+
+/// The layout of c_b2FrictionJointDef.
+#[repr(C)]
+pub struct B2FrictionJointDef {
+	base: B2JointDef,
+	local_anchor_a: Vec2,
+	local_anchor_b: Vec2,
+	max_force: Float32,
+	max_torque: Float32,
+}
+
+impl Sealed for FrictionJointDef {}
+
+impl JointDef for FrictionJointDef {
+	type B2Def = B2FrictionJointDef;
+
+	fn joint_type(&self) -> JointType {
+		JointType::FrictionJoint
+	}
+
+	fn to_b2(&self) -> B2FrictionJointDef {
+		B2FrictionJointDef {
+			base: B2JointDef::new(self.joint_type(), self.user_data, &self.body_a, &self.body_b, self.collide_connected),
+			local_anchor_a: self.local_anchor_a,
+			local_anchor_b: self.local_anchor_b,
+			max_force: self.max_force,
+			max_torque: self.max_torque,
+		}
+	}
+}
+
+pub enum B2FrictionJoint {}
+
+extern {
+    fn b2FrictionJoint_Upcast(this: *mut B2FrictionJoint) -> *mut B2Joint;
+    fn b2FrictionJoint_GetLocalAnchorA(this: *const B2FrictionJoint) -> *const Vec2;
+    fn b2FrictionJoint_GetLocalAnchorB(this: *const B2FrictionJoint) -> *const Vec2;
+    fn b2FrictionJoint_SetMaxForce(this: *mut B2FrictionJoint, force: Float32);
+    fn b2FrictionJoint_GetMaxForce(this: *const B2FrictionJoint) -> Float32;
+    fn b2FrictionJoint_SetMaxTorque(this: *mut B2FrictionJoint, torque: Float32);
+    fn b2FrictionJoint_GetMaxTorque(this: *const B2FrictionJoint) -> Float32;
+}
+
+/// Friction joint. This is used for top-down friction.
+/// It provides 2D translational friction and angular friction.
+#[allow(raw_pointer_derive)]
+#[derive(Clone, Debug)]
+pub struct FrictionJoint {
+	pub ptr: *mut B2FrictionJoint
+}
+
+impl FrictionJoint {
+
+	/// The local anchor point relative to bodyA's origin.
+    pub fn get_local_anchor_a(&self) -> Vec2 {
+        unsafe {
+            *b2FrictionJoint_GetLocalAnchorA(self.ptr)
+        }
+    }
+
+	/// The local anchor point relative to bodyB's origin.
+    pub fn get_local_anchor_b(&self) -> Vec2 {
+        unsafe {
+            *b2FrictionJoint_GetLocalAnchorB(self.ptr)
+        }
+    }
+
+	/// Set the maximum friction force in N.
+    pub fn set_max_force(&self, force: f32) {
+        unsafe {
+            b2FrictionJoint_SetMaxForce(self.ptr, force);
+        }
+    }
+
+	/// Get the maximum friction force in N.
+    pub fn get_max_force(&self) -> f32 {
+        unsafe {
+            b2FrictionJoint_GetMaxForce(self.ptr)
+        }
+    }
+
+	/// Set the maximum friction torque in N*m.
+    pub fn set_max_torque(&self, torque: f32) {
+        unsafe {
+            b2FrictionJoint_SetMaxTorque(self.ptr, torque);
+        }
+    }
+
+	/// Get the maximum friction torque in N*m.
+    pub fn get_max_torque(&self) -> f32 {
+        unsafe {
+            b2FrictionJoint_GetMaxTorque(self.ptr)
+        }
+    }
+
+}
+
+impl From<FrictionJoint> for Joint {
+	fn from(joint: FrictionJoint) -> Joint {
+		unsafe {
+			Joint { ptr: b2FrictionJoint_Upcast(joint.ptr) }
+		}
+	}
+}
+
+impl Joint {
+	/// Downcast to a friction joint.
+	/// @return the friction joint or None if this is another type of joint.
+	pub fn as_friction_joint(&self) -> Option<FrictionJoint> {
+		match self.get_type() {
+			JointType::FrictionJoint => Some(FrictionJoint { ptr: self.ptr as *mut B2FrictionJoint }),
+			_ => None
+		}
+	}
+}
@@ -0,0 +1,161 @@
+use libc::size_t;
+use super::super::super::common::settings::*;
+use super::super::super::dynamics::body::Body;
+
+use super::{B2JointDef, Sealed, B2Joint, Joint, JointType, JointDef};
+
+/// Gear joint definition. This definition requires two existing
+/// revolute or prismatic joints (any combination will work).
+/// Any joint handle converts into the Joint base with `into()`.
+#[derive(Debug)]
+pub struct GearJointDef {
+
+	pub user_data: size_t,
+	pub body_a: Option<Body>,
+	pub body_b: Option<Body>,
+	pub collide_connected: bool,
+
+	/// The first revolute/prismatic joint attached to the gear joint.
+	pub joint1: Option<Joint>,
+
+	/// The second revolute/prismatic joint attached to the gear joint.
+	pub joint2: Option<Joint>,
+
+	/// The gear ratio.
+	/// @see GearJoint for explanation.
+	pub ratio: Float32,
+}
+
+impl Default for GearJointDef {
+	fn default() -> GearJointDef {
+		GearJointDef {
+			user_data: 0,
+			body_a: None,
+			body_b: None,
+			collide_connected: false,
+			joint1: None,
+			joint2: None,
+			ratio: 1.0,
+		}
+	}
+}
+
+impl GearJointDef {
+	pub fn new() -> GearJointDef {
+		GearJointDef::default()
+	}
+}
+
+/// The layout of c_b2GearJointDef.
+#[repr(C)]
+pub struct B2GearJointDef {
+	base: B2JointDef,
+	joint1: *mut B2Joint,
+	joint2: *mut B2Joint,
+	ratio: Float32,
+}
+
+impl Sealed for GearJointDef {}
+
+impl JointDef for GearJointDef {
+	type B2Def = B2GearJointDef;
+
+	fn joint_type(&self) -> JointType {
+		JointType::GearJoint
+	}
+
+	fn to_b2(&self) -> B2GearJointDef {
+		B2GearJointDef {
+			base: B2JointDef::new(self.joint_type(), self.user_data, &self.body_a, &self.body_b, self.collide_connected),
+			joint1: geared_joint(&self.joint1, "joint1"),
+			joint2: geared_joint(&self.joint2, "joint2"),
+			ratio: self.ratio,
+		}
+	}
+}
+
+/// LiquidFun only gears revolute and prismatic joints, check the joint before
+/// handing it over.
+fn geared_joint(joint: &Option<Joint>, name: &str) -> *mut B2Joint {
+	let joint = joint.as_ref().unwrap_or_else(|| panic!("gear joint requires {}", name));
+	match joint.get_type() {
+		JointType::RevoluteJoint | JointType::PrismaticJoint => joint.ptr,
+		joint_type => panic!("gear joint {} is a {:?}, expected a revolute or prismatic joint", name, joint_type),
+	}
+}
+
+pub enum B2GearJoint {}
+
+extern {
+    fn b2GearJoint_Upcast(this: *mut B2GearJoint) -> *mut B2Joint;
+    fn b2GearJoint_GetJoint1(this: *mut B2GearJoint) -> *mut B2Joint;
+    fn b2GearJoint_GetJoint2(this: *mut B2GearJoint) -> *mut B2Joint;
+    fn b2GearJoint_SetRatio(this: *mut B2GearJoint, ratio: Float32);
+    fn b2GearJoint_GetRatio(this: *const B2GearJoint) -> Float32;
+}
+
+/// A gear joint is used to connect two joints together. Either joint
+/// can be a revolute or prismatic joint. You specify a gear ratio
+/// to bind the motions together:
+/// coordinate1 + ratio * coordinate2 = constant
+/// The ratio can be negative or positive. If one joint is a revolute joint
+/// and the other joint is a prismatic joint, then the ratio will have units
+/// of length or units of 1/length.
+/// @warning You have to manually destroy the gear joint if joint1 or joint2
+/// is destroyed.
+#[allow(raw_pointer_derive)]
+#[derive(Clone, Debug)]
+pub struct GearJoint {
+	pub ptr: *mut B2GearJoint
+}
+
+impl GearJoint {
+
+	/// Get the first joint.
+    pub fn get_joint1(&self) -> Joint {
+        unsafe {
+            Joint { ptr: b2GearJoint_GetJoint1(self.ptr) }
+        }
+    }
+
+	/// Get the second joint.
+    pub fn get_joint2(&self) -> Joint {
+        unsafe {
+            Joint { ptr: b2GearJoint_GetJoint2(self.ptr) }
+        }
+    }
+
+	/// Set the gear ratio.
+    pub fn set_ratio(&self, ratio: f32) {
+        unsafe {
+            b2GearJoint_SetRatio(self.ptr, ratio);
+        }
+    }
+
+	/// Get the gear ratio.
+    pub fn get_ratio(&self) -> f32 {
+        unsafe {
+            b2GearJoint_GetRatio(self.ptr)
+        }
+    }
+
+}
+
+impl From<GearJoint> for Joint {
+	fn from(joint: GearJoint) -> Joint {
+		unsafe {
+			Joint { ptr: b2GearJoint_Upcast(joint.ptr) }
+		}
+	}
+}
+
+impl Joint {
+	/// Downcast to a gear joint.
+	/// @return the gear joint or None if this is another type of joint.
+	pub fn as_gear_joint(&self) -> Option<GearJoint> {
+		match self.get_type() {
+			JointType::GearJoint => Some(GearJoint { ptr: self.ptr as *mut B2GearJoint }),
+			_ => None
+		}
+	}
+}
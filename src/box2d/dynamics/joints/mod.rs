@@ -1,10 +1,24 @@
 use libc::size_t;
-use super::super::dynamics::body::{Body};
+use std::ptr;
+use super::super::common::math::*;
+use super::super::common::settings::*;
+use super::super::dynamics::body::{Body, B2Body};
 
+pub mod distance_joint;
+pub mod friction_joint;
+pub mod gear_joint;
+pub mod motor_joint;
+pub mod mouse_joint;
+pub mod prismatic_joint;
+pub mod pulley_joint;
 pub mod revolute_joint;
+pub mod rope_joint;
+pub mod weld_joint;
+pub mod wheel_joint;
 
 #[repr(C)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum JointType {
 	UnknownJoint = 0,
 	RevoluteJoint,
@@ -20,32 +34,241 @@ pub enum JointType {
 	MotorJoint
 }
 
-/// Joint definitions are used to construct joints.
-#[derive(Debug)]
-pub struct JointDef {
-	pub joint_type: JointType,
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LimitState {
+	InactiveLimit = 0,
+	AtLowerLimit,
+	AtUpperLimit,
+	EqualLimits
+}
+
+pub enum B2Joint {}
+
+/// The base joint class. Joints are used to constraint two bodies together in
+/// various fashions. Some joints also feature limits and motors.
+/// Every concrete joint handle converts into this base with `From`/`Into`,
+/// and the base converts back with the `as_*` methods of each joint module.
+#[allow(raw_pointer_derive)]
+#[derive(Clone, Debug)]
+pub struct Joint {
+	pub ptr: *mut B2Joint
+}
+
+/// A joint edge is used to connect bodies and joints together
+/// in a joint graph where each body is a node and each joint
+/// is an edge. A joint edge belongs to a doubly linked list
+/// maintained in each attached body. Each joint has two joint
+/// nodes, one for each attached body.
+#[repr(C)]
+pub struct B2JointEdge {
+	other: *mut B2Body,
+	joint: *mut B2Joint,
+	prev: *mut B2JointEdge,
+	next: *mut B2JointEdge,
+}
+
+#[allow(raw_pointer_derive)]
+#[derive(Clone, Debug)]
+pub struct JointEdge {
+	pub ptr: *mut B2JointEdge
+}
+
+extern {
+    fn b2Joint_GetType(this: *const B2Joint) -> JointType;
+    fn b2Joint_GetBodyA(this: *mut B2Joint) -> *mut B2Body;
+    fn b2Joint_GetBodyB(this: *mut B2Joint) -> *mut B2Body;
+    fn b2Joint_GetAnchorA(this: *const B2Joint) -> Vec2;
+    fn b2Joint_GetAnchorB(this: *const B2Joint) -> Vec2;
+    fn b2Joint_GetReactionForce(this: *const B2Joint, inv_dt: Float32) -> Vec2;
+    fn b2Joint_GetReactionTorque(this: *const B2Joint, inv_dt: Float32) -> Float32;
+    fn b2Joint_GetNext(this: *mut B2Joint) -> *mut B2Joint;
+    fn b2Joint_GetUserData(this: *const B2Joint) -> size_t;
+    fn b2Joint_IsActive(this: *const B2Joint) -> bool;
+    fn b2Joint_GetCollideConnected(this: *const B2Joint) -> bool;
+}
+
+impl Joint {
+
+	/// Get the type of the concrete joint.
+    pub fn get_type(&self) -> JointType {
+        unsafe {
+            b2Joint_GetType(self.ptr)
+        }
+    }
+
+	/// Get the first body attached to this joint.
+    pub fn get_body_a(&self) -> Body {
+        unsafe {
+            Body { ptr: b2Joint_GetBodyA(self.ptr) }
+        }
+    }
+
+	/// Get the second body attached to this joint.
+    pub fn get_body_b(&self) -> Body {
+        unsafe {
+            Body { ptr: b2Joint_GetBodyB(self.ptr) }
+        }
+    }
+
+	/// Get the anchor point on bodyA in world coordinates.
+    pub fn get_anchor_a(&self) -> Vec2 {
+        unsafe {
+            b2Joint_GetAnchorA(self.ptr)
+        }
+    }
+
+	/// Get the anchor point on bodyB in world coordinates.
+    pub fn get_anchor_b(&self) -> Vec2 {
+        unsafe {
+            b2Joint_GetAnchorB(self.ptr)
+        }
+    }
+
+	/// Get the reaction force on bodyB at the joint anchor in Newtons.
+    pub fn get_reaction_force(&self, inv_dt: f32) -> Vec2 {
+        unsafe {
+            b2Joint_GetReactionForce(self.ptr, inv_dt)
+        }
+    }
+
+	/// Get the reaction torque on bodyB in N*m.
+    pub fn get_reaction_torque(&self, inv_dt: f32) -> f32 {
+        unsafe {
+            b2Joint_GetReactionTorque(self.ptr, inv_dt)
+        }
+    }
+
+	/// Get the next joint in the world joint list.
+    pub fn get_next(&self) -> Option<Joint> {
+        let ptr;
+        unsafe {
+            ptr = b2Joint_GetNext(self.ptr);
+        }
 
-	/// Use this to attach application specific data to your joints.
-	pub user_data: size_t,
+        if ptr.is_null() {
+            None
+        } else {
+            Some(Joint { ptr: ptr })
+        }
+    }
 
-	/// The first attached body.
-	pub body_a: Option<Body>,
+	/// Get the user data pointer.
+    pub fn get_user_data(&self) -> usize {
+        unsafe {
+            b2Joint_GetUserData(self.ptr)
+        }
+    }
 
-	/// The second attached body.
-	pub body_b: Option<Body>,
+	/// Short-cut function to determine if either body is inactive.
+    pub fn is_active(&self) -> bool {
+        unsafe {
+            b2Joint_IsActive(self.ptr)
+        }
+    }
+
+	/// Get collide connected.
+	/// Note: modifying the collide connect flag won't work correctly because
+	/// the flag is only checked when fixture AABBs begin to overlap.
+    pub fn get_collide_connected(&self) -> bool {
+        unsafe {
+            b2Joint_GetCollideConnected(self.ptr)
+        }
+    }
 
-	/// Set this flag to true if the attached bodies should collide.
-	pub collide_connected: bool,
 }
 
-impl Default for JointDef {
-	fn default() -> JointDef {
-		JointDef {
-			joint_type: JointType::UnknownJoint,
-			user_data: 0,
-			body_a: None,
-			body_b: None,
-			collide_connected: false,
+impl JointEdge {
+
+	/// Provides quick access to the other body attached.
+    pub fn get_other(&self) -> Body {
+        unsafe {
+            Body { ptr: (*self.ptr).other }
+        }
+    }
+
+	/// The joint.
+    pub fn get_joint(&self) -> Joint {
+        unsafe {
+            Joint { ptr: (*self.ptr).joint }
+        }
+    }
+
+	/// The previous joint edge in the body's joint list.
+    pub fn get_prev(&self) -> Option<JointEdge> {
+        let ptr = unsafe { (*self.ptr).prev };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(JointEdge { ptr: ptr })
+        }
+    }
+
+	/// The next joint edge in the body's joint list.
+    pub fn get_next(&self) -> Option<JointEdge> {
+        let ptr = unsafe { (*self.ptr).next };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(JointEdge { ptr: ptr })
+        }
+    }
+
+}
+
+/// Joint definitions are used to construct joints. Every concrete joint
+/// definition implements this trait and is passed to World::create_joint.
+/// The b2JointDef members are fields of each definition, as they are
+/// inherited in C++: user_data to attach application specific data to the
+/// joint, body_a and body_b for the attached bodies, and collide_connected
+/// set to true if the attached bodies should collide.
+/// The trait is sealed, the definitions of this crate are the only ones the
+/// C shim can build joints from.
+pub trait JointDef: Sealed {
+	/// The LiquidFun layout of the definition, handed to b2World::CreateJoint
+	/// by pointer.
+	type B2Def;
+
+	/// The type of joint built from this definition.
+	fn joint_type(&self) -> JointType;
+
+	/// Convert the definition into its LiquidFun layout.
+	fn to_b2(&self) -> Self::B2Def;
+}
+
+mod private {
+	pub trait Sealed {}
+}
+
+use self::private::Sealed;
+
+/// The layout of c_b2JointDef, the b2JointDef members at the start of every
+/// B2*JointDef. The layouts are plain C structs, the C shim copies them into
+/// the LiquidFun definitions.
+#[repr(C)]
+pub struct B2JointDef {
+	joint_type: JointType,
+	user_data: size_t,
+	body_a: *mut B2Body,
+	body_b: *mut B2Body,
+	collide_connected: bool,
+}
+
+impl B2JointDef {
+	fn new(joint_type: JointType, user_data: size_t, body_a: &Option<Body>, body_b: &Option<Body>, collide_connected: bool) -> B2JointDef {
+		B2JointDef {
+			joint_type: joint_type,
+			user_data: user_data,
+			body_a: body_a.as_ref().map_or(ptr::null_mut(), |b| b.ptr),
+			body_b: body_b.as_ref().map_or(ptr::null_mut(), |b| b.ptr),
+			collide_connected: collide_connected,
 		}
 	}
-}
\ No newline at end of file
+
+	/// LiquidFun dereferences both bodies, so check them before creating
+	/// the joint.
+	pub(crate) fn assert_bodies(&self) {
+		assert!(!self.body_a.is_null() && !self.body_b.is_null(), "joint requires body_a and body_b");
+		assert!(self.body_a != self.body_b, "joint requires two different bodies");
+	}
+}
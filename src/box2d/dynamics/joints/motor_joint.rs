@@ -0,0 +1,216 @@
+use libc::size_t;
+use super::super::super::common::math::*;
+use super::super::super::common::settings::*;
+use super::super::super::dynamics::body::Body;
+
+use super::{B2JointDef, Sealed, B2Joint, Joint, JointType, JointDef};
+
+/// Motor joint definition.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct MotorJointDef {
+
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub user_data: size_t,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_a: Option<Body>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_b: Option<Body>,
+	pub collide_connected: bool,
+
+	/// Position of bodyB minus the position of bodyA, in bodyA's frame, in meters.
+	pub linear_offset: Vec2,
+
+	/// The bodyB angle minus bodyA angle in radians.
+	pub angular_offset: Float32,
+
+	/// The maximum motor force in N.
+	pub max_force: Float32,
+
+	/// The maximum motor torque in N-m.
+	pub max_torque: Float32,
+
+	/// Position correction factor in the range [0,1].
+	pub correction_factor: Float32,
+}
+
+impl Default for MotorJointDef {
+	fn default() -> MotorJointDef {
+		MotorJointDef {
+			user_data: 0,
+			body_a: None,
+			body_b: None,
+			collide_connected: false,
+			linear_offset: Vec2::zero(),
+			angular_offset: 0.0,
+			max_force: 1.0,
+			max_torque: 1.0,
+			correction_factor: 0.3,
+		}
+	}
+}
+
+impl MotorJointDef {
+	pub fn new() -> MotorJointDef {
+		MotorJointDef::default()
+	}
+
+	/// Initialize the bodies and offsets using the current transforms.
+	pub fn initialize(&mut self, body_a: Body, body_b: Body) {
+		self.linear_offset = body_a.get_local_point(body_b.get_position());
+		self.angular_offset = body_b.get_angle() - body_a.get_angle();
+		self.body_a = Some(body_a);
+		self.body_b = Some(body_b);
+	}
+}
+
+/// The layout of c_b2MotorJointDef.
+#[repr(C)]
+pub struct B2MotorJointDef {
+	base: B2JointDef,
+	linear_offset: Vec2,
+	angular_offset: Float32,
+	max_force: Float32,
+	max_torque: Float32,
+	correction_factor: Float32,
+}
+
+impl Sealed for MotorJointDef {}
+
+impl JointDef for MotorJointDef {
+	type B2Def = B2MotorJointDef;
+
+	fn joint_type(&self) -> JointType {
+		JointType::MotorJoint
+	}
+
+	fn to_b2(&self) -> B2MotorJointDef {
+		B2MotorJointDef {
+			base: B2JointDef::new(self.joint_type(), self.user_data, &self.body_a, &self.body_b, self.collide_connected),
+			linear_offset: self.linear_offset,
+			angular_offset: self.angular_offset,
+			max_force: self.max_force,
+			max_torque: self.max_torque,
+			correction_factor: self.correction_factor,
+		}
+	}
+}
+
+pub enum B2MotorJoint {}
+
+extern {
+    fn b2MotorJoint_Upcast(this: *mut B2MotorJoint) -> *mut B2Joint;
+    fn b2MotorJoint_SetLinearOffset(this: *mut B2MotorJoint, linear_offset: *const Vec2);
+    fn b2MotorJoint_GetLinearOffset(this: *const B2MotorJoint) -> *const Vec2;
+    fn b2MotorJoint_SetAngularOffset(this: *mut B2MotorJoint, angular_offset: Float32);
+    fn b2MotorJoint_GetAngularOffset(this: *const B2MotorJoint) -> Float32;
+    fn b2MotorJoint_SetMaxForce(this: *mut B2MotorJoint, force: Float32);
+    fn b2MotorJoint_GetMaxForce(this: *const B2MotorJoint) -> Float32;
+    fn b2MotorJoint_SetMaxTorque(this: *mut B2MotorJoint, torque: Float32);
+    fn b2MotorJoint_GetMaxTorque(this: *const B2MotorJoint) -> Float32;
+    fn b2MotorJoint_SetCorrectionFactor(this: *mut B2MotorJoint, factor: Float32);
+    fn b2MotorJoint_GetCorrectionFactor(this: *const B2MotorJoint) -> Float32;
+}
+
+/// A motor joint is used to control the relative motion
+/// between two bodies. A typical usage is to control the movement
+/// of a dynamic body with respect to the ground.
+#[allow(raw_pointer_derive)]
+#[derive(Clone, Debug)]
+pub struct MotorJoint {
+	pub ptr: *mut B2MotorJoint
+}
+
+impl MotorJoint {
+
+	/// Set the target linear offset, in frame A, in meters.
+    pub fn set_linear_offset(&self, linear_offset: &Vec2) {
+        unsafe {
+            b2MotorJoint_SetLinearOffset(self.ptr, linear_offset);
+        }
+    }
+
+	/// Get the target linear offset, in frame A, in meters.
+    pub fn get_linear_offset(&self) -> Vec2 {
+        unsafe {
+            *b2MotorJoint_GetLinearOffset(self.ptr)
+        }
+    }
+
+	/// Set the target angular offset, in radians.
+    pub fn set_angular_offset(&self, angular_offset: f32) {
+        unsafe {
+            b2MotorJoint_SetAngularOffset(self.ptr, angular_offset);
+        }
+    }
+
+	/// Get the target angular offset, in radians.
+    pub fn get_angular_offset(&self) -> f32 {
+        unsafe {
+            b2MotorJoint_GetAngularOffset(self.ptr)
+        }
+    }
+
+	/// Set the maximum friction force in N.
+    pub fn set_max_force(&self, force: f32) {
+        unsafe {
+            b2MotorJoint_SetMaxForce(self.ptr, force);
+        }
+    }
+
+	/// Get the maximum friction force in N.
+    pub fn get_max_force(&self) -> f32 {
+        unsafe {
+            b2MotorJoint_GetMaxForce(self.ptr)
+        }
+    }
+
+	/// Set the maximum friction torque in N*m.
+    pub fn set_max_torque(&self, torque: f32) {
+        unsafe {
+            b2MotorJoint_SetMaxTorque(self.ptr, torque);
+        }
+    }
+
+	/// Get the maximum friction torque in N*m.
+    pub fn get_max_torque(&self) -> f32 {
+        unsafe {
+            b2MotorJoint_GetMaxTorque(self.ptr)
+        }
+    }
+
+	/// Set the position correction factor in the range [0,1].
+    pub fn set_correction_factor(&self, factor: f32) {
+        unsafe {
+            b2MotorJoint_SetCorrectionFactor(self.ptr, factor);
+        }
+    }
+
+	/// Get the position correction factor in the range [0,1].
+    pub fn get_correction_factor(&self) -> f32 {
+        unsafe {
+            b2MotorJoint_GetCorrectionFactor(self.ptr)
+        }
+    }
+
+}
+
+impl From<MotorJoint> for Joint {
+	fn from(joint: MotorJoint) -> Joint {
+		unsafe {
+			Joint { ptr: b2MotorJoint_Upcast(joint.ptr) }
+		}
+	}
+}
+
+impl Joint {
+	/// Downcast to a motor joint.
+	/// @return the motor joint or None if this is another type of joint.
+	pub fn as_motor_joint(&self) -> Option<MotorJoint> {
+		match self.get_type() {
+			JointType::MotorJoint => Some(MotorJoint { ptr: self.ptr as *mut B2MotorJoint }),
+			_ => None
+		}
+	}
+}
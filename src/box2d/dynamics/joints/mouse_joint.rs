@@ -0,0 +1,194 @@
+use libc::size_t;
+use super::super::super::common::math::*;
+use super::super::super::common::settings::*;
+use super::super::super::dynamics::body::Body;
+
+use super::{B2JointDef, Sealed, B2Joint, Joint, JointType, JointDef};
+
+/// Mouse joint definition. This requires a world target point,
+/// tuning parameters, and the time step.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct MouseJointDef {
+
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub user_data: size_t,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_a: Option<Body>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_b: Option<Body>,
+	pub collide_connected: bool,
+
+	/// The initial world target point. This is assumed
+	/// to coincide with the body anchor initially.
+	pub target: Vec2,
+
+	/// The maximum constraint force that can be exerted
+	/// to move the candidate body. Usually you will express
+	/// as some multiple of the weight (multiplier * mass * gravity).
+	pub max_force: Float32,
+
+	/// The response speed.
+	pub frequency_hz: Float32,
+
+	/// The damping ratio. 0 = no damping, 1 = critical damping.
+	pub damping_ratio: Float32,
+}
+
+impl Default for MouseJointDef {
+	fn default() -> MouseJointDef {
+		MouseJointDef {
+			user_data: 0,
+			body_a: None,
+			body_b: None,
+			collide_connected: false,
+			target: Vec2::zero(),
+			max_force: 0.0,
+			frequency_hz: 5.0,
+			damping_ratio: 0.7,
+		}
+	}
+}
+
+impl MouseJointDef {
+	pub fn new() -> MouseJointDef {
+		MouseJointDef::default()
+	}
+}
+
+/// The layout of c_b2MouseJointDef.
+#[repr(C)]
+pub struct B2MouseJointDef {
+	base: B2JointDef,
+	target: Vec2,
+	max_force: Float32,
+	frequency_hz: Float32,
+	damping_ratio: Float32,
+}
+
+impl Sealed for MouseJointDef {}
+
+impl JointDef for MouseJointDef {
+	type B2Def = B2MouseJointDef;
+
+	fn joint_type(&self) -> JointType {
+		JointType::MouseJoint
+	}
+
+	fn to_b2(&self) -> B2MouseJointDef {
+		B2MouseJointDef {
+			base: B2JointDef::new(self.joint_type(), self.user_data, &self.body_a, &self.body_b, self.collide_connected),
+			target: self.target,
+			max_force: self.max_force,
+			frequency_hz: self.frequency_hz,
+			damping_ratio: self.damping_ratio,
+		}
+	}
+}
+
+pub enum B2MouseJoint {}
+
+extern {
+    fn b2MouseJoint_Upcast(this: *mut B2MouseJoint) -> *mut B2Joint;
+    fn b2MouseJoint_SetTarget(this: *mut B2MouseJoint, target: *const Vec2);
+    fn b2MouseJoint_GetTarget(this: *const B2MouseJoint) -> *const Vec2;
+    fn b2MouseJoint_SetMaxForce(this: *mut B2MouseJoint, force: Float32);
+    fn b2MouseJoint_GetMaxForce(this: *const B2MouseJoint) -> Float32;
+    fn b2MouseJoint_SetFrequency(this: *mut B2MouseJoint, hz: Float32);
+    fn b2MouseJoint_GetFrequency(this: *const B2MouseJoint) -> Float32;
+    fn b2MouseJoint_SetDampingRatio(this: *mut B2MouseJoint, ratio: Float32);
+    fn b2MouseJoint_GetDampingRatio(this: *const B2MouseJoint) -> Float32;
+}
+
+/// A mouse joint is used to make a point on a body track a
+/// specified world point. This a soft constraint with a maximum
+/// force. This allows the constraint to stretch and without
+/// applying huge forces.
+/// NOTE: this joint is not documented in the manual because it was
+/// developed to be used in the testbed. If you want to learn how to
+/// use the mouse joint, look at the testbed.
+#[allow(raw_pointer_derive)]
+#[derive(Clone, Debug)]
+pub struct MouseJoint {
+	pub ptr: *mut B2MouseJoint
+}
+
+impl MouseJoint {
+
+	/// Use this to update the target point.
+    pub fn set_target(&self, target: &Vec2) {
+        unsafe {
+            b2MouseJoint_SetTarget(self.ptr, target);
+        }
+    }
+
+	/// Get the target point.
+    pub fn get_target(&self) -> Vec2 {
+        unsafe {
+            *b2MouseJoint_GetTarget(self.ptr)
+        }
+    }
+
+	/// Set the maximum force in Newtons.
+    pub fn set_max_force(&self, force: f32) {
+        unsafe {
+            b2MouseJoint_SetMaxForce(self.ptr, force);
+        }
+    }
+
+	/// Get the maximum force in Newtons.
+    pub fn get_max_force(&self) -> f32 {
+        unsafe {
+            b2MouseJoint_GetMaxForce(self.ptr)
+        }
+    }
+
+	/// Set the frequency in Hertz.
+    pub fn set_frequency(&self, hz: f32) {
+        unsafe {
+            b2MouseJoint_SetFrequency(self.ptr, hz);
+        }
+    }
+
+	/// Get the frequency in Hertz.
+    pub fn get_frequency(&self) -> f32 {
+        unsafe {
+            b2MouseJoint_GetFrequency(self.ptr)
+        }
+    }
+
+	/// Set the damping ratio (dimensionless).
+    pub fn set_damping_ratio(&self, ratio: f32) {
+        unsafe {
+            b2MouseJoint_SetDampingRatio(self.ptr, ratio);
+        }
+    }
+
+	/// Get the damping ratio (dimensionless).
+    pub fn get_damping_ratio(&self) -> f32 {
+        unsafe {
+            b2MouseJoint_GetDampingRatio(self.ptr)
+        }
+    }
+
+}
+
+impl From<MouseJoint> for Joint {
+	fn from(joint: MouseJoint) -> Joint {
+		unsafe {
+			Joint { ptr: b2MouseJoint_Upcast(joint.ptr) }
+		}
+	}
+}
+
+impl Joint {
+	/// Downcast to a mouse joint.
+	/// @return the mouse joint or None if this is another type of joint.
+	pub fn as_mouse_joint(&self) -> Option<MouseJoint> {
+		match self.get_type() {
+			JointType::MouseJoint => Some(MouseJoint { ptr: self.ptr as *mut B2MouseJoint }),
+			_ => None
+		}
+	}
+}
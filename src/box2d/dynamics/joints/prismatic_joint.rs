@@ -0,0 +1,319 @@
+use libc::size_t;
+use super::super::super::common::math::*;
+use super::super::super::common::settings::*;
+use super::super::super::dynamics::body::Body;
+
+use super::{B2JointDef, Sealed, B2Joint, Joint, JointType, JointDef};
+
+/// Prismatic joint definition. This requires defining a line of
+/// motion using an axis and an anchor point. The definition uses local
+/// anchor points and a local axis so that the initial configuration
+/// can violate the constraint slightly. The joint translation is zero
+/// when the local anchor points coincide in world space. Using local
+/// anchors and a local axis helps when saving and loading a game.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct PrismaticJointDef {
+
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub user_data: size_t,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_a: Option<Body>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_b: Option<Body>,
+	pub collide_connected: bool,
+
+	/// The local anchor point relative to bodyA's origin.
+	pub local_anchor_a: Vec2,
+
+	/// The local anchor point relative to bodyB's origin.
+	pub local_anchor_b: Vec2,
+
+	/// The local translation unit axis in bodyA.
+	pub local_axis_a: Vec2,
+
+	/// The constrained angle between the bodies: bodyB_angle - bodyA_angle.
+	pub reference_angle: Float32,
+
+	/// Enable/disable the joint limit.
+	pub enable_limit: bool,
+
+	/// The lower translation limit, usually in meters.
+	pub lower_translation: Float32,
+
+	/// The upper translation limit, usually in meters.
+	pub upper_translation: Float32,
+
+	/// Enable/disable the joint motor.
+	pub enable_motor: bool,
+
+	/// The maximum motor force, usually in N.
+	pub max_motor_force: Float32,
+
+	/// The desired motor speed, usually in meters per second.
+	pub motor_speed: Float32,
+}
+
+impl Default for PrismaticJointDef {
+	fn default() -> PrismaticJointDef {
+		PrismaticJointDef {
+			user_data: 0,
+			body_a: None,
+			body_b: None,
+			collide_connected: false,
+			local_anchor_a: Vec2::zero(),
+			local_anchor_b: Vec2::zero(),
+			local_axis_a: Vec2::new(1.0, 0.0),
+			reference_angle: 0.0,
+			enable_limit: false,
+			lower_translation: 0.0,
+			upper_translation: 0.0,
+			enable_motor: false,
+			max_motor_force: 0.0,
+			motor_speed: 0.0,
+		}
+	}
+}
+
+impl PrismaticJointDef {
+	pub fn new() -> PrismaticJointDef {
+		PrismaticJointDef::default()
+	}
+
+	/// Initialize the bodies, anchors, axis, and reference angle using the world
+	/// anchor and unit world axis.
+	pub fn initialize(&mut self, body_a: Body, body_b: Body, anchor: &Vec2, axis: &Vec2) {
+		self.local_anchor_a = body_a.get_local_point(anchor);
+		self.local_anchor_b = body_b.get_local_point(anchor);
+		self.local_axis_a = body_a.get_local_vector(axis);
+		self.reference_angle = body_b.get_angle() - body_a.get_angle();
+		self.body_a = Some(body_a);
+		self.body_b = Some(body_b);
+	}
+}
+
+/// The layout of c_b2PrismaticJointDef.
+#[repr(C)]
+pub struct B2PrismaticJointDef {
+	base: B2JointDef,
+	local_anchor_a: Vec2,
+	local_anchor_b: Vec2,
+	local_axis_a: Vec2,
+	reference_angle: Float32,
+	enable_limit: bool,
+	lower_translation: Float32,
+	upper_translation: Float32,
+	enable_motor: bool,
+	max_motor_force: Float32,
+	motor_speed: Float32,
+}
+
+impl Sealed for PrismaticJointDef {}
+
+impl JointDef for PrismaticJointDef {
+	type B2Def = B2PrismaticJointDef;
+
+	fn joint_type(&self) -> JointType {
+		JointType::PrismaticJoint
+	}
+
+	fn to_b2(&self) -> B2PrismaticJointDef {
+		B2PrismaticJointDef {
+			base: B2JointDef::new(self.joint_type(), self.user_data, &self.body_a, &self.body_b, self.collide_connected),
+			local_anchor_a: self.local_anchor_a,
+			local_anchor_b: self.local_anchor_b,
+			local_axis_a: self.local_axis_a,
+			reference_angle: self.reference_angle,
+			enable_limit: self.enable_limit,
+			lower_translation: self.lower_translation,
+			upper_translation: self.upper_translation,
+			enable_motor: self.enable_motor,
+			max_motor_force: self.max_motor_force,
+			motor_speed: self.motor_speed,
+		}
+	}
+}
+
+pub enum B2PrismaticJoint {}
+
+extern {
+    fn b2PrismaticJoint_Upcast(this: *mut B2PrismaticJoint) -> *mut B2Joint;
+    fn b2PrismaticJoint_GetLocalAnchorA(this: *const B2PrismaticJoint) -> *const Vec2;
+    fn b2PrismaticJoint_GetLocalAnchorB(this: *const B2PrismaticJoint) -> *const Vec2;
+    fn b2PrismaticJoint_GetLocalAxisA(this: *const B2PrismaticJoint) -> *const Vec2;
+    fn b2PrismaticJoint_GetReferenceAngle(this: *const B2PrismaticJoint) -> Float32;
+    fn b2PrismaticJoint_GetJointTranslation(this: *const B2PrismaticJoint) -> Float32;
+    fn b2PrismaticJoint_GetJointSpeed(this: *const B2PrismaticJoint) -> Float32;
+    fn b2PrismaticJoint_IsLimitEnabled(this: *const B2PrismaticJoint) -> bool;
+    fn b2PrismaticJoint_EnableLimit(this: *mut B2PrismaticJoint, flag: bool);
+    fn b2PrismaticJoint_GetLowerLimit(this: *const B2PrismaticJoint) -> Float32;
+    fn b2PrismaticJoint_GetUpperLimit(this: *const B2PrismaticJoint) -> Float32;
+    fn b2PrismaticJoint_SetLimits(this: *mut B2PrismaticJoint, lower: Float32, upper: Float32);
+    fn b2PrismaticJoint_IsMotorEnabled(this: *const B2PrismaticJoint) -> bool;
+    fn b2PrismaticJoint_EnableMotor(this: *mut B2PrismaticJoint, flag: bool);
+    fn b2PrismaticJoint_SetMotorSpeed(this: *mut B2PrismaticJoint, speed: Float32);
+    fn b2PrismaticJoint_GetMotorSpeed(this: *const B2PrismaticJoint) -> Float32;
+    fn b2PrismaticJoint_SetMaxMotorForce(this: *mut B2PrismaticJoint, force: Float32);
+    fn b2PrismaticJoint_GetMaxMotorForce(this: *const B2PrismaticJoint) -> Float32;
+    fn b2PrismaticJoint_GetMotorForce(this: *const B2PrismaticJoint, inv_dt: Float32) -> Float32;
+}
+
+/// A prismatic joint. This joint provides one degree of freedom: translation
+/// along an axis fixed in bodyA. Relative rotation is prevented. You can
+/// use a joint limit to restrict the range of motion and a joint motor to
+/// drive the motion or to model joint friction.
+#[allow(raw_pointer_derive)]
+#[derive(Clone, Debug)]
+pub struct PrismaticJoint {
+	pub ptr: *mut B2PrismaticJoint
+}
+
+impl PrismaticJoint {
+
+	/// The local anchor point relative to bodyA's origin.
+    pub fn get_local_anchor_a(&self) -> Vec2 {
+        unsafe {
+            *b2PrismaticJoint_GetLocalAnchorA(self.ptr)
+        }
+    }
+
+	/// The local anchor point relative to bodyB's origin.
+    pub fn get_local_anchor_b(&self) -> Vec2 {
+        unsafe {
+            *b2PrismaticJoint_GetLocalAnchorB(self.ptr)
+        }
+    }
+
+	/// The local joint axis relative to bodyA.
+    pub fn get_local_axis_a(&self) -> Vec2 {
+        unsafe {
+            *b2PrismaticJoint_GetLocalAxisA(self.ptr)
+        }
+    }
+
+	/// Get the reference angle.
+    pub fn get_reference_angle(&self) -> f32 {
+        unsafe {
+            b2PrismaticJoint_GetReferenceAngle(self.ptr)
+        }
+    }
+
+	/// Get the current joint translation, usually in meters.
+    pub fn get_joint_translation(&self) -> f32 {
+        unsafe {
+            b2PrismaticJoint_GetJointTranslation(self.ptr)
+        }
+    }
+
+	/// Get the current joint translation speed, usually in meters per second.
+    pub fn get_joint_speed(&self) -> f32 {
+        unsafe {
+            b2PrismaticJoint_GetJointSpeed(self.ptr)
+        }
+    }
+
+	/// Is the joint limit enabled?
+    pub fn is_limit_enabled(&self) -> bool {
+        unsafe {
+            b2PrismaticJoint_IsLimitEnabled(self.ptr)
+        }
+    }
+
+	/// Enable/disable the joint limit.
+    pub fn enable_limit(&self, flag: bool) {
+        unsafe {
+            b2PrismaticJoint_EnableLimit(self.ptr, flag);
+        }
+    }
+
+	/// Get the lower joint limit, usually in meters.
+    pub fn get_lower_limit(&self) -> f32 {
+        unsafe {
+            b2PrismaticJoint_GetLowerLimit(self.ptr)
+        }
+    }
+
+	/// Get the upper joint limit, usually in meters.
+    pub fn get_upper_limit(&self) -> f32 {
+        unsafe {
+            b2PrismaticJoint_GetUpperLimit(self.ptr)
+        }
+    }
+
+	/// Set the joint limits, usually in meters.
+    pub fn set_limits(&self, lower: f32, upper: f32) {
+        unsafe {
+            b2PrismaticJoint_SetLimits(self.ptr, lower, upper);
+        }
+    }
+
+	/// Is the joint motor enabled?
+    pub fn is_motor_enabled(&self) -> bool {
+        unsafe {
+            b2PrismaticJoint_IsMotorEnabled(self.ptr)
+        }
+    }
+
+	/// Enable/disable the joint motor.
+    pub fn enable_motor(&self, flag: bool) {
+        unsafe {
+            b2PrismaticJoint_EnableMotor(self.ptr, flag);
+        }
+    }
+
+	/// Set the motor speed, usually in meters per second.
+    pub fn set_motor_speed(&self, speed: f32) {
+        unsafe {
+            b2PrismaticJoint_SetMotorSpeed(self.ptr, speed);
+        }
+    }
+
+	/// Get the motor speed, usually in meters per second.
+    pub fn get_motor_speed(&self) -> f32 {
+        unsafe {
+            b2PrismaticJoint_GetMotorSpeed(self.ptr)
+        }
+    }
+
+	/// Set the maximum motor force, usually in N.
+    pub fn set_max_motor_force(&self, force: f32) {
+        unsafe {
+            b2PrismaticJoint_SetMaxMotorForce(self.ptr, force);
+        }
+    }
+
+	/// Get the maximum motor force, usually in N.
+    pub fn get_max_motor_force(&self) -> f32 {
+        unsafe {
+            b2PrismaticJoint_GetMaxMotorForce(self.ptr)
+        }
+    }
+
+	/// Get the current motor force given the inverse time step, usually in N.
+    pub fn get_motor_force(&self, inv_dt: f32) -> f32 {
+        unsafe {
+            b2PrismaticJoint_GetMotorForce(self.ptr, inv_dt)
+        }
+    }
+
+}
+
+impl From<PrismaticJoint> for Joint {
+	fn from(joint: PrismaticJoint) -> Joint {
+		unsafe {
+			Joint { ptr: b2PrismaticJoint_Upcast(joint.ptr) }
+		}
+	}
+}
+
+impl Joint {
+	/// Downcast to a prismatic joint.
+	/// @return the prismatic joint or None if this is another type of joint.
+	pub fn as_prismatic_joint(&self) -> Option<PrismaticJoint> {
+		match self.get_type() {
+			JointType::PrismaticJoint => Some(PrismaticJoint { ptr: self.ptr as *mut B2PrismaticJoint }),
+			_ => None
+		}
+	}
+}
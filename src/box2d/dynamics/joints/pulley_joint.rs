@@ -0,0 +1,219 @@
+use libc::size_t;
+use super::super::super::common::math::*;
+use super::super::super::common::settings::*;
+use super::super::super::dynamics::body::Body;
+
+use super::{B2JointDef, Sealed, B2Joint, Joint, JointType, JointDef};
+
+/// Pulley joint definition. This requires two ground anchors,
+/// two dynamic body anchor points, and a pulley ratio.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct PulleyJointDef {
+
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub user_data: size_t,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_a: Option<Body>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_b: Option<Body>,
+	pub collide_connected: bool,
+
+	/// The first ground anchor in world coordinates. This point never moves.
+	pub ground_anchor_a: Vec2,
+
+	/// The second ground anchor in world coordinates. This point never moves.
+	pub ground_anchor_b: Vec2,
+
+	/// The local anchor point relative to bodyA's origin.
+	pub local_anchor_a: Vec2,
+
+	/// The local anchor point relative to bodyB's origin.
+	pub local_anchor_b: Vec2,
+
+	/// The a reference length for the segment attached to bodyA.
+	pub length_a: Float32,
+
+	/// The a reference length for the segment attached to bodyB.
+	pub length_b: Float32,
+
+	/// The pulley ratio, used to simulate a block-and-tackle.
+	pub ratio: Float32,
+}
+
+impl Default for PulleyJointDef {
+	fn default() -> PulleyJointDef {
+		PulleyJointDef {
+			user_data: 0,
+			body_a: None,
+			body_b: None,
+			collide_connected: true,
+			ground_anchor_a: Vec2::new(-1.0, 1.0),
+			ground_anchor_b: Vec2::new(1.0, 1.0),
+			local_anchor_a: Vec2::new(-1.0, 0.0),
+			local_anchor_b: Vec2::new(1.0, 0.0),
+			length_a: 0.0,
+			length_b: 0.0,
+			ratio: 1.0,
+		}
+	}
+}
+
+impl PulleyJointDef {
+	pub fn new() -> PulleyJointDef {
+		PulleyJointDef::default()
+	}
+
+	/// Initialize the bodies, anchors, lengths, max lengths, and ratio using the world anchors.
+	pub fn initialize(&mut self, body_a: Body, body_b: Body,
+	                  ground_anchor_a: &Vec2, ground_anchor_b: &Vec2,
+	                  anchor_a: &Vec2, anchor_b: &Vec2,
+	                  ratio: f32) {
+		assert!(ratio > EPSILON);
+		self.ground_anchor_a = *ground_anchor_a;
+		self.ground_anchor_b = *ground_anchor_b;
+		self.local_anchor_a = body_a.get_local_point(anchor_a);
+		self.local_anchor_b = body_b.get_local_point(anchor_b);
+		self.length_a = anchor_a.distance(ground_anchor_a);
+		self.length_b = anchor_b.distance(ground_anchor_b);
+		self.ratio = ratio;
+		self.body_a = Some(body_a);
+		self.body_b = Some(body_b);
+	}
+}
+
+/// The layout of c_b2PulleyJointDef.
+#[repr(C)]
+pub struct B2PulleyJointDef {
+	base: B2JointDef,
+	ground_anchor_a: Vec2,
+	ground_anchor_b: Vec2,
+	local_anchor_a: Vec2,
+	local_anchor_b: Vec2,
+	length_a: Float32,
+	length_b: Float32,
+	ratio: Float32,
+}
+
+impl Sealed for PulleyJointDef {}
+
+impl JointDef for PulleyJointDef {
+	type B2Def = B2PulleyJointDef;
+
+	fn joint_type(&self) -> JointType {
+		JointType::PulleyJoint
+	}
+
+	fn to_b2(&self) -> B2PulleyJointDef {
+		B2PulleyJointDef {
+			base: B2JointDef::new(self.joint_type(), self.user_data, &self.body_a, &self.body_b, self.collide_connected),
+			ground_anchor_a: self.ground_anchor_a,
+			ground_anchor_b: self.ground_anchor_b,
+			local_anchor_a: self.local_anchor_a,
+			local_anchor_b: self.local_anchor_b,
+			length_a: self.length_a,
+			length_b: self.length_b,
+			ratio: self.ratio,
+		}
+	}
+}
+
+pub enum B2PulleyJoint {}
+
+extern {
+    fn b2PulleyJoint_Upcast(this: *mut B2PulleyJoint) -> *mut B2Joint;
+    fn b2PulleyJoint_GetGroundAnchorA(this: *const B2PulleyJoint) -> Vec2;
+    fn b2PulleyJoint_GetGroundAnchorB(this: *const B2PulleyJoint) -> Vec2;
+    fn b2PulleyJoint_GetLengthA(this: *const B2PulleyJoint) -> Float32;
+    fn b2PulleyJoint_GetLengthB(this: *const B2PulleyJoint) -> Float32;
+    fn b2PulleyJoint_GetRatio(this: *const B2PulleyJoint) -> Float32;
+    fn b2PulleyJoint_GetCurrentLengthA(this: *const B2PulleyJoint) -> Float32;
+    fn b2PulleyJoint_GetCurrentLengthB(this: *const B2PulleyJoint) -> Float32;
+}
+
+/// The pulley joint is connected to two bodies and two fixed ground points.
+/// The pulley supports a ratio such that:
+/// length1 + ratio * length2 <= constant
+/// Yes, the force transmitted is scaled by the ratio.
+/// Warning: the pulley joint can get a bit squirrelly by itself. They often
+/// work better when combined with prismatic joints. You should also cover the
+/// the anchor points with static shapes to prevent one side from going to
+/// zero length.
+#[allow(raw_pointer_derive)]
+#[derive(Clone, Debug)]
+pub struct PulleyJoint {
+	pub ptr: *mut B2PulleyJoint
+}
+
+impl PulleyJoint {
+
+	/// Get the first ground anchor.
+    pub fn get_ground_anchor_a(&self) -> Vec2 {
+        unsafe {
+            b2PulleyJoint_GetGroundAnchorA(self.ptr)
+        }
+    }
+
+	/// Get the second ground anchor.
+    pub fn get_ground_anchor_b(&self) -> Vec2 {
+        unsafe {
+            b2PulleyJoint_GetGroundAnchorB(self.ptr)
+        }
+    }
+
+	/// Get the reference length of the segment attached to bodyA.
+    pub fn get_length_a(&self) -> f32 {
+        unsafe {
+            b2PulleyJoint_GetLengthA(self.ptr)
+        }
+    }
+
+	/// Get the reference length of the segment attached to bodyB.
+    pub fn get_length_b(&self) -> f32 {
+        unsafe {
+            b2PulleyJoint_GetLengthB(self.ptr)
+        }
+    }
+
+	/// Get the pulley ratio.
+    pub fn get_ratio(&self) -> f32 {
+        unsafe {
+            b2PulleyJoint_GetRatio(self.ptr)
+        }
+    }
+
+	/// Get the current length of the segment attached to bodyA.
+    pub fn get_current_length_a(&self) -> f32 {
+        unsafe {
+            b2PulleyJoint_GetCurrentLengthA(self.ptr)
+        }
+    }
+
+	/// Get the current length of the segment attached to bodyB.
+    pub fn get_current_length_b(&self) -> f32 {
+        unsafe {
+            b2PulleyJoint_GetCurrentLengthB(self.ptr)
+        }
+    }
+
+}
+
+impl From<PulleyJoint> for Joint {
+	fn from(joint: PulleyJoint) -> Joint {
+		unsafe {
+			Joint { ptr: b2PulleyJoint_Upcast(joint.ptr) }
+		}
+	}
+}
+
+impl Joint {
+	/// Downcast to a pulley joint.
+	/// @return the pulley joint or None if this is another type of joint.
+	pub fn as_pulley_joint(&self) -> Option<PulleyJoint> {
+		match self.get_type() {
+			JointType::PulleyJoint => Some(PulleyJoint { ptr: self.ptr as *mut B2PulleyJoint }),
+			_ => None
+		}
+	}
+}
@@ -1,8 +1,9 @@
+use libc::size_t;
 use super::super::super::common::math::*;
 use super::super::super::common::settings::*;
 use super::super::super::dynamics::body::Body;
 
-use super::{JointType, JointDef};
+use super::{B2JointDef, Sealed, B2Joint, Joint, JointType, JointDef};
 
 /// Revolute joint definition. This requires defining an
 /// anchor point where the bodies are joined. The definition
@@ -16,8 +17,18 @@ use super::{JointType, JointDef};
 /// 2. if you add/remove shapes from a body and recompute the mass,
 ///    the joints will be broken.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct RevoluteJointDef {
 
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub user_data: size_t,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_a: Option<Body>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_b: Option<Body>,
+	pub collide_connected: bool,
+
 	/// Initialize the bodies, anchors, and reference angle using a world
 	/// anchor point.
 	// void Initialize(b2Body* bodyA, b2Body* bodyB, const b2Vec2& anchor);
@@ -51,37 +62,101 @@ pub struct RevoluteJointDef {
 	pub max_motor_torque: Float32,	
 }
 
+impl Default for RevoluteJointDef {
+	fn default() -> RevoluteJointDef {
+		RevoluteJointDef {
+			user_data: 0,
+			body_a: None,
+			body_b: None,
+			collide_connected: false,
+			local_anchor_a: Vec2::zero(),
+			local_anchor_b: Vec2::zero(),
+			reference_angle: 0.0,
+			lower_angle: 0.0,
+			upper_angle: 0.0,
+			max_motor_torque: 0.0,
+			motor_speed: 0.0,
+			enable_limit: false,
+			enable_motor: false,
+		}
+	}
+}
+
 impl RevoluteJointDef {
-	pub fn new () -> (JointDef, RevoluteJointDef) {
-		(
-			JointDef { joint_type: JointType::RevoluteJoint, ..JointDef::default() }, 
-	    	RevoluteJointDef {
-				local_anchor_a: Vec2::zero(),
-				local_anchor_b: Vec2::zero(),
-				reference_angle: 0.0,
-				lower_angle: 0.0,
-				upper_angle: 0.0,
-				max_motor_torque: 0.0,
-				motor_speed: 0.0,
-				enable_limit: false,
-				enable_motor: false,
-		    }
-	    )
-    }
-
-    pub fn initialize(&mut self, joint: &mut JointDef, body_a: Body, body_b: Body, anchor: &Vec2) {
+	pub fn new() -> RevoluteJointDef {
+		RevoluteJointDef::default()
+	}
+
+    pub fn initialize(&mut self, body_a: Body, body_b: Body, anchor: &Vec2) {
 		self.local_anchor_a = body_a.get_local_point(anchor);
 		self.local_anchor_b = body_b.get_local_point(anchor);
 		self.reference_angle = body_b.get_angle() - body_a.get_angle();
-		joint.body_a = Some(body_a);
-		joint.body_b = Some(body_b);
+		self.body_a = Some(body_a);
+		self.body_b = Some(body_b);
     }
 }
 
+/// The layout of c_b2RevoluteJointDef.
+#[repr(C)]
+pub struct B2RevoluteJointDef {
+	base: B2JointDef,
+	local_anchor_a: Vec2,
+	local_anchor_b: Vec2,
+	reference_angle: Float32,
+	enable_limit: bool,
+	lower_angle: Float32,
+	upper_angle: Float32,
+	enable_motor: bool,
+	motor_speed: Float32,
+	max_motor_torque: Float32,
+}
+
+impl Sealed for RevoluteJointDef {}
+
+impl JointDef for RevoluteJointDef {
+	type B2Def = B2RevoluteJointDef;
+
+	fn joint_type(&self) -> JointType {
+		JointType::RevoluteJoint
+	}
+
+	fn to_b2(&self) -> B2RevoluteJointDef {
+		B2RevoluteJointDef {
+			base: B2JointDef::new(self.joint_type(), self.user_data, &self.body_a, &self.body_b, self.collide_connected),
+			local_anchor_a: self.local_anchor_a,
+			local_anchor_b: self.local_anchor_b,
+			reference_angle: self.reference_angle,
+			enable_limit: self.enable_limit,
+			lower_angle: self.lower_angle,
+			upper_angle: self.upper_angle,
+			enable_motor: self.enable_motor,
+			motor_speed: self.motor_speed,
+			max_motor_torque: self.max_motor_torque,
+		}
+	}
+}
+
 pub enum B2RevoluteJoint {}
 
 extern {
+    fn b2RevoluteJoint_Upcast(this: *mut B2RevoluteJoint) -> *mut B2Joint;
+    fn b2RevoluteJoint_GetLocalAnchorA(this: *const B2RevoluteJoint) -> *const Vec2;
+    fn b2RevoluteJoint_GetLocalAnchorB(this: *const B2RevoluteJoint) -> *const Vec2;
+    fn b2RevoluteJoint_GetReferenceAngle(this: *const B2RevoluteJoint) -> Float32;
+    fn b2RevoluteJoint_GetJointAngle(this: *const B2RevoluteJoint) -> Float32;
+    fn b2RevoluteJoint_GetJointSpeed(this: *const B2RevoluteJoint) -> Float32;
+    fn b2RevoluteJoint_IsLimitEnabled(this: *const B2RevoluteJoint) -> bool;
+    fn b2RevoluteJoint_EnableLimit(this: *mut B2RevoluteJoint, flag: bool);
+    fn b2RevoluteJoint_GetLowerLimit(this: *const B2RevoluteJoint) -> Float32;
+    fn b2RevoluteJoint_GetUpperLimit(this: *const B2RevoluteJoint) -> Float32;
+    fn b2RevoluteJoint_SetLimits(this: *mut B2RevoluteJoint, lower: Float32, upper: Float32);
+    fn b2RevoluteJoint_IsMotorEnabled(this: *const B2RevoluteJoint) -> bool;
+    fn b2RevoluteJoint_EnableMotor(this: *mut B2RevoluteJoint, flag: bool);
     fn b2RevoluteJoint_SetMotorSpeed(this: *mut B2RevoluteJoint, speed: Float32);
+    fn b2RevoluteJoint_GetMotorSpeed(this: *const B2RevoluteJoint) -> Float32;
+    fn b2RevoluteJoint_SetMaxMotorTorque(this: *mut B2RevoluteJoint, torque: Float32);
+    fn b2RevoluteJoint_GetMaxMotorTorque(this: *const B2RevoluteJoint) -> Float32;
+    fn b2RevoluteJoint_GetMotorTorque(this: *const B2RevoluteJoint, inv_dt: Float32) -> Float32;
 }
 
 
@@ -99,6 +174,90 @@ pub struct RevoluteJoint {
 
 impl RevoluteJoint {
 
+	/// The local anchor point relative to bodyA's origin.
+    pub fn get_local_anchor_a(&self) -> Vec2 {
+        unsafe {
+            *b2RevoluteJoint_GetLocalAnchorA(self.ptr)
+        }
+    }
+
+	/// The local anchor point relative to bodyB's origin.
+    pub fn get_local_anchor_b(&self) -> Vec2 {
+        unsafe {
+            *b2RevoluteJoint_GetLocalAnchorB(self.ptr)
+        }
+    }
+
+	/// Get the reference angle.
+    pub fn get_reference_angle(&self) -> f32 {
+        unsafe {
+            b2RevoluteJoint_GetReferenceAngle(self.ptr)
+        }
+    }
+
+	/// Get the current joint angle in radians.
+    pub fn get_joint_angle(&self) -> f32 {
+        unsafe {
+            b2RevoluteJoint_GetJointAngle(self.ptr)
+        }
+    }
+
+	/// Get the current joint angle speed in radians per second.
+    pub fn get_joint_speed(&self) -> f32 {
+        unsafe {
+            b2RevoluteJoint_GetJointSpeed(self.ptr)
+        }
+    }
+
+	/// Is the joint limit enabled?
+    pub fn is_limit_enabled(&self) -> bool {
+        unsafe {
+            b2RevoluteJoint_IsLimitEnabled(self.ptr)
+        }
+    }
+
+	/// Enable/disable the joint limit.
+    pub fn enable_limit(&self, flag: bool) {
+        unsafe {
+            b2RevoluteJoint_EnableLimit(self.ptr, flag);
+        }
+    }
+
+	/// Get the lower joint limit in radians.
+    pub fn get_lower_limit(&self) -> f32 {
+        unsafe {
+            b2RevoluteJoint_GetLowerLimit(self.ptr)
+        }
+    }
+
+	/// Get the upper joint limit in radians.
+    pub fn get_upper_limit(&self) -> f32 {
+        unsafe {
+            b2RevoluteJoint_GetUpperLimit(self.ptr)
+        }
+    }
+
+	/// Set the joint limits in radians.
+    pub fn set_limits(&self, lower: f32, upper: f32) {
+        unsafe {
+            b2RevoluteJoint_SetLimits(self.ptr, lower, upper);
+        }
+    }
+
+	/// Is the joint motor enabled?
+    pub fn is_motor_enabled(&self) -> bool {
+        unsafe {
+            b2RevoluteJoint_IsMotorEnabled(self.ptr)
+        }
+    }
+
+	/// Enable/disable the joint motor.
+    pub fn enable_motor(&self, flag: bool) {
+        unsafe {
+            b2RevoluteJoint_EnableMotor(self.ptr, flag);
+        }
+    }
+
 	/// Set the motor speed in radians per second.
     pub fn set_motor_speed(&self, speed: f32) {
         unsafe {
@@ -106,4 +265,52 @@ impl RevoluteJoint {
         }
     }
 
-}
\ No newline at end of file
+	/// Get the motor speed in radians per second.
+    pub fn get_motor_speed(&self) -> f32 {
+        unsafe {
+            b2RevoluteJoint_GetMotorSpeed(self.ptr)
+        }
+    }
+
+	/// Set the maximum motor torque, usually in N-m.
+    pub fn set_max_motor_torque(&self, torque: f32) {
+        unsafe {
+            b2RevoluteJoint_SetMaxMotorTorque(self.ptr, torque);
+        }
+    }
+
+	/// Get the maximum motor torque, usually in N-m.
+    pub fn get_max_motor_torque(&self) -> f32 {
+        unsafe {
+            b2RevoluteJoint_GetMaxMotorTorque(self.ptr)
+        }
+    }
+
+	/// Get the current motor torque given the inverse time step.
+	/// Unit is N*m.
+    pub fn get_motor_torque(&self, inv_dt: f32) -> f32 {
+        unsafe {
+            b2RevoluteJoint_GetMotorTorque(self.ptr, inv_dt)
+        }
+    }
+
+}
+
+impl From<RevoluteJoint> for Joint {
+	fn from(joint: RevoluteJoint) -> Joint {
+		unsafe {
+			Joint { ptr: b2RevoluteJoint_Upcast(joint.ptr) }
+		}
+	}
+}
+
+impl Joint {
+	/// Downcast to a revolute joint.
+	/// @return the revolute joint or None if this is another type of joint.
+	pub fn as_revolute_joint(&self) -> Option<RevoluteJoint> {
+		match self.get_type() {
+			JointType::RevoluteJoint => Some(RevoluteJoint { ptr: self.ptr as *mut B2RevoluteJoint }),
+			_ => None
+		}
+	}
+}
@@ -0,0 +1,166 @@
+use libc::size_t;
+use super::super::super::common::math::*;
+use super::super::super::common::settings::*;
+use super::super::super::dynamics::body::Body;
+
+use super::{B2JointDef, Sealed, B2Joint, Joint, JointType, JointDef, LimitState};
+
+/// Rope joint definition. This requires two body anchor points and
+/// a maximum lengths.
+/// Note: by default the connected objects will not collide.
+/// see collideConnected in b2JointDef.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct RopeJointDef {
+
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub user_data: size_t,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_a: Option<Body>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_b: Option<Body>,
+	pub collide_connected: bool,
+
+	/// The local anchor point relative to bodyA's origin.
+	pub local_anchor_a: Vec2,
+
+	/// The local anchor point relative to bodyB's origin.
+	pub local_anchor_b: Vec2,
+
+	/// The maximum length of the rope.
+	/// Warning: this must be larger than b2_linearSlop or
+	/// the joint will have no effect.
+	pub max_length: Float32,
+}
+
+impl Default for RopeJointDef {
+	fn default() -> RopeJointDef {
+		RopeJointDef {
+			user_data: 0,
+			body_a: None,
+			body_b: None,
+			collide_connected: false,
+			local_anchor_a: Vec2::new(-1.0, 0.0),
+			local_anchor_b: Vec2::new(1.0, 0.0),
+			max_length: 0.0,
+		}
+	}
+}
+
+impl RopeJointDef {
+	pub fn new() -> RopeJointDef {
+		RopeJointDef::default()
+	}
+}
+
+/// The layout of c_b2RopeJointDef.
+#[repr(C)]
+pub struct B2RopeJointDef {
+	base: B2JointDef,
+	local_anchor_a: Vec2,
+	local_anchor_b: Vec2,
+	max_length: Float32,
+}
+
+impl Sealed for RopeJointDef {}
+
+impl JointDef for RopeJointDef {
+	type B2Def = B2RopeJointDef;
+
+	fn joint_type(&self) -> JointType {
+		JointType::RopeJoint
+	}
+
+	fn to_b2(&self) -> B2RopeJointDef {
+		B2RopeJointDef {
+			base: B2JointDef::new(self.joint_type(), self.user_data, &self.body_a, &self.body_b, self.collide_connected),
+			local_anchor_a: self.local_anchor_a,
+			local_anchor_b: self.local_anchor_b,
+			max_length: self.max_length,
+		}
+	}
+}
+
+pub enum B2RopeJoint {}
+
+extern {
+    fn b2RopeJoint_Upcast(this: *mut B2RopeJoint) -> *mut B2Joint;
+    fn b2RopeJoint_GetLocalAnchorA(this: *const B2RopeJoint) -> *const Vec2;
+    fn b2RopeJoint_GetLocalAnchorB(this: *const B2RopeJoint) -> *const Vec2;
+    fn b2RopeJoint_SetMaxLength(this: *mut B2RopeJoint, length: Float32);
+    fn b2RopeJoint_GetMaxLength(this: *const B2RopeJoint) -> Float32;
+    fn b2RopeJoint_GetLimitState(this: *const B2RopeJoint) -> LimitState;
+}
+
+/// A rope joint enforces a maximum distance between two points
+/// on two bodies. It has no other effect.
+/// Warning: if you attempt to change the maximum length during
+/// the simulation you will get some non-physical behavior.
+/// A model that would allow you to dynamically modify the length
+/// would have some sponginess, so I chose not to implement it
+/// that way. See b2DistanceJoint if you want to dynamically
+/// control length.
+#[allow(raw_pointer_derive)]
+#[derive(Clone, Debug)]
+pub struct RopeJoint {
+	pub ptr: *mut B2RopeJoint
+}
+
+impl RopeJoint {
+
+	/// The local anchor point relative to bodyA's origin.
+    pub fn get_local_anchor_a(&self) -> Vec2 {
+        unsafe {
+            *b2RopeJoint_GetLocalAnchorA(self.ptr)
+        }
+    }
+
+	/// The local anchor point relative to bodyB's origin.
+    pub fn get_local_anchor_b(&self) -> Vec2 {
+        unsafe {
+            *b2RopeJoint_GetLocalAnchorB(self.ptr)
+        }
+    }
+
+	/// Set the maximum length of the rope.
+    pub fn set_max_length(&self, length: f32) {
+        unsafe {
+            b2RopeJoint_SetMaxLength(self.ptr, length);
+        }
+    }
+
+	/// Get the maximum length of the rope.
+    pub fn get_max_length(&self) -> f32 {
+        unsafe {
+            b2RopeJoint_GetMaxLength(self.ptr)
+        }
+    }
+
+	/// Get the state of the length limit.
+    pub fn get_limit_state(&self) -> LimitState {
+        unsafe {
+            b2RopeJoint_GetLimitState(self.ptr)
+        }
+    }
+
+}
+
+impl From<RopeJoint> for Joint {
+	fn from(joint: RopeJoint) -> Joint {
+		unsafe {
+			Joint { ptr: b2RopeJoint_Upcast(joint.ptr) }
+		}
+	}
+}
+
+impl Joint {
+	/// Downcast to a rope joint.
+	/// @return the rope joint or None if this is another type of joint.
+	pub fn as_rope_joint(&self) -> Option<RopeJoint> {
+		match self.get_type() {
+			JointType::RopeJoint => Some(RopeJoint { ptr: self.ptr as *mut B2RopeJoint }),
+			_ => None
+		}
+	}
+}
@@ -0,0 +1,196 @@
+use libc::size_t;
+use super::super::super::common::math::*;
+use super::super::super::common::settings::*;
+use super::super::super::dynamics::body::Body;
+
+use super::{B2JointDef, Sealed, B2Joint, Joint, JointType, JointDef};
+
+/// Weld joint definition. You need to specify local anchor points
+/// where they are attached and the relative body angle. The position
+/// of the anchor points is important for computing the reaction torque.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct WeldJointDef {
+
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub user_data: size_t,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_a: Option<Body>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_b: Option<Body>,
+	pub collide_connected: bool,
+
+	/// The local anchor point relative to bodyA's origin.
+	pub local_anchor_a: Vec2,
+
+	/// The local anchor point relative to bodyB's origin.
+	pub local_anchor_b: Vec2,
+
+	/// The bodyB angle minus bodyA angle in the reference state (radians).
+	pub reference_angle: Float32,
+
+	/// The mass-spring-damper frequency in Hertz. Rotation only.
+	/// Disable softness with a value of 0.
+	pub frequency_hz: Float32,
+
+	/// The damping ratio. 0 = no damping, 1 = critical damping.
+	pub damping_ratio: Float32,
+}
+
+impl Default for WeldJointDef {
+	fn default() -> WeldJointDef {
+		WeldJointDef {
+			user_data: 0,
+			body_a: None,
+			body_b: None,
+			collide_connected: false,
+			local_anchor_a: Vec2::zero(),
+			local_anchor_b: Vec2::zero(),
+			reference_angle: 0.0,
+			frequency_hz: 0.0,
+			damping_ratio: 0.0,
+		}
+	}
+}
+
+impl WeldJointDef {
+	pub fn new() -> WeldJointDef {
+		WeldJointDef::default()
+	}
+
+	/// Initialize the bodies, anchors, and reference angle using a world
+	/// anchor point.
+	pub fn initialize(&mut self, body_a: Body, body_b: Body, anchor: &Vec2) {
+		self.local_anchor_a = body_a.get_local_point(anchor);
+		self.local_anchor_b = body_b.get_local_point(anchor);
+		self.reference_angle = body_b.get_angle() - body_a.get_angle();
+		self.body_a = Some(body_a);
+		self.body_b = Some(body_b);
+	}
+}
+
+/// The layout of c_b2WeldJointDef.
+#[repr(C)]
+pub struct B2WeldJointDef {
+	base: B2JointDef,
+	local_anchor_a: Vec2,
+	local_anchor_b: Vec2,
+	reference_angle: Float32,
+	frequency_hz: Float32,
+	damping_ratio: Float32,
+}
+
+impl Sealed for WeldJointDef {}
+
+impl JointDef for WeldJointDef {
+	type B2Def = B2WeldJointDef;
+
+	fn joint_type(&self) -> JointType {
+		JointType::WeldJoint
+	}
+
+	fn to_b2(&self) -> B2WeldJointDef {
+		B2WeldJointDef {
+			base: B2JointDef::new(self.joint_type(), self.user_data, &self.body_a, &self.body_b, self.collide_connected),
+			local_anchor_a: self.local_anchor_a,
+			local_anchor_b: self.local_anchor_b,
+			reference_angle: self.reference_angle,
+			frequency_hz: self.frequency_hz,
+			damping_ratio: self.damping_ratio,
+		}
+	}
+}
+
+pub enum B2WeldJoint {}
+
+extern {
+    fn b2WeldJoint_Upcast(this: *mut B2WeldJoint) -> *mut B2Joint;
+    fn b2WeldJoint_GetLocalAnchorA(this: *const B2WeldJoint) -> *const Vec2;
+    fn b2WeldJoint_GetLocalAnchorB(this: *const B2WeldJoint) -> *const Vec2;
+    fn b2WeldJoint_GetReferenceAngle(this: *const B2WeldJoint) -> Float32;
+    fn b2WeldJoint_SetFrequency(this: *mut B2WeldJoint, hz: Float32);
+    fn b2WeldJoint_GetFrequency(this: *const B2WeldJoint) -> Float32;
+    fn b2WeldJoint_SetDampingRatio(this: *mut B2WeldJoint, ratio: Float32);
+    fn b2WeldJoint_GetDampingRatio(this: *const B2WeldJoint) -> Float32;
+}
+
+/// A weld joint essentially glues two bodies together. A weld joint may
+/// distort somewhat because the island constraint solver is approximate.
+#[allow(raw_pointer_derive)]
+#[derive(Clone, Debug)]
+pub struct WeldJoint {
+	pub ptr: *mut B2WeldJoint
+}
+
+impl WeldJoint {
+
+	/// The local anchor point relative to bodyA's origin.
+    pub fn get_local_anchor_a(&self) -> Vec2 {
+        unsafe {
+            *b2WeldJoint_GetLocalAnchorA(self.ptr)
+        }
+    }
+
+	/// The local anchor point relative to bodyB's origin.
+    pub fn get_local_anchor_b(&self) -> Vec2 {
+        unsafe {
+            *b2WeldJoint_GetLocalAnchorB(self.ptr)
+        }
+    }
+
+	/// Get the reference angle.
+    pub fn get_reference_angle(&self) -> f32 {
+        unsafe {
+            b2WeldJoint_GetReferenceAngle(self.ptr)
+        }
+    }
+
+	/// Set frequency in Hz.
+    pub fn set_frequency(&self, hz: f32) {
+        unsafe {
+            b2WeldJoint_SetFrequency(self.ptr, hz);
+        }
+    }
+
+	/// Get frequency in Hz.
+    pub fn get_frequency(&self) -> f32 {
+        unsafe {
+            b2WeldJoint_GetFrequency(self.ptr)
+        }
+    }
+
+	/// Set damping ratio.
+    pub fn set_damping_ratio(&self, ratio: f32) {
+        unsafe {
+            b2WeldJoint_SetDampingRatio(self.ptr, ratio);
+        }
+    }
+
+	/// Get damping ratio.
+    pub fn get_damping_ratio(&self) -> f32 {
+        unsafe {
+            b2WeldJoint_GetDampingRatio(self.ptr)
+        }
+    }
+
+}
+
+impl From<WeldJoint> for Joint {
+	fn from(joint: WeldJoint) -> Joint {
+		unsafe {
+			Joint { ptr: b2WeldJoint_Upcast(joint.ptr) }
+		}
+	}
+}
+
+impl Joint {
+	/// Downcast to a weld joint.
+	/// @return the weld joint or None if this is another type of joint.
+	pub fn as_weld_joint(&self) -> Option<WeldJoint> {
+		match self.get_type() {
+			JointType::WeldJoint => Some(WeldJoint { ptr: self.ptr as *mut B2WeldJoint }),
+			_ => None
+		}
+	}
+}
@@ -0,0 +1,291 @@
+use libc::size_t;
+use super::super::super::common::math::*;
+use super::super::super::common::settings::*;
+use super::super::super::dynamics::body::Body;
+
+use super::{B2JointDef, Sealed, B2Joint, Joint, JointType, JointDef};
+
+/// Wheel joint definition. This requires defining a line of
+/// motion using an axis and an anchor point. The definition uses local
+/// anchor points and a local axis so that the initial configuration
+/// can violate the constraint slightly. The joint translation is zero
+/// when the local anchor points coincide in world space. Using local
+/// anchors and a local axis helps when saving and loading a game.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct WheelJointDef {
+
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub user_data: size_t,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_a: Option<Body>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body_b: Option<Body>,
+	pub collide_connected: bool,
+
+	/// The local anchor point relative to bodyA's origin.
+	pub local_anchor_a: Vec2,
+
+	/// The local anchor point relative to bodyB's origin.
+	pub local_anchor_b: Vec2,
+
+	/// The local translation axis in bodyA.
+	pub local_axis_a: Vec2,
+
+	/// Enable/disable the joint motor.
+	pub enable_motor: bool,
+
+	/// The maximum motor torque, usually in N-m.
+	pub max_motor_torque: Float32,
+
+	/// The desired motor speed in radians per second.
+	pub motor_speed: Float32,
+
+	/// Suspension frequency, zero indicates no suspension
+	pub frequency_hz: Float32,
+
+	/// Suspension damping ratio, one indicates critical damping
+	pub damping_ratio: Float32,
+}
+
+impl Default for WheelJointDef {
+	fn default() -> WheelJointDef {
+		WheelJointDef {
+			user_data: 0,
+			body_a: None,
+			body_b: None,
+			collide_connected: false,
+			local_anchor_a: Vec2::zero(),
+			local_anchor_b: Vec2::zero(),
+			local_axis_a: Vec2::new(1.0, 0.0),
+			enable_motor: false,
+			max_motor_torque: 0.0,
+			motor_speed: 0.0,
+			frequency_hz: 2.0,
+			damping_ratio: 0.7,
+		}
+	}
+}
+
+impl WheelJointDef {
+	pub fn new() -> WheelJointDef {
+		WheelJointDef::default()
+	}
+
+	/// Initialize the bodies, anchors, axis, and reference angle using the world
+	/// anchor and world axis.
+	pub fn initialize(&mut self, body_a: Body, body_b: Body, anchor: &Vec2, axis: &Vec2) {
+		self.local_anchor_a = body_a.get_local_point(anchor);
+		self.local_anchor_b = body_b.get_local_point(anchor);
+		self.local_axis_a = body_a.get_local_vector(axis);
+		self.body_a = Some(body_a);
+		self.body_b = Some(body_b);
+	}
+}
+
+/// The layout of c_b2WheelJointDef.
+#[repr(C)]
+pub struct B2WheelJointDef {
+	base: B2JointDef,
+	local_anchor_a: Vec2,
+	local_anchor_b: Vec2,
+	local_axis_a: Vec2,
+	enable_motor: bool,
+	max_motor_torque: Float32,
+	motor_speed: Float32,
+	frequency_hz: Float32,
+	damping_ratio: Float32,
+}
+
+impl Sealed for WheelJointDef {}
+
+impl JointDef for WheelJointDef {
+	type B2Def = B2WheelJointDef;
+
+	fn joint_type(&self) -> JointType {
+		JointType::WheelJoint
+	}
+
+	fn to_b2(&self) -> B2WheelJointDef {
+		B2WheelJointDef {
+			base: B2JointDef::new(self.joint_type(), self.user_data, &self.body_a, &self.body_b, self.collide_connected),
+			local_anchor_a: self.local_anchor_a,
+			local_anchor_b: self.local_anchor_b,
+			local_axis_a: self.local_axis_a,
+			enable_motor: self.enable_motor,
+			max_motor_torque: self.max_motor_torque,
+			motor_speed: self.motor_speed,
+			frequency_hz: self.frequency_hz,
+			damping_ratio: self.damping_ratio,
+		}
+	}
+}
+
+pub enum B2WheelJoint {}
+
+extern {
+    fn b2WheelJoint_Upcast(this: *mut B2WheelJoint) -> *mut B2Joint;
+    fn b2WheelJoint_GetLocalAnchorA(this: *const B2WheelJoint) -> *const Vec2;
+    fn b2WheelJoint_GetLocalAnchorB(this: *const B2WheelJoint) -> *const Vec2;
+    fn b2WheelJoint_GetLocalAxisA(this: *const B2WheelJoint) -> *const Vec2;
+    fn b2WheelJoint_GetJointTranslation(this: *const B2WheelJoint) -> Float32;
+    fn b2WheelJoint_GetJointSpeed(this: *const B2WheelJoint) -> Float32;
+    fn b2WheelJoint_IsMotorEnabled(this: *const B2WheelJoint) -> bool;
+    fn b2WheelJoint_EnableMotor(this: *mut B2WheelJoint, flag: bool);
+    fn b2WheelJoint_SetMotorSpeed(this: *mut B2WheelJoint, speed: Float32);
+    fn b2WheelJoint_GetMotorSpeed(this: *const B2WheelJoint) -> Float32;
+    fn b2WheelJoint_SetMaxMotorTorque(this: *mut B2WheelJoint, torque: Float32);
+    fn b2WheelJoint_GetMaxMotorTorque(this: *const B2WheelJoint) -> Float32;
+    fn b2WheelJoint_GetMotorTorque(this: *const B2WheelJoint, inv_dt: Float32) -> Float32;
+    fn b2WheelJoint_SetSpringFrequencyHz(this: *mut B2WheelJoint, hz: Float32);
+    fn b2WheelJoint_GetSpringFrequencyHz(this: *const B2WheelJoint) -> Float32;
+    fn b2WheelJoint_SetSpringDampingRatio(this: *mut B2WheelJoint, ratio: Float32);
+    fn b2WheelJoint_GetSpringDampingRatio(this: *const B2WheelJoint) -> Float32;
+}
+
+/// A wheel joint. This joint provides two degrees of freedom: translation
+/// along an axis fixed in bodyA and rotation in the plane. You can use a
+/// joint limit to restrict the range of motion and a joint motor to drive
+/// the rotation or to model rotational friction.
+/// This joint is designed for vehicle suspensions.
+#[allow(raw_pointer_derive)]
+#[derive(Clone, Debug)]
+pub struct WheelJoint {
+	pub ptr: *mut B2WheelJoint
+}
+
+impl WheelJoint {
+
+	/// The local anchor point relative to bodyA's origin.
+    pub fn get_local_anchor_a(&self) -> Vec2 {
+        unsafe {
+            *b2WheelJoint_GetLocalAnchorA(self.ptr)
+        }
+    }
+
+	/// The local anchor point relative to bodyB's origin.
+    pub fn get_local_anchor_b(&self) -> Vec2 {
+        unsafe {
+            *b2WheelJoint_GetLocalAnchorB(self.ptr)
+        }
+    }
+
+	/// The local joint axis relative to bodyA.
+    pub fn get_local_axis_a(&self) -> Vec2 {
+        unsafe {
+            *b2WheelJoint_GetLocalAxisA(self.ptr)
+        }
+    }
+
+	/// Get the current joint translation, usually in meters.
+    pub fn get_joint_translation(&self) -> f32 {
+        unsafe {
+            b2WheelJoint_GetJointTranslation(self.ptr)
+        }
+    }
+
+	/// Get the current joint translation speed, usually in meters per second.
+    pub fn get_joint_speed(&self) -> f32 {
+        unsafe {
+            b2WheelJoint_GetJointSpeed(self.ptr)
+        }
+    }
+
+	/// Is the joint motor enabled?
+    pub fn is_motor_enabled(&self) -> bool {
+        unsafe {
+            b2WheelJoint_IsMotorEnabled(self.ptr)
+        }
+    }
+
+	/// Enable/disable the joint motor.
+    pub fn enable_motor(&self, flag: bool) {
+        unsafe {
+            b2WheelJoint_EnableMotor(self.ptr, flag);
+        }
+    }
+
+	/// Set the motor speed, usually in radians per second.
+    pub fn set_motor_speed(&self, speed: f32) {
+        unsafe {
+            b2WheelJoint_SetMotorSpeed(self.ptr, speed);
+        }
+    }
+
+	/// Get the motor speed, usually in radians per second.
+    pub fn get_motor_speed(&self) -> f32 {
+        unsafe {
+            b2WheelJoint_GetMotorSpeed(self.ptr)
+        }
+    }
+
+	/// Set the maximum motor torque, usually in N-m.
+    pub fn set_max_motor_torque(&self, torque: f32) {
+        unsafe {
+            b2WheelJoint_SetMaxMotorTorque(self.ptr, torque);
+        }
+    }
+
+	/// Get the maximum motor torque, usually in N-m.
+    pub fn get_max_motor_torque(&self) -> f32 {
+        unsafe {
+            b2WheelJoint_GetMaxMotorTorque(self.ptr)
+        }
+    }
+
+	/// Get the current motor torque given the inverse time step, usually in N-m.
+    pub fn get_motor_torque(&self, inv_dt: f32) -> f32 {
+        unsafe {
+            b2WheelJoint_GetMotorTorque(self.ptr, inv_dt)
+        }
+    }
+
+	/// Set the spring frequency in hertz. Setting the frequency to zero disables the spring.
+    pub fn set_spring_frequency_hz(&self, hz: f32) {
+        unsafe {
+            b2WheelJoint_SetSpringFrequencyHz(self.ptr, hz);
+        }
+    }
+
+	/// Get the spring frequency in hertz.
+    pub fn get_spring_frequency_hz(&self) -> f32 {
+        unsafe {
+            b2WheelJoint_GetSpringFrequencyHz(self.ptr)
+        }
+    }
+
+	/// Set the spring damping ratio.
+    pub fn set_spring_damping_ratio(&self, ratio: f32) {
+        unsafe {
+            b2WheelJoint_SetSpringDampingRatio(self.ptr, ratio);
+        }
+    }
+
+	/// Get the spring damping ratio.
+    pub fn get_spring_damping_ratio(&self) -> f32 {
+        unsafe {
+            b2WheelJoint_GetSpringDampingRatio(self.ptr)
+        }
+    }
+
+}
+
+impl From<WheelJoint> for Joint {
+	fn from(joint: WheelJoint) -> Joint {
+		unsafe {
+			Joint { ptr: b2WheelJoint_Upcast(joint.ptr) }
+		}
+	}
+}
+
+impl Joint {
+	/// Downcast to a wheel joint.
+	/// @return the wheel joint or None if this is another type of joint.
+	pub fn as_wheel_joint(&self) -> Option<WheelJoint> {
+		match self.get_type() {
+			JointType::WheelJoint => Some(WheelJoint { ptr: self.ptr as *mut B2WheelJoint }),
+			_ => None
+		}
+	}
+}
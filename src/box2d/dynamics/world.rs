@@ -1,5 +1,4 @@
-use libc::size_t;
-use std::ptr;
+use std::collections::HashMap;
 use super::body::*;
 use super::super::common::math::*;
 use super::super::common::settings::*;
@@ -12,41 +11,25 @@ extern {
     fn b2World_CreateBody(world: *mut B2World, bd: *const BodyDef) -> *mut B2Body;
     fn b2World_CreateParticleSystem(world: *mut B2World, def: *const ParticleSystemDef) -> *mut B2ParticleSystem;
     fn b2World_Delete(world: *mut B2World);
+    fn b2World_CreateJoint(world: *mut B2World, def: *const joints::B2JointDef) -> *mut joints::B2Joint;
+    fn b2World_DestroyJoint(world: *mut B2World, joint: *mut joints::B2Joint);
     fn b2World_GetBodyCount(world: *const B2World) -> Int32;
     fn b2World_GetJointCount(world: *const B2World) -> Int32;
     fn b2World_GetBodyList(world: *const B2World) -> *mut B2Body;
+    fn b2World_GetJointList(world: *mut B2World) -> *mut joints::B2Joint;
     fn b2World_GetGravity(world: *mut B2World) -> Vec2;
     fn b2World_GetParticleSystemList(world: *const B2World) -> *mut B2ParticleSystem;
     fn b2World_New(gravity: *const Vec2) -> *mut B2World;
     fn b2World_Step(this: *mut B2World, timeStep: Float32, velocityIterations: Int32, positionIterations: Int32);
-
-    fn b2World_CreateRevoluteJoint(
-        world: *mut B2World,
-        
-        joint_type: joints::JointType,
-        user_data: size_t,
-        body_a: *mut B2Body,
-        body_b: *mut B2Body,
-        collide_connected: bool,
-
-        local_anchor_a: Vec2,
-        local_anchor_b: Vec2,
-        reference_angle: Float32,
-        enable_limit: bool,
-        lower_angle: Float32,
-        upper_angle: Float32,
-        enable_motor: bool,
-        motor_speed: Float32,
-        max_motor_torque: Float32
-    ) -> *mut joints::revolute_joint::B2RevoluteJoint;
-
 }
 
 /// The world class manages all physics entities, dynamic simulation,
 /// and asynchronous queries. The world also contains efficient memory
 /// management facilities.
 pub struct World {
-	pub ptr: *mut B2World
+	pub ptr: *mut B2World,
+	owned: bool,
+	particle_data: HashMap<usize, ParticleSystemData>,
 }
 
 impl World {
@@ -55,8 +38,32 @@ impl World {
     /// @param gravity the world gravity vector.
     pub fn new(gravity: &Vec2) -> World {
         unsafe {
-            World { ptr: b2World_New(gravity) }
+            World { ptr: b2World_New(gravity), owned: true, particle_data: HashMap::new() }
+        }
+    }
+
+    /// Wrap a world owned by another World value. It isn't deleted on drop.
+    pub(crate) fn from_ptr(ptr: *mut B2World) -> World {
+        World { ptr: ptr, owned: false, particle_data: HashMap::new() }
+    }
+
+    /// Check that this is the World value owning the particle system. Only
+    /// the owner guards the particle buffers, a World returned by
+    /// Body::get_world doesn't.
+    pub(crate) fn owns(&self, system: &ParticleSystem) -> bool {
+        let mut next = self.get_particle_system_list();
+        while let Some(s) = next {
+            if s.ptr == system.ptr {
+                return self.owned;
+            }
+            next = s.get_next();
         }
+        false
+    }
+
+    /// Get the data kept for a particle system of this world.
+    pub(crate) fn particle_data(&mut self, system: &ParticleSystem) -> &mut ParticleSystemData {
+        self.particle_data.entry(system.ptr as usize).or_default()
     }
 
     /// Create a rigid body given a definition. No reference to the definition
@@ -68,34 +75,25 @@ impl World {
         }
     }
 
-    /// Create a revolute joint to constrain bodies together. No reference to the definition
+    /// Create a joint to constrain bodies together. No reference to the definition
     /// is retained. This may cause the connected bodies to cease colliding.
+    /// Use the `as_*` methods of the returned joint to get the concrete joint.
     /// @warning This function is locked during callbacks.
-    pub fn create_revolute_joint(&mut self, def: &(joints::JointDef, joints::revolute_joint::RevoluteJointDef)) -> joints::revolute_joint::RevoluteJoint {
+    /// @panics if body_a or body_b is missing or both are the same body.
+    pub fn create_joint<D: joints::JointDef>(&mut self, def: &D) -> joints::Joint {
+        let def = def.to_b2();
+        let base = &def as *const D::B2Def as *const joints::B2JointDef;
         unsafe {
-            joints::revolute_joint::RevoluteJoint {ptr: b2World_CreateRevoluteJoint(
-                self.ptr,
-                def.0.joint_type,
-                def.0.user_data,
-                match def.0.body_a {
-                    Some(ref b) =>b.ptr,
-                    None => ptr::null_mut()
-                },
-                match def.0.body_b {
-                    Some(ref b) =>b.ptr,
-                    None => ptr::null_mut()
-                },
-                def.0.collide_connected,
-                def.1.local_anchor_a,
-                def.1.local_anchor_b,
-                def.1.reference_angle,
-                def.1.enable_limit,
-                def.1.lower_angle,
-                def.1.upper_angle,
-                def.1.enable_motor,
-                def.1.motor_speed,
-                def.1.max_motor_torque
-            )}
+            (*base).assert_bodies();
+            joints::Joint { ptr: b2World_CreateJoint(self.ptr, base) }
+        }
+    }
+
+    /// Destroy a joint. This may cause the connected bodies to begin colliding.
+    /// @warning This function is locked during callbacks.
+    pub fn destroy_joint(&mut self, joint: joints::Joint) {
+        unsafe {
+            b2World_DestroyJoint(self.ptr, joint.ptr);
         }
     }
 
@@ -138,6 +136,22 @@ impl World {
         }
     }
 
+    /// Get the world joint list. With the returned joint, use b2Joint::GetNext to get
+    /// the next joint in the world list. A NULL joint indicates the end of the list.
+    /// @return the head of the world joint list.
+    pub fn get_joint_list(&self) -> Option<joints::Joint> {
+        let ptr;
+        unsafe {
+            ptr = b2World_GetJointList(self.ptr);
+        }
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(joints::Joint { ptr: ptr })
+        }
+    }
+
     /// Get the world particle-system list. With the returned body, use
     /// b2ParticleSystem::GetNext to get the next particle-system in the world
     /// list. A NULL particle-system indicates the end of the list.
@@ -177,8 +191,16 @@ impl World {
 
 impl Drop for World {
     fn drop(&mut self) {
-        unsafe {
-            b2World_Delete(self.ptr);
+        if self.owned {
+            // The particle handles are freed with the world.
+            for data in self.particle_data.values() {
+                for alive in data.handles.values() {
+                    alive.set(false);
+                }
+            }
+            unsafe {
+                b2World_Delete(self.ptr);
+            }
         }
     }
 }
\ No newline at end of file
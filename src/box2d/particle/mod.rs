@@ -5,7 +5,10 @@ pub mod particle_system;
 use libc::size_t;
 use self::particle_color::*;
 use self::particle_group::*;
+use std::cell::Cell;
+use std::collections::HashMap;
 use std::ptr;
+use std::rc::Rc;
 use super::common::math::*;
 use super::common::settings::*;
 
@@ -62,13 +65,133 @@ bitflags! {
     }
 }
 
+/// Names of the particle flags, used to serialize ParticleFlags.
+pub const PARTICLE_FLAG_NAMES: [(&'static str, ParticleFlags); 17] = [
+	("ZOMBIE_PARTICLE", ZOMBIE_PARTICLE),
+	("WALL_PARTICLE", WALL_PARTICLE),
+	("SPRING_PARTICLE", SPRING_PARTICLE),
+	("ELASTIC_PARTICLE", ELASTIC_PARTICLE),
+	("VISCOUS_PARTICLE", VISCOUS_PARTICLE),
+	("POWDER_PARTICLE", POWDER_PARTICLE),
+	("TENSILE_PARTICLE", TENSILE_PARTICLE),
+	("COLOR_MIXING_PARTICLE", COLOR_MIXING_PARTICLE),
+	("DESTRUCTION_LISTENER_PARTICLE", DESTRUCTION_LISTENER_PARTICLE),
+	("BARRIER_PARTICLE", BARRIER_PARTICLE),
+	("STATIC_PRESSURE_PARTICLE", STATIC_PRESSURE_PARTICLE),
+	("REACTIVE_PARTICLE", REACTIVE_PARTICLE),
+	("REPULSIVE_PARTICLE", REPULSIVE_PARTICLE),
+	("FIXTURE_CONTACT_LISTENER_PARTICLE", FIXTURE_CONTACT_LISTENER_PARTICLE),
+	("PARTICLE_CONTACT_LISTENER_PARTICLE", PARTICLE_CONTACT_LISTENER_PARTICLE),
+	("FIXTURE_CONTACT_FILTER_PARTICLE", FIXTURE_CONTACT_FILTER_PARTICLE),
+	("PARTICLE_CONTACT_FILTER_PARTICLE", PARTICLE_CONTACT_FILTER_PARTICLE),
+];
+
+bitflags! {
+	/// The particle group type. Can be combined with the | operator.
+	flags ParticleGroupFlags: UInt32 {
+		/// Prevents overlapping or leaking.
+		const SOLID_PARTICLE_GROUP = 1 << 0,
+		/// Keeps its shape.
+		const RIGID_PARTICLE_GROUP = 1 << 1,
+		/// Won't be destroyed if it gets empty.
+		const PARTICLE_GROUP_CAN_BE_EMPTY = 1 << 2,
+		/// Will be destroyed on next simulation step.
+		const PARTICLE_GROUP_WILL_BE_DESTROYED = 1 << 3,
+		/// Updates depth data on next simulation step.
+		const PARTICLE_GROUP_NEEDS_UPDATE_DEPTH = 1 << 4,
+
+		const PARTICLE_GROUP_INTERNAL_MASK =
+			PARTICLE_GROUP_WILL_BE_DESTROYED.bits |
+			PARTICLE_GROUP_NEEDS_UPDATE_DEPTH.bits,
+	}
+}
+
+/// Names of the particle group flags, used to serialize ParticleGroupFlags.
+pub const PARTICLE_GROUP_FLAG_NAMES: [(&'static str, ParticleGroupFlags); 5] = [
+	("SOLID_PARTICLE_GROUP", SOLID_PARTICLE_GROUP),
+	("RIGID_PARTICLE_GROUP", RIGID_PARTICLE_GROUP),
+	("PARTICLE_GROUP_CAN_BE_EMPTY", PARTICLE_GROUP_CAN_BE_EMPTY),
+	("PARTICLE_GROUP_WILL_BE_DESTROYED", PARTICLE_GROUP_WILL_BE_DESTROYED),
+	("PARTICLE_GROUP_NEEDS_UPDATE_DEPTH", PARTICLE_GROUP_NEEDS_UPDATE_DEPTH),
+];
+
+/// Serializes ParticleFlags as a list of flag names. WATER_PARTICLE has no
+/// bits so it is the empty list, but it is accepted when deserializing.
+#[cfg(feature = "serde")]
+mod particle_flags_serde {
+	use serde::{Deserialize, Deserializer, Serializer};
+	use serde::de::Error;
+	use serde::ser::SerializeSeq;
+	use super::*;
+
+	pub fn serialize<S: Serializer>(flags: &ParticleFlags, serializer: S) -> Result<S::Ok, S::Error> {
+		let names: Vec<&str> = PARTICLE_FLAG_NAMES.iter()
+			.filter(|&&(_, flag)| flags.contains(flag))
+			.map(|&(name, _)| name)
+			.collect();
+		let mut seq = serializer.serialize_seq(Some(names.len()))?;
+		for name in names {
+			seq.serialize_element(name)?;
+		}
+		seq.end()
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ParticleFlags, D::Error> {
+		let names: Vec<String> = Vec::deserialize(deserializer)?;
+		let mut flags = ParticleFlags::empty();
+		for name in names {
+			if name == "WATER_PARTICLE" {
+				continue;
+			}
+			match PARTICLE_FLAG_NAMES.iter().find(|&&(n, _)| n == name) {
+				Some(&(_, flag)) => flags = flags | flag,
+				None => return Err(D::Error::custom(format!("unknown particle flag {}", name))),
+			}
+		}
+		Ok(flags)
+	}
+}
+
+/// Serializes ParticleGroupFlags as a list of flag names.
+#[cfg(feature = "serde")]
+mod particle_group_flags_serde {
+	use serde::{Deserialize, Deserializer, Serializer};
+	use serde::de::Error;
+	use serde::ser::SerializeSeq;
+	use super::*;
+
+	pub fn serialize<S: Serializer>(flags: &ParticleGroupFlags, serializer: S) -> Result<S::Ok, S::Error> {
+		let names: Vec<&str> = PARTICLE_GROUP_FLAG_NAMES.iter()
+			.filter(|&&(_, flag)| flags.contains(flag))
+			.map(|&(name, _)| name)
+			.collect();
+		let mut seq = serializer.serialize_seq(Some(names.len()))?;
+		for name in names {
+			seq.serialize_element(name)?;
+		}
+		seq.end()
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ParticleGroupFlags, D::Error> {
+		let names: Vec<String> = Vec::deserialize(deserializer)?;
+		let mut flags = ParticleGroupFlags::empty();
+		for name in names {
+			match PARTICLE_GROUP_FLAG_NAMES.iter().find(|&&(n, _)| n == name) {
+				Some(&(_, flag)) => flags = flags | flag,
+				None => return Err(D::Error::custom(format!("unknown particle group flag {}", name))),
+			}
+		}
+		Ok(flags)
+	}
+}
+
 #[repr(C)]
 #[derive(Debug)]
 pub struct B2ParticleDef {
  	flags: UInt32,
 	position: Vec2,
 	velocity: Vec2,
- 	color: *mut B2ParticleColor,
+ 	color: ParticleColor,
 	lifetime: Float32,
 	user_data: size_t,
 	group: *mut B2ParticleGroup,
@@ -76,12 +199,15 @@ pub struct B2ParticleDef {
 
 /// A particle definition holds all the data needed to construct a particle.
 /// You can safely re-use these definitions.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct ParticleDef {
 	/// \brief Specifies the type of particle (see #b2ParticleFlag).
 	///
 	/// A particle may be more than one type.
 	/// Multiple types are chained by logical sums, for example:
 	/// pd.flags = b2_elasticParticle | b2_viscousParticle
+	#[cfg_attr(feature = "serde", serde(with = "particle_flags_serde"))]
  	pub flags: ParticleFlags,
 
 	/// The world position of the particle.
@@ -98,9 +224,11 @@ pub struct ParticleDef {
 	pub lifetime: Float32,
 
 	/// Use this to store application-specific body data.
+	#[cfg_attr(feature = "serde", serde(skip))]
 	pub user_data: size_t,
 
 	// An existing particle group to which the particle will be added.
+	#[cfg_attr(feature = "serde", serde(skip))]
 	pub group: Option<ParticleGroup>,
 }
 
@@ -124,7 +252,7 @@ impl B2ParticleDef {
 		 	flags: pd.flags.bits(),
 			position: pd.position.clone(),
 			velocity: pd.velocity.clone(),
-		 	color: pd.color.ptr(),
+		 	color: pd.color,
 			lifetime: pd.lifetime,
 			user_data: pd.user_data,
 			group: match pd.group {
@@ -133,4 +261,53 @@ impl B2ParticleDef {
 			}
 		}
 	}
-}
\ No newline at end of file
+}
+
+pub enum B2ParticleHandle {}
+
+extern {
+	fn b2ParticleHandle_GetIndex(this: *const B2ParticleHandle) -> Int32;
+}
+
+/// Handle to a particle. Particle indices are ephemeral: the same index might
+/// refer to a different particle, from frame-to-frame. If you need to keep a
+/// reference to a particular particle across frames, you should acquire a
+/// ParticleHandle with ParticleSystem::get_particle_handle_from_index.
+#[allow(raw_pointer_derive)]
+#[derive(Clone)]
+pub struct ParticleHandle {
+	ptr: *const B2ParticleHandle,
+	alive: Rc<Cell<bool>>,
+}
+
+impl ParticleHandle {
+
+	pub(crate) fn new(ptr: *const B2ParticleHandle, alive: Rc<Cell<bool>>) -> ParticleHandle {
+		ParticleHandle { ptr: ptr, alive: alive }
+	}
+
+	/// Mark the handles of destroyed particles as dead and forget them.
+	/// LiquidFun resets their index and reuses them for the next particle
+	/// asking for a handle, so this must run before handing out a handle.
+	pub(crate) fn release_destroyed(handles: &mut HashMap<usize, Rc<Cell<bool>>>) {
+		handles.retain(|&ptr, alive| {
+			let destroyed = unsafe { b2ParticleHandle_GetIndex(ptr as *const B2ParticleHandle) } < 0;
+			if destroyed {
+				alive.set(false);
+			}
+			!destroyed
+		});
+	}
+
+	/// Get the index of the particle associated with this handle, or None if
+	/// the particle was destroyed.
+	pub fn index(&self) -> Option<Int32> {
+		if !self.alive.get() {
+			return None;
+		}
+		// LiquidFun keeps freed handles in its slab allocator until the
+		// particle system is destroyed, with their index reset.
+		let index = unsafe { b2ParticleHandle_GetIndex(self.ptr) };
+		if index >= 0 { Some(index) } else { None }
+	}
+}
@@ -1,20 +1,13 @@
 use super::super::common::settings::*;
 
-pub enum B2ParticleColor {}
-
-extern {
-	fn b2ParticleColor_New(r: UInt8, g: UInt8, b: UInt8, a: UInt8) -> *mut B2ParticleColor;
-	fn b2ParticleColor_Delete(p: *mut B2ParticleColor);
-    fn b2ParticleColor_Set(p: *mut B2ParticleColor, r: UInt8, g: UInt8, b: UInt8, a: UInt8);
-    fn b2ParticleColor_IsZero(p: *mut B2ParticleColor) -> bool;
-}
-
-
 /// Small color object for each particle
-#[allow(raw_pointer_derive)]
-#[derive(Clone)]
+#[repr(C)]
+#[derive(Debug, PartialEq, Default, Copy, Clone)]
 pub struct ParticleColor {
-	pub ptr: *mut B2ParticleColor
+    pub r: UInt8,
+    pub g: UInt8,
+    pub b: UInt8,
+    pub a: UInt8,
 }
 
 impl ParticleColor {
@@ -23,42 +16,47 @@ impl ParticleColor {
     /// (opacity).
     /// Each element can be specified 0 to 255.
     pub fn new(r: UInt8, g: UInt8, b: UInt8, a: UInt8) -> ParticleColor {
-        unsafe {
-            ParticleColor { ptr: b2ParticleColor_New(r, g, b, a) }
-        }
+        ParticleColor { r: r, g: g, b: b, a: a }
     }
 
     /// Create a ParticleColor with zero values.
 	pub fn zero() -> ParticleColor {
-		ParticleColor::new(0, 0, 0, 0)
+		ParticleColor::default()
 	}
 
     /// True when all four color elements equal 0. When true, a particle color
     /// buffer isn't allocated by CreateParticle().
     pub fn is_zero(&self) -> bool {
-        unsafe {
-            b2ParticleColor_IsZero(self.ptr)
-        }
+        self.r == 0 && self.g == 0 && self.b == 0 && self.a == 0
     }
 
-    /// Get ParticleColor's raw pointer.
-	pub fn ptr(&self) -> *mut B2ParticleColor {
-		self.ptr
-	}
+    /// Get the four color elements: r, g, b and a.
+    pub fn rgba(&self) -> [UInt8; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
 
     /// Sets color for current object using the four elements described above.
-    pub fn set(&self, r: UInt8, g: UInt8, b: UInt8, a: UInt8) {
-        unsafe {
-            b2ParticleColor_Set(self.ptr, r, g, b, a);
-        }
+    pub fn set(&mut self, r: UInt8, g: UInt8, b: UInt8, a: UInt8) {
+        self.r = r;
+        self.g = g;
+        self.b = b;
+        self.a = a;
     }
 
 }
 
-impl Drop for ParticleColor {
-    fn drop(&mut self) {
-        unsafe {
-            b2ParticleColor_Delete(self.ptr);
-        }
+/// Serialized as `[r, g, b, a]`.
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for ParticleColor {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.rgba().serialize(serializer)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "serde")]
+impl<'de> ::serde::Deserialize<'de> for ParticleColor {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<ParticleColor, D::Error> {
+        let rgba = <[UInt8; 4]>::deserialize(deserializer)?;
+        Ok(ParticleColor::new(rgba[0], rgba[1], rgba[2], rgba[3]))
+    }
+}
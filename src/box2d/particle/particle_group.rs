@@ -1,5 +1,183 @@
+use libc::size_t;
+use std::ptr;
+use super::*;
+use super::particle_color::*;
+use super::particle_system::*;
+use super::super::collision::shapes::shape::*;
+use super::super::common::math::*;
+use super::super::common::settings::*;
+use super::super::dynamics::world::World;
+
+/// A particle group definition holds all the data needed to construct a
+/// particle group.  You can safely re-use these definitions.
+/// Unlike the C++ API the definition owns descriptions of its shapes, the
+/// LiquidFun shapes are built from them when the group is created.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ParticleGroupDef {
+	/// The particle-behavior flags (See ParticleFlags).
+	#[cfg_attr(feature = "serde", serde(with = "super::particle_flags_serde"))]
+	pub flags: ParticleFlags,
+
+	/// The group-construction flags (See ParticleGroupFlags).
+	#[cfg_attr(feature = "serde", serde(with = "super::particle_group_flags_serde"))]
+	pub group_flags: ParticleGroupFlags,
+
+	/// The world position of the group.
+	/// Moves the group's shape a distance equal to the value of position.
+	pub position: Vec2,
+
+	/// The world angle of the group in radians.
+	/// Rotates the shape by an angle equal to the value of angle.
+	pub angle: Float32,
+
+	/// The linear velocity of the group's origin in world co-ordinates.
+	pub linear_velocity: Vec2,
+
+	/// The angular velocity of the group.
+	pub angular_velocity: Float32,
+
+	/// The color of all particles in the group.
+	pub color: ParticleColor,
+
+	/// The strength of cohesion among the particles in a group with flag
+	/// ELASTIC_PARTICLE or SPRING_PARTICLE.
+	pub strength: Float32,
+
+	/// The shape where particles will be added.
+	pub shape: Option<ShapeDef>,
+
+	/// The shapes where particles will be added, in addition to shape.
+	pub shapes: Vec<ShapeDef>,
+
+	/// The interval of particles in the shape.
+	/// If it is 0, b2_particleStride * particleDiameter is used instead.
+	pub stride: Float32,
+
+	/// The positions of particles added in addition to the ones added in the
+	/// shapes, relative to the group position and angle.
+	pub position_data: Vec<Vec2>,
+
+	/// Lifetime of the particle group in seconds.  A value <= 0.0f indicates a
+	/// particle group with infinite lifetime.
+	pub lifetime: Float32,
+
+	/// Use this to store application-specific group data.
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub user_data: size_t,
+
+	/// An existing particle group to which the particles will be added.
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub group: Option<ParticleGroup>,
+}
+
+impl Default for ParticleGroupDef {
+	fn default() -> ParticleGroupDef {
+		ParticleGroupDef {
+			flags: ParticleFlags::empty(),
+			group_flags: ParticleGroupFlags::empty(),
+			position: Vec2::zero(),
+			angle: 0.0,
+			linear_velocity: Vec2::zero(),
+			angular_velocity: 0.0,
+			color: ParticleColor::zero(),
+			strength: 1.0,
+			shape: None,
+			shapes: Vec::new(),
+			stride: 0.0,
+			position_data: Vec::new(),
+			lifetime: 0.0,
+			user_data: 0,
+			group: None,
+		}
+	}
+}
+
+impl ParticleGroupDef {
+	/// Create a definition filling the given shape with particles.
+	pub fn new<S: Into<ShapeDef>>(shape: S) -> ParticleGroupDef {
+		ParticleGroupDef { shape: Some(shape.into()), ..ParticleGroupDef::default() }
+	}
+}
+
+#[repr(C)]
+pub struct B2ParticleGroupDef {
+	flags: UInt32,
+	group_flags: UInt32,
+	position: Vec2,
+	angle: Float32,
+	linear_velocity: Vec2,
+	angular_velocity: Float32,
+	color: ParticleColor,
+	strength: Float32,
+	shape: *const B2Shape,
+	shapes: *const *const B2Shape,
+	shape_count: Int32,
+	stride: Float32,
+	particle_count: Int32,
+	position_data: *const Vec2,
+	lifetime: Float32,
+	user_data: size_t,
+	group: *mut B2ParticleGroup,
+}
+
+impl B2ParticleGroupDef {
+	pub fn from(gd: &ParticleGroupDef, shape: Option<&Shape>, shapes: &[*const B2Shape]) -> B2ParticleGroupDef {
+		B2ParticleGroupDef {
+			flags: gd.flags.bits(),
+			group_flags: gd.group_flags.bits(),
+			position: gd.position,
+			angle: gd.angle,
+			linear_velocity: gd.linear_velocity,
+			angular_velocity: gd.angular_velocity,
+			color: gd.color,
+			strength: gd.strength,
+			shape: match shape {
+				Some(s) => s.handle() as *const B2Shape,
+				None => ptr::null()
+			},
+			shapes: if shapes.is_empty() { ptr::null() } else { shapes.as_ptr() },
+			shape_count: shapes.len() as Int32,
+			stride: gd.stride,
+			particle_count: gd.position_data.len() as Int32,
+			position_data: gd.position_data.as_ptr(),
+			lifetime: gd.lifetime,
+			user_data: gd.user_data,
+			group: match gd.group {
+				Some(ref g) => g.ptr(),
+				None => ptr::null_mut()
+			}
+		}
+	}
+}
+
 pub enum B2ParticleGroup {}
 
+extern {
+	fn b2ParticleGroup_GetNext(this: *mut B2ParticleGroup) -> *mut B2ParticleGroup;
+	fn b2ParticleGroup_GetParticleSystem(this: *mut B2ParticleGroup) -> *mut B2ParticleSystem;
+	fn b2ParticleGroup_GetParticleCount(this: *const B2ParticleGroup) -> Int32;
+	fn b2ParticleGroup_GetBufferIndex(this: *const B2ParticleGroup) -> Int32;
+	fn b2ParticleGroup_ContainsParticle(this: *const B2ParticleGroup, index: Int32) -> bool;
+	fn b2ParticleGroup_GetAllParticleFlags(this: *const B2ParticleGroup) -> UInt32;
+	fn b2ParticleGroup_GetGroupFlags(this: *const B2ParticleGroup) -> UInt32;
+	fn b2ParticleGroup_SetGroupFlags(this: *mut B2ParticleGroup, flags: UInt32);
+	fn b2ParticleGroup_GetMass(this: *const B2ParticleGroup) -> Float32;
+	fn b2ParticleGroup_GetInertia(this: *const B2ParticleGroup) -> Float32;
+	fn b2ParticleGroup_GetCenter(this: *const B2ParticleGroup) -> Vec2;
+	fn b2ParticleGroup_GetLinearVelocity(this: *const B2ParticleGroup) -> Vec2;
+	fn b2ParticleGroup_GetAngularVelocity(this: *const B2ParticleGroup) -> Float32;
+	fn b2ParticleGroup_GetTransform(this: *const B2ParticleGroup) -> *const Transform;
+	fn b2ParticleGroup_GetPosition(this: *const B2ParticleGroup) -> *const Vec2;
+	fn b2ParticleGroup_GetAngle(this: *const B2ParticleGroup) -> Float32;
+	fn b2ParticleGroup_GetLinearVelocityFromWorldPoint(this: *const B2ParticleGroup, world_point: &Vec2) -> Vec2;
+	fn b2ParticleGroup_GetUserData(this: *const B2ParticleGroup) -> size_t;
+	fn b2ParticleGroup_SetUserData(this: *mut B2ParticleGroup, data: size_t);
+	fn b2ParticleGroup_ApplyForce(this: *mut B2ParticleGroup, force: &Vec2);
+	fn b2ParticleGroup_ApplyLinearImpulse(this: *mut B2ParticleGroup, impulse: &Vec2);
+	fn b2ParticleGroup_DestroyParticles(this: *mut B2ParticleGroup, call_destruction_listener: bool);
+}
+
 /// A group of particles. b2ParticleGroup::CreateParticleGroup creates these.
 #[allow(raw_pointer_derive)]
 #[derive(Clone)]
@@ -13,4 +191,182 @@ impl ParticleGroup {
 	pub fn ptr(&self) -> *mut B2ParticleGroup {
 		self.ptr
 	}
-}
\ No newline at end of file
+
+	/// Get the next particle group from the list in b2_World.
+	pub fn get_next(&self) -> Option<ParticleGroup> {
+		let ptr;
+		unsafe {
+			ptr = b2ParticleGroup_GetNext(self.ptr);
+		}
+
+		if ptr.is_null() {
+			None
+		} else {
+			Some(ParticleGroup { ptr: ptr })
+		}
+	}
+
+	/// Get the particle system that holds this particle group.
+	pub fn get_particle_system(&self) -> ParticleSystem {
+		unsafe {
+			ParticleSystem { ptr: b2ParticleGroup_GetParticleSystem(self.ptr) }
+		}
+	}
+
+	/// Get the number of particles.
+	pub fn get_particle_count(&self) -> i32 {
+		unsafe {
+			b2ParticleGroup_GetParticleCount(self.ptr)
+		}
+	}
+
+	/// Get the offset of this group in the global particle buffer.
+	pub fn get_buffer_index(&self) -> i32 {
+		unsafe {
+			b2ParticleGroup_GetBufferIndex(self.ptr)
+		}
+	}
+
+	/// Does this group contain the particle.
+	pub fn contains_particle(&self, index: i32) -> bool {
+		unsafe {
+			b2ParticleGroup_ContainsParticle(self.ptr, index)
+		}
+	}
+
+	/// Get the logical sum of particle flags.
+	pub fn get_all_particle_flags(&self) -> ParticleFlags {
+		unsafe {
+			ParticleFlags::from_bits_truncate(b2ParticleGroup_GetAllParticleFlags(self.ptr))
+		}
+	}
+
+	/// Get the construction flags for the group.
+	pub fn get_group_flags(&self) -> ParticleGroupFlags {
+		unsafe {
+			ParticleGroupFlags::from_bits_truncate(b2ParticleGroup_GetGroupFlags(self.ptr))
+		}
+	}
+
+	/// Set the construction flags for the group.
+	pub fn set_group_flags(&self, flags: ParticleGroupFlags) {
+		unsafe {
+			b2ParticleGroup_SetGroupFlags(self.ptr, flags.bits());
+		}
+	}
+
+	/// Get the total mass of the group: the sum of all particles in it.
+	pub fn get_mass(&self) -> f32 {
+		unsafe {
+			b2ParticleGroup_GetMass(self.ptr)
+		}
+	}
+
+	/// Get the moment of inertia for the group.
+	pub fn get_inertia(&self) -> f32 {
+		unsafe {
+			b2ParticleGroup_GetInertia(self.ptr)
+		}
+	}
+
+	/// Get the center of gravity for the group.
+	pub fn get_center(&self) -> Vec2 {
+		unsafe {
+			b2ParticleGroup_GetCenter(self.ptr)
+		}
+	}
+
+	/// Get the linear velocity of the group.
+	pub fn get_linear_velocity(&self) -> Vec2 {
+		unsafe {
+			b2ParticleGroup_GetLinearVelocity(self.ptr)
+		}
+	}
+
+	/// Get the angular velocity of the group.
+	pub fn get_angular_velocity(&self) -> f32 {
+		unsafe {
+			b2ParticleGroup_GetAngularVelocity(self.ptr)
+		}
+	}
+
+	/// Get the position of the group's origin and rotation.
+	/// Used only with groups of rigid particles.
+	pub fn get_transform(&self) -> Transform {
+		unsafe {
+			*b2ParticleGroup_GetTransform(self.ptr)
+		}
+	}
+
+	/// Get position of the particle group as a whole.
+	/// Used only with groups of rigid particles.
+	pub fn get_position(&self) -> Vec2 {
+		unsafe {
+			*b2ParticleGroup_GetPosition(self.ptr)
+		}
+	}
+
+	/// Get the rotational angle of the particle group as a whole.
+	/// Used only with groups of rigid particles.
+	pub fn get_angle(&self) -> f32 {
+		unsafe {
+			b2ParticleGroup_GetAngle(self.ptr)
+		}
+	}
+
+	/// Get the world linear velocity of a world point, from the average linear
+	/// and angular velocities of the particle group.
+	/// @param worldPoint a point in world coordinates.
+	/// @return the world velocity of a point.
+	pub fn get_linear_velocity_from_world_point(&self, world_point: &Vec2) -> Vec2 {
+		unsafe {
+			b2ParticleGroup_GetLinearVelocityFromWorldPoint(self.ptr, world_point)
+		}
+	}
+
+	/// Get the user data pointer that was provided in the group definition.
+	pub fn get_user_data(&self) -> usize {
+		unsafe {
+			b2ParticleGroup_GetUserData(self.ptr)
+		}
+	}
+
+	/// Set the user data. Use this to store your application specific data.
+	pub fn set_user_data(&self, data: usize) {
+		unsafe {
+			b2ParticleGroup_SetUserData(self.ptr, data);
+		}
+	}
+
+	/// Call b2ParticleSystem::ApplyForce for every particle in the group.
+	/// The world must own the particle system, see ParticleSystem.
+	pub fn apply_force(&self, world: &mut World, force: &Vec2) {
+		self.get_particle_system().assert_world(world);
+		unsafe {
+			b2ParticleGroup_ApplyForce(self.ptr, force);
+		}
+	}
+
+	/// Call b2ParticleSystem::ApplyLinearImpulse for every particle in the
+	/// group.
+	/// The world must own the particle system, see ParticleSystem.
+	pub fn apply_linear_impulse(&self, world: &mut World, impulse: &Vec2) {
+		self.get_particle_system().assert_world(world);
+		unsafe {
+			b2ParticleGroup_ApplyLinearImpulse(self.ptr, impulse);
+		}
+	}
+
+	/// Destroy all the particles in this group.
+	/// This function is locked during callbacks.
+	/// @param Whether to call the world b2DestructionListener for each
+	/// particle is destroyed.
+	/// @warning This function is locked during callbacks.
+	/// The world must own the particle system, see ParticleSystem.
+	pub fn destroy_particles(&self, world: &mut World, call_destruction_listener: bool) {
+		self.get_particle_system().assert_world(world);
+		unsafe {
+			b2ParticleGroup_DestroyParticles(self.ptr, call_destruction_listener);
+		}
+	}
+}
@@ -1,10 +1,22 @@
+use libc::size_t;
 use super::*;
+use super::particle_color::*;
+use super::particle_group::*;
+use super::super::collision::shapes::shape::*;
 use super::super::common::math::*;
 use super::super::common::settings::*;
+use super::super::dynamics::body::*;
+use super::super::dynamics::fixture::*;
+use super::super::dynamics::world::*;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::rc::Rc;
 use std::slice;
 
 #[repr(C)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct ParticleSystemDef {
     /// Enable strict Particle/Body contact check.
     /// See SetStrictContactCheck for details.
@@ -131,17 +143,175 @@ impl Default for ParticleSystemDef {
     }
 }
 
+/// A contact between two particles, see ParticleSystem::get_contacts.
+/// The particle indices are 32-bit, LiquidFun is built without
+/// B2_USE_16_BIT_PARTICLE_INDICES.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ParticleContact {
+    /// Indices of the respective particles making contact.
+    pub index_a: Int32,
+    pub index_b: Int32,
+
+    /// Weight of the contact. A value between 0.0f and 1.0f.
+    /// 0.0f ==> particles are just barely touching
+    /// 1.0f ==> particles are perfectly on top of each other
+    pub weight: Float32,
+
+    /// The normalized direction from A to B.
+    pub normal: Vec2,
+
+    /// The logical sum of the particle behaviors that have been set.
+    /// See the ParticleFlags struct.
+    pub flags: UInt32,
+}
+
+impl ParticleContact {
+
+    /// Get the particle behaviors of the contact.
+    pub fn get_flags(&self) -> ParticleFlags {
+        ParticleFlags::from_bits_truncate(self.flags)
+    }
+}
+
+/// A contact between a particle and a fixture, see
+/// ParticleSystem::get_body_contacts.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ParticleBodyContact {
+    /// Index of the particle making contact.
+    pub index: Int32,
+
+    /// The body making contact.
+    pub body: *mut B2Body,
+
+    /// The specific fixture making contact
+    pub fixture: *mut B2Fixture,
+
+    /// Weight of the contact. A value between 0.0f and 1.0f.
+    pub weight: Float32,
+
+    /// The normalized direction from the particle to the body.
+    pub normal: Vec2,
+
+    /// The effective mass used in calculating force.
+    pub mass: Float32,
+}
+
+impl ParticleBodyContact {
+
+    /// Get the body making contact.
+    pub fn get_body(&self) -> Body {
+        Body { ptr: self.body }
+    }
+
+    /// Get the fixture making contact.
+    pub fn get_fixture(&self) -> Fixture {
+        Fixture { ptr: self.fixture }
+    }
+}
+
+/// Connection between two particles
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ParticlePair {
+    /// Indices of the respective particles making pair.
+    pub index_a: Int32,
+    pub index_b: Int32,
+
+    /// The logical sum of the particle flags. See the ParticleFlags struct.
+    pub flags: UInt32,
+
+    /// The strength of cohesion among the particles.
+    pub strength: Float32,
+
+    /// The initial distance of the particles.
+    pub distance: Float32,
+}
+
+/// Connection between three particles
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ParticleTriad {
+    /// Indices of the respective particles making triad.
+    pub index_a: Int32,
+    pub index_b: Int32,
+    pub index_c: Int32,
+
+    /// The logical sum of the particle flags. See the ParticleFlags struct.
+    pub flags: UInt32,
+
+    /// The strength of cohesion among the particles.
+    pub strength: Float32,
+
+    /// Values used for calculation.
+    pub pa: Vec2,
+    pub pb: Vec2,
+    pub pc: Vec2,
+    pub ka: Float32,
+    pub kb: Float32,
+    pub kc: Float32,
+    pub s: Float32,
+}
+
 pub enum B2ParticleSystem {}
 
 extern {
     fn b2ParticleSystem_CreateParticle(ps: *mut B2ParticleSystem, pd: &B2ParticleDef) -> Int32;
+    fn b2ParticleSystem_CreateParticleGroup(ps: *mut B2ParticleSystem, def: &B2ParticleGroupDef) -> *mut B2ParticleGroup;
+    fn b2ParticleSystem_JoinParticleGroups(ps: *mut B2ParticleSystem, group_a: *mut B2ParticleGroup, group_b: *mut B2ParticleGroup);
+    fn b2ParticleSystem_SplitParticleGroup(ps: *mut B2ParticleSystem, group: *mut B2ParticleGroup);
+    fn b2ParticleSystem_GetParticleHandleFromIndex(ps: *mut B2ParticleSystem, index: Int32) -> *const B2ParticleHandle;
     fn b2ParticleSystem_DestroyParticle(ps: *mut B2ParticleSystem, index: Int32);
     fn b2ParticleSystem_GetParticleCount(ps: *mut B2ParticleSystem) -> Int32;
     fn b2ParticleSystem_GetParticleFlags(ps: *mut B2ParticleSystem, index: Int32) -> UInt32;
+    fn b2ParticleSystem_SetParticleFlags(ps: *mut B2ParticleSystem, index: Int32, flags: UInt32);
+    fn b2ParticleSystem_SetParticleVelocity(ps: *mut B2ParticleSystem, index: Int32, velocity: &Vec2);
+    fn b2ParticleSystem_GetParticleLifetime(ps: *mut B2ParticleSystem, index: Int32) -> Float32;
+    fn b2ParticleSystem_SetParticleLifetime(ps: *mut B2ParticleSystem, index: Int32, lifetime: Float32);
+    fn b2ParticleSystem_ParticleApplyForce(ps: *mut B2ParticleSystem, index: Int32, force: &Vec2);
+    fn b2ParticleSystem_ParticleApplyLinearImpulse(ps: *mut B2ParticleSystem, index: Int32, impulse: &Vec2);
+    fn b2ParticleSystem_ApplyForce(ps: *mut B2ParticleSystem, first_index: Int32, last_index: Int32, force: &Vec2);
+    fn b2ParticleSystem_ApplyLinearImpulse(ps: *mut B2ParticleSystem, first_index: Int32, last_index: Int32, impulse: &Vec2);
+    fn b2ParticleSystem_GetParticleGroupList(ps: *mut B2ParticleSystem) -> *mut B2ParticleGroup;
+    fn b2ParticleSystem_GetParticleGroupCount(ps: *const B2ParticleSystem) -> Int32;
     fn b2ParticleSystem_GetNext(ps: *mut B2ParticleSystem) -> *mut B2ParticleSystem;
+    fn b2ParticleSystem_GetMaxParticleCount(ps: *const B2ParticleSystem) -> Int32;
+    fn b2ParticleSystem_SetMaxParticleCount(ps: *mut B2ParticleSystem, count: Int32);
+    fn b2ParticleSystem_GetDestructionByAge(ps: *const B2ParticleSystem) -> bool;
+    fn b2ParticleSystem_SetDestructionByAge(ps: *mut B2ParticleSystem, enable: bool);
+    fn b2ParticleSystem_DestroyOldestParticle(ps: *mut B2ParticleSystem, index: Int32, call_destruction_listener: bool);
+    fn b2ParticleSystem_DestroyParticlesInShape(ps: *mut B2ParticleSystem, shape: *const B2Shape, xf: &Transform, call_destruction_listener: bool) -> Int32;
     fn b2ParticleSystem_GetPositionBuffer(ps: *mut B2ParticleSystem) -> *mut Vec2;
+    fn b2ParticleSystem_GetVelocityBuffer(ps: *mut B2ParticleSystem) -> *mut Vec2;
+    fn b2ParticleSystem_GetColorBuffer(ps: *mut B2ParticleSystem) -> *mut ParticleColor;
+    fn b2ParticleSystem_GetGroupBuffer(ps: *mut B2ParticleSystem) -> *const *mut B2ParticleGroup;
+    fn b2ParticleSystem_GetWeightBuffer(ps: *mut B2ParticleSystem) -> *mut Float32;
+    fn b2ParticleSystem_GetUserDataBuffer(ps: *mut B2ParticleSystem) -> *mut size_t;
+    fn b2ParticleSystem_GetFlagsBuffer(ps: *const B2ParticleSystem) -> *const UInt32;
+    fn b2ParticleSystem_GetExpirationTimeBuffer(ps: *mut B2ParticleSystem) -> *const Int32;
+    fn b2ParticleSystem_GetIndexByExpirationTimeBuffer(ps: *mut B2ParticleSystem) -> *const Int32;
+    fn b2ParticleSystem_GetContacts(ps: *const B2ParticleSystem) -> *const ParticleContact;
+    fn b2ParticleSystem_GetContactCount(ps: *const B2ParticleSystem) -> Int32;
+    fn b2ParticleSystem_GetBodyContacts(ps: *const B2ParticleSystem) -> *const ParticleBodyContact;
+    fn b2ParticleSystem_GetBodyContactCount(ps: *const B2ParticleSystem) -> Int32;
+    fn b2ParticleSystem_GetPairs(ps: *const B2ParticleSystem) -> *const ParticlePair;
+    fn b2ParticleSystem_GetPairCount(ps: *const B2ParticleSystem) -> Int32;
+    fn b2ParticleSystem_GetTriads(ps: *const B2ParticleSystem) -> *const ParticleTriad;
+    fn b2ParticleSystem_GetTriadCount(ps: *const B2ParticleSystem) -> Int32;
+    fn b2ParticleSystem_SetFlagsBuffer(ps: *mut B2ParticleSystem, buffer: *mut UInt32, capacity: Int32);
+    fn b2ParticleSystem_SetPositionBuffer(ps: *mut B2ParticleSystem, buffer: *mut Vec2, capacity: Int32);
+    fn b2ParticleSystem_SetVelocityBuffer(ps: *mut B2ParticleSystem, buffer: *mut Vec2, capacity: Int32);
+    fn b2ParticleSystem_SetColorBuffer(ps: *mut B2ParticleSystem, buffer: *mut ParticleColor, capacity: Int32);
+    fn b2ParticleSystem_SetUserDataBuffer(ps: *mut B2ParticleSystem, buffer: *mut size_t, capacity: Int32);
 }
 
+/// A particle system, owned by the world it was created in.
+/// Functions that reallocate, reorder or write the particle data take the
+/// owning World mutably and the buffer accessors borrow it, so buffers can't
+/// be held across a step or a change to the particles.
+/// These functions panic when given a world that doesn't own the particle
+/// system, like the World returned by Body::get_world.
 #[allow(raw_pointer_derive)]
 #[derive(Clone)]
 pub struct ParticleSystem {
@@ -157,21 +327,114 @@ impl ParticleSystem {
     /// not destroy a particle until b2World::Step() has been called.
     /// @warning This function is locked during callbacks.
     /// @return the index of the particle.
-    pub fn create_particle(&self, pd: &ParticleDef) -> Int32 {
+    pub fn create_particle(&self, world: &mut World, pd: &ParticleDef) -> Int32 {
+        self.assert_world(world);
         unsafe {
             b2ParticleSystem_CreateParticle(self.ptr, &B2ParticleDef::from(pd))
         }
     }
 
+    /// Create a particle group whose properties have been defined.
+    /// No reference to the definition is retained.
+    /// @warning This function is locked during callbacks.
+    /// @panics if a shape definition is invalid, see ShapeDef::validate.
+    pub fn create_particle_group(&self, world: &mut World, def: &ParticleGroupDef) -> ParticleGroup {
+        self.assert_world(world);
+        let to_shape = |s: &ShapeDef| s.to_shape().unwrap_or_else(|e| panic!("invalid particle group shape: {}", e));
+        let shape = def.shape.as_ref().map(&to_shape);
+        let shapes: Vec<Box<Shape>> = def.shapes.iter().map(&to_shape).collect();
+        let handles: Vec<*const B2Shape> = shapes.iter().map(|s| s.handle() as *const B2Shape).collect();
+        unsafe {
+            ParticleGroup { ptr: b2ParticleSystem_CreateParticleGroup(self.ptr, &B2ParticleGroupDef::from(def, shape.as_ref().map(|s| &**s), &handles)) }
+        }
+    }
+
+    /// Retrieve a handle to the particle at the specified index.
+    /// Please see ParticleHandle for why you might want a handle.
+    /// @param world the world owning the particle system, which tracks the
+    /// handles to tell destroyed particles apart from the ones LiquidFun
+    /// reuses their handles for.
+    /// @panics if the world doesn't own the particle system or index is out
+    /// of range.
+    pub fn get_particle_handle_from_index(&self, world: &mut World, index: Int32) -> ParticleHandle {
+        self.assert_world(world);
+        self.assert_index(index);
+        let handles = &mut world.particle_data(self).handles;
+        ParticleHandle::release_destroyed(handles);
+        let ptr = unsafe {
+            b2ParticleSystem_GetParticleHandleFromIndex(self.ptr, index)
+        };
+        let alive = handles.entry(ptr as usize).or_insert_with(|| Rc::new(Cell::new(true)));
+        ParticleHandle::new(ptr, alive.clone())
+    }
+
+    /// Join two particle groups.
+    /// @param the first group. Expands to encompass the second group.
+    /// @param the second group. It is destroyed.
+    /// @warning This function is locked during callbacks.
+    pub fn join_particle_groups(&self, world: &mut World, group_a: &ParticleGroup, group_b: ParticleGroup) {
+        self.assert_world(world);
+        unsafe {
+            b2ParticleSystem_JoinParticleGroups(self.ptr, group_a.ptr, group_b.ptr);
+        }
+    }
+
+    /// Split particle group into multiple disconnected groups.
+    /// Particles moved to the new groups are cloned, the originals are
+    /// removed after the next simulation step.
+    /// @param the group to be split.
+    /// @warning This function is locked during callbacks.
+    pub fn split_particle_group(&self, world: &mut World, group: &ParticleGroup) {
+        self.assert_world(world);
+        unsafe {
+            b2ParticleSystem_SplitParticleGroup(self.ptr, group.ptr);
+        }
+    }
+
     /// Destroy a particle.
     /// The particle is removed after the next simulation step (see
     /// b2World::Step()).
-    pub fn destroy_particle(&self, index: Int32) {
+    pub fn destroy_particle(&self, world: &mut World, index: Int32) {
+        self.assert_world(world);
         unsafe {
             b2ParticleSystem_DestroyParticle(self.ptr, index);
         }
     }
 
+    /// Destroy the Nth oldest particle in the system.
+    /// The particle is removed after the next b2World::Step().
+    /// @param Index of the Nth oldest particle to destroy, 0 will destroy the
+    /// oldest particle in the system, 1 will destroy the next oldest
+    /// particle etc.
+    /// @param Whether to call the destruction listener just before the
+    /// particle is destroyed.
+    /// @panics if index is out of range.
+    pub fn destroy_oldest_particle(&self, world: &mut World, index: Int32, call_destruction_listener: bool) {
+        self.assert_world(world);
+        self.assert_index(index);
+        unsafe {
+            b2ParticleSystem_DestroyOldestParticle(self.ptr, index, call_destruction_listener);
+        }
+    }
+
+    /// Destroy particles inside a shape.
+    /// This function is locked during callbacks.
+    /// In addition, this function immediately destroys particles in the shape
+    /// in constrast to DestroyParticle() which defers the destruction until
+    /// the next simulation step.
+    /// @param Shape which encloses particles that should be destroyed.
+    /// @param Transform applied to the shape.
+    /// @param Whether to call the world b2DestructionListener for each
+    /// particle destroyed.
+    /// @warning This function is locked during callbacks.
+    /// @return Number of particles destroyed.
+    pub fn destroy_particles_in_shape(&self, world: &mut World, shape: &Shape, xf: &Transform, call_destruction_listener: bool) -> Int32 {
+        self.assert_world(world);
+        unsafe {
+            b2ParticleSystem_DestroyParticlesInShape(self.ptr, shape.handle(), xf, call_destruction_listener)
+        }
+    }
+
     /// Get the number of particles.
     pub fn get_particle_count(&self) -> Int32 {
         unsafe {
@@ -179,12 +442,183 @@ impl ParticleSystem {
         }
     }
 
+    /// Get the maximum number of particles.
+    pub fn get_max_particle_count(&self) -> Int32 {
+        unsafe {
+            b2ParticleSystem_GetMaxParticleCount(self.ptr)
+        }
+    }
+
+    /// Set the maximum number of particles.
+    /// A value of 0 means there is no maximum. The particle buffers can
+    /// continue to grow while b2World's block allocator still has memory.
+    /// Note: If you try to CreateParticle() with more than this count,
+    /// b2_invalidParticleIndex is returned unless
+    /// SetDestructionByAge() is used to enable the destruction of the
+    /// oldest particles in the system.
+    /// @panics if count is less than the number of particles.
+    pub fn set_max_particle_count(&self, count: Int32) {
+        assert!(count >= self.get_particle_count(),
+                "max particle count {} is less than the particle count", count);
+        unsafe {
+            b2ParticleSystem_SetMaxParticleCount(self.ptr, count);
+        }
+    }
+
+    /// Enable or disable the destruction of the oldest particle in
+    /// CreateParticle() when the maximum number of particles are present in
+    /// the system. Enabling it also enables particle lifetime tracking.
+    pub fn set_destruction_by_age(&self, enable: bool) {
+        unsafe {
+            b2ParticleSystem_SetDestructionByAge(self.ptr, enable);
+        }
+    }
+
+    /// Get whether the oldest particle will be destroyed in CreateParticle()
+    /// when the maximum number of particles are present in the system.
+    pub fn get_destruction_by_age(&self) -> bool {
+        unsafe {
+            b2ParticleSystem_GetDestructionByAge(self.ptr)
+        }
+    }
+
     /// Get flags for a particle. See the ParticleFlags struct.
+    /// @panics if index is out of range.
     pub fn get_particle_flags(&self, index: Int32) -> Option<ParticleFlags> {
+        self.assert_index(index);
         unsafe {
             ParticleFlags::from_bits(b2ParticleSystem_GetParticleFlags(self.ptr, index))
         }
-    }    
+    }
+
+    /// Set flags for a particle. See the ParticleFlags struct.
+    /// @panics if index is out of range.
+    pub fn set_particle_flags(&self, world: &mut World, index: Int32, flags: ParticleFlags) {
+        self.assert_world(world);
+        self.assert_index(index);
+        unsafe {
+            b2ParticleSystem_SetParticleFlags(self.ptr, index, flags.bits());
+        }
+    }
+
+    /// Set the velocity of particle at index.
+    /// @panics if index is out of range.
+    pub fn set_particle_velocity(&self, world: &mut World, index: Int32, velocity: &Vec2) {
+        self.assert_world(world);
+        self.assert_index(index);
+        unsafe {
+            b2ParticleSystem_SetParticleVelocity(self.ptr, index, velocity);
+        }
+    }
+
+    /// Get the lifetime (in seconds) of a particle relative to the current
+    /// time.  A value > 0.0f is returned if the particle is scheduled to be
+    /// destroyed in the future, values <= 0.0f indicate the particle has an
+    /// infinite lifetime.
+    /// @panics if index is out of range.
+    pub fn get_particle_lifetime(&self, index: Int32) -> f32 {
+        self.assert_index(index);
+        unsafe {
+            b2ParticleSystem_GetParticleLifetime(self.ptr, index)
+        }
+    }
+
+    /// Set the lifetime (in seconds) of a particle relative to the current
+    /// time.  A lifetime of less than or equal to 0.0f results in the particle
+    /// living forever until it's manually destroyed by the application.
+    /// @panics if index is out of range.
+    pub fn set_particle_lifetime(&self, world: &mut World, index: Int32, lifetime: f32) {
+        self.assert_world(world);
+        self.assert_index(index);
+        unsafe {
+            b2ParticleSystem_SetParticleLifetime(self.ptr, index, lifetime);
+        }
+    }
+
+    /// Apply a force to the center of a particle.
+    /// @param index the particle that will be modified.
+    /// @param force the world force vector, usually in Newtons (N).
+    /// @panics if index is out of range.
+    pub fn particle_apply_force(&self, world: &mut World, index: Int32, force: &Vec2) {
+        self.assert_world(world);
+        self.assert_index(index);
+        unsafe {
+            b2ParticleSystem_ParticleApplyForce(self.ptr, index, force);
+        }
+    }
+
+    /// Apply an impulse to one particle. This immediately modifies the
+    /// velocity. Similar to b2Body::ApplyLinearImpulse.
+    /// @param index the particle that will be modified.
+    /// @param impulse the world impulse vector, usually in N-seconds or
+    ///        kg-m/s.
+    /// @panics if index is out of range.
+    pub fn particle_apply_linear_impulse(&self, world: &mut World, index: Int32, impulse: &Vec2) {
+        self.assert_world(world);
+        self.assert_index(index);
+        unsafe {
+            b2ParticleSystem_ParticleApplyLinearImpulse(self.ptr, index, impulse);
+        }
+    }
+
+    /// Distribute a force across several particles. The particles must not be
+    /// wall particles. Note that the force is distributed across all the
+    /// particles, so calling this function for indices 0..N is not the same as
+    /// calling ParticleApplyForce(i, force) for i in 0..N.
+    /// @param first_index the first particle to be modified.
+    /// @param last_index one past the last particle to be modified.
+    /// @param force the world force vector, usually in Newtons (N).
+    /// @panics if the range isn't within 0..get_particle_count().
+    pub fn apply_force(&self, world: &mut World, first_index: Int32, last_index: Int32, force: &Vec2) {
+        self.assert_world(world);
+        self.assert_range(first_index, last_index);
+        unsafe {
+            b2ParticleSystem_ApplyForce(self.ptr, first_index, last_index, force);
+        }
+    }
+
+    /// Apply an impulse to all particles between 'firstIndex' and 'lastIndex'.
+    /// This immediately modifies the velocity. Note that the impulse is
+    /// applied to the total mass of all particles. So, calling
+    /// ParticleApplyLinearImpulse(0, impulse) and
+    /// ParticleApplyLinearImpulse(1, impulse) will impart twice as much
+    /// velocity as calling just ApplyLinearImpulse(0, 1, impulse).
+    /// @param first_index the first particle to be modified.
+    /// @param last_index one past the last particle to be modified.
+    /// @param impulse the world impulse vector, usually in N-seconds or
+    ///        kg-m/s.
+    /// @panics if the range isn't within 0..get_particle_count().
+    pub fn apply_linear_impulse(&self, world: &mut World, first_index: Int32, last_index: Int32, impulse: &Vec2) {
+        self.assert_world(world);
+        self.assert_range(first_index, last_index);
+        unsafe {
+            b2ParticleSystem_ApplyLinearImpulse(self.ptr, first_index, last_index, impulse);
+        }
+    }
+
+    /// Get the world particle group list. With the returned group, use
+    /// b2ParticleGroup::GetNext to get the next group in the world list.
+    /// A NULL group indicates the end of the list.
+    /// @return the head of the world particle group list.
+    pub fn get_particle_group_list(&self) -> Option<ParticleGroup> {
+        let ptr;
+        unsafe {
+            ptr = b2ParticleSystem_GetParticleGroupList(self.ptr);
+        }
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(ParticleGroup { ptr: ptr })
+        }
+    }
+
+    /// Get the number of particle groups.
+    pub fn get_particle_group_count(&self) -> Int32 {
+        unsafe {
+            b2ParticleSystem_GetParticleGroupCount(self.ptr)
+        }
+    }
 
     /// Get the next particle-system in the world's particle-system list.
     pub fn get_next(&self) -> Option<ParticleSystem> {
@@ -202,10 +636,318 @@ impl ParticleSystem {
     }
 
     /// Get the position of each particle
-    pub fn get_position_buffer(&self) -> &[Vec2] {
+    /// Array is length GetParticleCount()
+    pub fn get_position_buffer<'a>(&self, world: &'a World) -> &'a [Vec2] {
+        self.assert_world(world);
+        unsafe {
+            buffer(b2ParticleSystem_GetPositionBuffer(self.ptr), self.get_particle_count())
+        }
+    }
+
+    /// Get the position of each particle for writing.
+    pub fn get_position_buffer_mut<'a>(&self, world: &'a mut World) -> &'a mut [Vec2] {
+        self.assert_world(world);
+        unsafe {
+            buffer_mut(b2ParticleSystem_GetPositionBuffer(self.ptr), self.get_particle_count())
+        }
+    }
+
+    /// Get the velocity of each particle
+    /// Array is length GetParticleCount()
+    pub fn get_velocity_buffer<'a>(&self, world: &'a World) -> &'a [Vec2] {
+        self.assert_world(world);
+        unsafe {
+            buffer(b2ParticleSystem_GetVelocityBuffer(self.ptr), self.get_particle_count())
+        }
+    }
+
+    /// Get the velocity of each particle for writing.
+    pub fn get_velocity_buffer_mut<'a>(&self, world: &'a mut World) -> &'a mut [Vec2] {
+        self.assert_world(world);
+        unsafe {
+            buffer_mut(b2ParticleSystem_GetVelocityBuffer(self.ptr), self.get_particle_count())
+        }
+    }
+
+    /// Get the color of each particle
+    /// Array is length GetParticleCount()
+    /// The buffer is allocated on first access.
+    pub fn get_color_buffer<'a>(&self, world: &'a World) -> &'a [ParticleColor] {
+        self.assert_world(world);
+        unsafe {
+            buffer(b2ParticleSystem_GetColorBuffer(self.ptr), self.get_particle_count())
+        }
+    }
+
+    /// Get the color of each particle for writing.
+    pub fn get_color_buffer_mut<'a>(&self, world: &'a mut World) -> &'a mut [ParticleColor] {
+        self.assert_world(world);
+        unsafe {
+            buffer_mut(b2ParticleSystem_GetColorBuffer(self.ptr), self.get_particle_count())
+        }
+    }
+
+    /// Get the particle-group of each particle.
+    /// Array is length GetParticleCount()
+    /// Particles without a group hold None.
+    pub fn get_group_buffer(&self) -> Vec<Option<ParticleGroup>> {
+        let groups = unsafe {
+            buffer(b2ParticleSystem_GetGroupBuffer(self.ptr), self.get_particle_count())
+        };
+        groups.iter().map(|&ptr| if ptr.is_null() { None } else { Some(ParticleGroup { ptr: ptr }) }).collect()
+    }
+
+    /// Get the weight of each particle
+    /// Array is length GetParticleCount()
+    pub fn get_weight_buffer<'a>(&self, world: &'a World) -> &'a [Float32] {
+        self.assert_world(world);
+        unsafe {
+            buffer(b2ParticleSystem_GetWeightBuffer(self.ptr), self.get_particle_count())
+        }
+    }
+
+    /// Get the weight of each particle for writing.
+    pub fn get_weight_buffer_mut<'a>(&self, world: &'a mut World) -> &'a mut [Float32] {
+        self.assert_world(world);
+        unsafe {
+            buffer_mut(b2ParticleSystem_GetWeightBuffer(self.ptr), self.get_particle_count())
+        }
+    }
+
+    /// Get the user-specified data of each particle.
+    /// Array is length GetParticleCount()
+    /// The buffer is allocated on first access.
+    pub fn get_user_data_buffer<'a>(&self, world: &'a World) -> &'a [usize] {
+        self.assert_world(world);
+        unsafe {
+            buffer(b2ParticleSystem_GetUserDataBuffer(self.ptr), self.get_particle_count())
+        }
+    }
+
+    /// Get the user-specified data of each particle for writing.
+    pub fn get_user_data_buffer_mut<'a>(&self, world: &'a mut World) -> &'a mut [usize] {
+        self.assert_world(world);
+        unsafe {
+            buffer_mut(b2ParticleSystem_GetUserDataBuffer(self.ptr), self.get_particle_count())
+        }
+    }
+
+    /// Get the flags for each particle. See the ParticleFlags struct.
+    /// Array is length GetParticleCount()
+    pub fn get_flags_buffer<'a>(&self, world: &'a World) -> &'a [UInt32] {
+        self.assert_world(world);
         unsafe {
-            slice::from_raw_parts(b2ParticleSystem_GetPositionBuffer(self.ptr), self.get_particle_count() as usize)
+            buffer(b2ParticleSystem_GetFlagsBuffer(self.ptr), self.get_particle_count())
         }
     }
 
+    /// Get the array of particle expiration times indexed by particle index.
+    /// GetParticleCount() items are in the returned array.
+    pub fn get_expiration_time_buffer<'a>(&self, world: &'a World) -> &'a [Int32] {
+        self.assert_world(world);
+        unsafe {
+            buffer(b2ParticleSystem_GetExpirationTimeBuffer(self.ptr), self.get_particle_count())
+        }
+    }
+
+    /// Get the array of particle indices ordered by reverse lifetime.
+    /// The oldest particle indexes are at the end of the array with the
+    /// newest at the start.  Particles with infinite lifetimes
+    /// (i.e expiration times less than or equal to 0) are placed at the start
+    ///  of the array.
+    /// GetParticleCount() items are in the returned array.
+    pub fn get_index_by_expiration_time_buffer<'a>(&self, world: &'a World) -> &'a [Int32] {
+        self.assert_world(world);
+        unsafe {
+            buffer(b2ParticleSystem_GetIndexByExpirationTimeBuffer(self.ptr), self.get_particle_count())
+        }
+    }
+
+    /// Get contacts between particles
+    /// Contact data can be used for many reasons, for example to trigger
+    /// rendering or audio effects.
+    pub fn get_contacts<'a>(&self, world: &'a World) -> &'a [ParticleContact] {
+        self.assert_world(world);
+        unsafe {
+            buffer(b2ParticleSystem_GetContacts(self.ptr), b2ParticleSystem_GetContactCount(self.ptr))
+        }
+    }
+
+    /// Get contacts between particles and bodies
+    /// Contact data can be used for many reasons, for example to trigger
+    /// rendering or audio effects.
+    pub fn get_body_contacts<'a>(&self, world: &'a World) -> &'a [ParticleBodyContact] {
+        self.assert_world(world);
+        unsafe {
+            buffer(b2ParticleSystem_GetBodyContacts(self.ptr), b2ParticleSystem_GetBodyContactCount(self.ptr))
+        }
+    }
+
+    /// Get array of particle pairs. The particles in a pair:
+    ///   (1) are contacting,
+    ///   (2) are in the same particle group,
+    ///   (3) are part of a rigid particle group, or are spring, elastic,
+    ///       or wall particles.
+    ///   (4) have at least one particle that is a spring or barrier
+    ///       particle (i.e. one of the types in k_pairFlags),
+    ///   (5) have at least one particle that returns true for
+    ///       ConnectionFilter::IsNecessary,
+    ///   (6) are not zombie particles.
+    /// Essentially, this is an array of spring or barrier particles that
+    /// are interacting. The array is sorted by b2ParticlePair's indexA,
+    /// and then indexB. There are no duplicate entries.
+    pub fn get_pairs<'a>(&self, world: &'a World) -> &'a [ParticlePair] {
+        self.assert_world(world);
+        unsafe {
+            buffer(b2ParticleSystem_GetPairs(self.ptr), b2ParticleSystem_GetPairCount(self.ptr))
+        }
+    }
+
+    /// Get array of particle triads. The particles in a triad:
+    ///   (1) are in the same particle group,
+    ///   (2) are in a Voronoi triangle together,
+    ///   (3) are within b2_maxTriadDistance particle diameters of each
+    ///       other,
+    ///   (4) return true for ConnectionFilter::ShouldCreateTriad
+    ///   (5) have at least one particle of type elastic (i.e. one of the
+    ///       types in k_triadFlags),
+    ///   (6) are part of a rigid particle group, or are spring, elastic,
+    ///       or wall particles.
+    ///   (7) are not zombie particles.
+    /// Essentially, this is an array of elastic particles that are
+    /// interacting. The array is sorted by b2ParticleTriad's indexA,
+    /// then indexB, then indexC. There are no duplicate entries.
+    pub fn get_triads<'a>(&self, world: &'a World) -> &'a [ParticleTriad] {
+        self.assert_world(world);
+        unsafe {
+            buffer(b2ParticleSystem_GetTriads(self.ptr), b2ParticleSystem_GetTriadCount(self.ptr))
+        }
+    }
+
+    /// Set a user supplied buffer for the particle flags.
+    /// Unlike the C++ API the caller doesn't keep the buffer: LiquidFun reads
+    /// and writes it on every step, so it's handed over to the world owning
+    /// the particle system, which keeps it until it's replaced or the world
+    /// is dropped. The data of the existing particles is copied into it and
+    /// it is filled up to its capacity, which must be at least the max_count
+    /// of the particle system. Read and write it in place with the
+    /// get_*_buffer functions.
+    /// @panics if the world doesn't own the particle system, or if max_count
+    /// is 0 or exceeds the capacity of the buffer.
+    pub fn set_flags_buffer(&self, world: &mut World, mut buffer: Vec<UInt32>) {
+        self.assert_world(world);
+        let capacity = self.prepare_buffer(&mut buffer, self.get_flags_buffer(world));
+        unsafe {
+            b2ParticleSystem_SetFlagsBuffer(self.ptr, buffer.as_mut_ptr(), capacity);
+        }
+        world.particle_data(self).flags = buffer;
+    }
+
+    /// Set a user supplied buffer for the particle positions.
+    /// See set_flags_buffer.
+    pub fn set_position_buffer(&self, world: &mut World, mut buffer: Vec<Vec2>) {
+        self.assert_world(world);
+        let capacity = self.prepare_buffer(&mut buffer, self.get_position_buffer(world));
+        unsafe {
+            b2ParticleSystem_SetPositionBuffer(self.ptr, buffer.as_mut_ptr(), capacity);
+        }
+        world.particle_data(self).position = buffer;
+    }
+
+    /// Set a user supplied buffer for the particle velocities.
+    /// See set_flags_buffer.
+    pub fn set_velocity_buffer(&self, world: &mut World, mut buffer: Vec<Vec2>) {
+        self.assert_world(world);
+        let capacity = self.prepare_buffer(&mut buffer, self.get_velocity_buffer(world));
+        unsafe {
+            b2ParticleSystem_SetVelocityBuffer(self.ptr, buffer.as_mut_ptr(), capacity);
+        }
+        world.particle_data(self).velocity = buffer;
+    }
+
+    /// Set a user supplied buffer for the particle colors.
+    /// See set_flags_buffer.
+    pub fn set_color_buffer(&self, world: &mut World, mut buffer: Vec<ParticleColor>) {
+        self.assert_world(world);
+        let capacity = self.prepare_buffer(&mut buffer, self.get_color_buffer(world));
+        unsafe {
+            b2ParticleSystem_SetColorBuffer(self.ptr, buffer.as_mut_ptr(), capacity);
+        }
+        world.particle_data(self).color = buffer;
+    }
+
+    /// Set a user supplied buffer for the particle user data.
+    /// See set_flags_buffer.
+    pub fn set_user_data_buffer(&self, world: &mut World, mut buffer: Vec<usize>) {
+        self.assert_world(world);
+        let capacity = self.prepare_buffer(&mut buffer, self.get_user_data_buffer(world));
+        unsafe {
+            b2ParticleSystem_SetUserDataBuffer(self.ptr, buffer.as_mut_ptr(), capacity);
+        }
+        world.particle_data(self).user_data = buffer;
+    }
+
+    /// Check that the world owns this particle system, the borrow of the
+    /// world guards the particle data.
+    pub(crate) fn assert_world(&self, world: &World) {
+        assert!(world.owns(self), "the world doesn't own the particle system");
+    }
+
+    /// LiquidFun doesn't check particle indices, so check them before
+    /// calling into it.
+    fn assert_index(&self, index: Int32) {
+        assert!(index >= 0 && index < self.get_particle_count(),
+                "particle index {} is out of range", index);
+    }
+
+    fn assert_range(&self, first_index: Int32, last_index: Int32) {
+        assert!(first_index >= 0 && first_index <= last_index && last_index <= self.get_particle_count(),
+                "particle range {}..{} is out of range", first_index, last_index);
+    }
+
+    /// Copy the existing particles into a user supplied buffer and fill it up
+    /// to its capacity. LiquidFun never grows user supplied buffers, so they
+    /// must be able to hold max_count particles.
+    fn prepare_buffer<T: Copy + Default>(&self, buffer: &mut Vec<T>, current: &[T]) -> Int32 {
+        let max_count = self.get_max_particle_count();
+        assert!(max_count > 0 && buffer.capacity() >= max_count as usize,
+                "particle buffer capacity {} is less than max_count {}", buffer.capacity(), max_count);
+        buffer.clear();
+        buffer.extend_from_slice(current);
+        let capacity = buffer.capacity();
+        buffer.resize(capacity, T::default());
+        capacity as Int32
+    }
+
+}
+
+/// The data the world keeps for each of its particle systems: the buffers
+/// supplied with the ParticleSystem::set_*_buffer functions, which LiquidFun
+/// neither frees nor grows, and the handles given out by
+/// ParticleSystem::get_particle_handle_from_index.
+#[derive(Default)]
+pub(crate) struct ParticleSystemData {
+    pub flags: Vec<UInt32>,
+    pub position: Vec<Vec2>,
+    pub velocity: Vec<Vec2>,
+    pub color: Vec<ParticleColor>,
+    pub user_data: Vec<usize>,
+    pub handles: HashMap<usize, Rc<Cell<bool>>>,
+}
+
+/// LiquidFun buffers are not allocated before the first particle is created.
+unsafe fn buffer<'a, T>(ptr: *const T, count: Int32) -> &'a [T] {
+    if ptr.is_null() {
+        &[]
+    } else {
+        slice::from_raw_parts(ptr, count as usize)
+    }
+}
+
+unsafe fn buffer_mut<'a, T>(ptr: *mut T, count: Int32) -> &'a mut [T] {
+    if ptr.is_null() {
+        &mut []
+    } else {
+        slice::from_raw_parts_mut(ptr, count as usize)
+    }
 }
\ No newline at end of file
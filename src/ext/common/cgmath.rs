@@ -0,0 +1,89 @@
+use cgmath;
+use cgmath::Rotation2;
+use super::super::super::box2d::common::math::*;
+
+impl From<Vec2> for cgmath::Vector2<f32> {
+	fn from(v: Vec2) -> cgmath::Vector2<f32> {
+		cgmath::Vector2::new(v.x, v.y)
+	}
+}
+
+impl From<cgmath::Vector2<f32>> for Vec2 {
+	fn from(v: cgmath::Vector2<f32>) -> Vec2 {
+		Vec2::new(v.x, v.y)
+	}
+}
+
+impl From<Vec2> for cgmath::Point2<f32> {
+	fn from(v: Vec2) -> cgmath::Point2<f32> {
+		cgmath::Point2::new(v.x, v.y)
+	}
+}
+
+impl From<cgmath::Point2<f32>> for Vec2 {
+	fn from(p: cgmath::Point2<f32>) -> Vec2 {
+		Vec2::new(p.x, p.y)
+	}
+}
+
+impl From<Vec3> for cgmath::Vector3<f32> {
+	fn from(v: Vec3) -> cgmath::Vector3<f32> {
+		cgmath::Vector3::new(v.x, v.y, v.z)
+	}
+}
+
+impl From<cgmath::Vector3<f32>> for Vec3 {
+	fn from(v: cgmath::Vector3<f32>) -> Vec3 {
+		Vec3::new(v.x, v.y, v.z)
+	}
+}
+
+impl From<Mat22> for cgmath::Matrix2<f32> {
+	fn from(m: Mat22) -> cgmath::Matrix2<f32> {
+		cgmath::Matrix2::from_cols(m.ex.into(), m.ey.into())
+	}
+}
+
+impl From<cgmath::Matrix2<f32>> for Mat22 {
+	fn from(m: cgmath::Matrix2<f32>) -> Mat22 {
+		Mat22::new(m.x.into(), m.y.into())
+	}
+}
+
+impl From<Mat33> for cgmath::Matrix3<f32> {
+	fn from(m: Mat33) -> cgmath::Matrix3<f32> {
+		cgmath::Matrix3::from_cols(m.ex.into(), m.ey.into(), m.ez.into())
+	}
+}
+
+impl From<cgmath::Matrix3<f32>> for Mat33 {
+	fn from(m: cgmath::Matrix3<f32>) -> Mat33 {
+		Mat33::new(m.x.into(), m.y.into(), m.z.into())
+	}
+}
+
+impl From<Rot> for cgmath::Basis2<f32> {
+	fn from(q: Rot) -> cgmath::Basis2<f32> {
+		cgmath::Basis2::from_angle(cgmath::Rad(q.get_angle()))
+	}
+}
+
+impl From<cgmath::Basis2<f32>> for Rot {
+	fn from(q: cgmath::Basis2<f32>) -> Rot {
+		let m: &cgmath::Matrix2<f32> = q.as_ref();
+		Rot { s: m.x.y, c: m.x.x }
+	}
+}
+
+impl From<Transform> for cgmath::Decomposed<cgmath::Vector2<f32>, cgmath::Basis2<f32>> {
+	fn from(xf: Transform) -> cgmath::Decomposed<cgmath::Vector2<f32>, cgmath::Basis2<f32>> {
+		cgmath::Decomposed { scale: 1.0, rot: xf.q.into(), disp: xf.p.into() }
+	}
+}
+
+/// The decomposed transform must have unit scale.
+impl From<cgmath::Decomposed<cgmath::Vector2<f32>, cgmath::Basis2<f32>>> for Transform {
+	fn from(d: cgmath::Decomposed<cgmath::Vector2<f32>, cgmath::Basis2<f32>>) -> Transform {
+		Transform::new(d.disp.into(), d.rot.into())
+	}
+}
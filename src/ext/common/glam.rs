@@ -0,0 +1,78 @@
+use glam;
+use super::super::super::box2d::common::math::*;
+
+impl From<Vec2> for glam::Vec2 {
+	fn from(v: Vec2) -> glam::Vec2 {
+		glam::Vec2::new(v.x, v.y)
+	}
+}
+
+impl From<glam::Vec2> for Vec2 {
+	fn from(v: glam::Vec2) -> Vec2 {
+		Vec2::new(v.x, v.y)
+	}
+}
+
+impl From<Vec3> for glam::Vec3 {
+	fn from(v: Vec3) -> glam::Vec3 {
+		glam::Vec3::new(v.x, v.y, v.z)
+	}
+}
+
+impl From<glam::Vec3> for Vec3 {
+	fn from(v: glam::Vec3) -> Vec3 {
+		Vec3::new(v.x, v.y, v.z)
+	}
+}
+
+impl From<Mat22> for glam::Mat2 {
+	fn from(m: Mat22) -> glam::Mat2 {
+		glam::Mat2::from_cols(m.ex.into(), m.ey.into())
+	}
+}
+
+impl From<glam::Mat2> for Mat22 {
+	fn from(m: glam::Mat2) -> Mat22 {
+		Mat22::new(m.col(0).into(), m.col(1).into())
+	}
+}
+
+impl From<Mat33> for glam::Mat3 {
+	fn from(m: Mat33) -> glam::Mat3 {
+		glam::Mat3::from_cols(m.ex.into(), m.ey.into(), m.ez.into())
+	}
+}
+
+impl From<glam::Mat3> for Mat33 {
+	fn from(m: glam::Mat3) -> Mat33 {
+		Mat33::new(m.col(0).into(), m.col(1).into(), m.col(2).into())
+	}
+}
+
+/// The rotation matrix of a `Rot`.
+impl From<Rot> for glam::Mat2 {
+	fn from(q: Rot) -> glam::Mat2 {
+		glam::Mat2::from_cols(q.get_x_axis().into(), q.get_y_axis().into())
+	}
+}
+
+/// Takes the rotation from the first column, which must be a unit vector.
+impl From<glam::Mat2> for Rot {
+	fn from(m: glam::Mat2) -> Rot {
+		let x_axis = m.col(0);
+		Rot { s: x_axis.y, c: x_axis.x }
+	}
+}
+
+impl From<Transform> for glam::Affine2 {
+	fn from(xf: Transform) -> glam::Affine2 {
+		glam::Affine2 { matrix2: xf.q.into(), translation: xf.p.into() }
+	}
+}
+
+/// The affine transform must not contain scale or shear.
+impl From<glam::Affine2> for Transform {
+	fn from(a: glam::Affine2) -> Transform {
+		Transform::new(a.translation.into(), a.matrix2.into())
+	}
+}
@@ -0,0 +1,95 @@
+use mint;
+use super::super::super::box2d::common::math::*;
+
+impl From<Vec2> for mint::Vector2<f32> {
+	fn from(v: Vec2) -> mint::Vector2<f32> {
+		mint::Vector2 { x: v.x, y: v.y }
+	}
+}
+
+impl From<mint::Vector2<f32>> for Vec2 {
+	fn from(v: mint::Vector2<f32>) -> Vec2 {
+		Vec2::new(v.x, v.y)
+	}
+}
+
+impl From<Vec2> for mint::Point2<f32> {
+	fn from(v: Vec2) -> mint::Point2<f32> {
+		mint::Point2 { x: v.x, y: v.y }
+	}
+}
+
+impl From<mint::Point2<f32>> for Vec2 {
+	fn from(p: mint::Point2<f32>) -> Vec2 {
+		Vec2::new(p.x, p.y)
+	}
+}
+
+impl From<Vec3> for mint::Vector3<f32> {
+	fn from(v: Vec3) -> mint::Vector3<f32> {
+		mint::Vector3 { x: v.x, y: v.y, z: v.z }
+	}
+}
+
+impl From<mint::Vector3<f32>> for Vec3 {
+	fn from(v: mint::Vector3<f32>) -> Vec3 {
+		Vec3::new(v.x, v.y, v.z)
+	}
+}
+
+impl From<Mat22> for mint::ColumnMatrix2<f32> {
+	fn from(m: Mat22) -> mint::ColumnMatrix2<f32> {
+		mint::ColumnMatrix2 { x: m.ex.into(), y: m.ey.into() }
+	}
+}
+
+impl From<mint::ColumnMatrix2<f32>> for Mat22 {
+	fn from(m: mint::ColumnMatrix2<f32>) -> Mat22 {
+		Mat22::new(m.x.into(), m.y.into())
+	}
+}
+
+impl From<Mat33> for mint::ColumnMatrix3<f32> {
+	fn from(m: Mat33) -> mint::ColumnMatrix3<f32> {
+		mint::ColumnMatrix3 { x: m.ex.into(), y: m.ey.into(), z: m.ez.into() }
+	}
+}
+
+impl From<mint::ColumnMatrix3<f32>> for Mat33 {
+	fn from(m: mint::ColumnMatrix3<f32>) -> Mat33 {
+		Mat33::new(m.x.into(), m.y.into(), m.z.into())
+	}
+}
+
+/// The rotation matrix of a `Rot`.
+impl From<Rot> for mint::ColumnMatrix2<f32> {
+	fn from(q: Rot) -> mint::ColumnMatrix2<f32> {
+		mint::ColumnMatrix2 { x: q.get_x_axis().into(), y: q.get_y_axis().into() }
+	}
+}
+
+/// Takes the rotation from the first column, which must be a unit vector.
+impl From<mint::ColumnMatrix2<f32>> for Rot {
+	fn from(m: mint::ColumnMatrix2<f32>) -> Rot {
+		Rot { s: m.x.y, c: m.x.x }
+	}
+}
+
+/// The homogeneous 2D affine matrix of a `Transform`.
+impl From<Transform> for mint::ColumnMatrix3<f32> {
+	fn from(xf: Transform) -> mint::ColumnMatrix3<f32> {
+		mint::ColumnMatrix3 {
+			x: mint::Vector3 { x: xf.q.c, y: xf.q.s, z: 0.0 },
+			y: mint::Vector3 { x: -xf.q.s, y: xf.q.c, z: 0.0 },
+			z: mint::Vector3 { x: xf.p.x, y: xf.p.y, z: 1.0 },
+		}
+	}
+}
+
+/// Takes the rotation from the first column, which must be a unit vector,
+/// and the translation from the third column.
+impl From<mint::ColumnMatrix3<f32>> for Transform {
+	fn from(m: mint::ColumnMatrix3<f32>) -> Transform {
+		Transform::new(Vec2::new(m.z.x, m.z.y), Rot { s: m.x.y, c: m.x.x })
+	}
+}
@@ -0,0 +1,11 @@
+//! Conversions between LiquidFun math types and other math crates.
+//! Each conversion module is enabled by the cargo feature of the same name.
+
+#[cfg(feature = "cgmath")]
+mod cgmath;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
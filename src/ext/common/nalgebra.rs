@@ -0,0 +1,94 @@
+use nalgebra;
+use super::super::super::box2d::common::math::*;
+
+impl From<Vec2> for nalgebra::Vector2<f32> {
+	fn from(v: Vec2) -> nalgebra::Vector2<f32> {
+		nalgebra::Vector2::new(v.x, v.y)
+	}
+}
+
+impl From<nalgebra::Vector2<f32>> for Vec2 {
+	fn from(v: nalgebra::Vector2<f32>) -> Vec2 {
+		Vec2::new(v.x, v.y)
+	}
+}
+
+impl From<Vec2> for nalgebra::Point2<f32> {
+	fn from(v: Vec2) -> nalgebra::Point2<f32> {
+		nalgebra::Point2::new(v.x, v.y)
+	}
+}
+
+impl From<nalgebra::Point2<f32>> for Vec2 {
+	fn from(p: nalgebra::Point2<f32>) -> Vec2 {
+		Vec2::new(p.x, p.y)
+	}
+}
+
+impl From<Vec3> for nalgebra::Vector3<f32> {
+	fn from(v: Vec3) -> nalgebra::Vector3<f32> {
+		nalgebra::Vector3::new(v.x, v.y, v.z)
+	}
+}
+
+impl From<nalgebra::Vector3<f32>> for Vec3 {
+	fn from(v: nalgebra::Vector3<f32>) -> Vec3 {
+		Vec3::new(v.x, v.y, v.z)
+	}
+}
+
+impl From<Mat22> for nalgebra::Matrix2<f32> {
+	fn from(m: Mat22) -> nalgebra::Matrix2<f32> {
+		nalgebra::Matrix2::new(
+			m.ex.x, m.ey.x,
+			m.ex.y, m.ey.y)
+	}
+}
+
+impl From<nalgebra::Matrix2<f32>> for Mat22 {
+	fn from(m: nalgebra::Matrix2<f32>) -> Mat22 {
+		Mat22::from_scalars(m[(0, 0)], m[(0, 1)], m[(1, 0)], m[(1, 1)])
+	}
+}
+
+impl From<Mat33> for nalgebra::Matrix3<f32> {
+	fn from(m: Mat33) -> nalgebra::Matrix3<f32> {
+		nalgebra::Matrix3::new(
+			m.ex.x, m.ey.x, m.ez.x,
+			m.ex.y, m.ey.y, m.ez.y,
+			m.ex.z, m.ey.z, m.ez.z)
+	}
+}
+
+impl From<nalgebra::Matrix3<f32>> for Mat33 {
+	fn from(m: nalgebra::Matrix3<f32>) -> Mat33 {
+		Mat33::new(
+			Vec3::new(m[(0, 0)], m[(1, 0)], m[(2, 0)]),
+			Vec3::new(m[(0, 1)], m[(1, 1)], m[(2, 1)]),
+			Vec3::new(m[(0, 2)], m[(1, 2)], m[(2, 2)]))
+	}
+}
+
+impl From<Rot> for nalgebra::UnitComplex<f32> {
+	fn from(q: Rot) -> nalgebra::UnitComplex<f32> {
+		nalgebra::UnitComplex::from_cos_sin_unchecked(q.c, q.s)
+	}
+}
+
+impl From<nalgebra::UnitComplex<f32>> for Rot {
+	fn from(q: nalgebra::UnitComplex<f32>) -> Rot {
+		Rot { s: q.sin_angle(), c: q.cos_angle() }
+	}
+}
+
+impl From<Transform> for nalgebra::Isometry2<f32> {
+	fn from(xf: Transform) -> nalgebra::Isometry2<f32> {
+		nalgebra::Isometry2::from_parts(nalgebra::Translation2::new(xf.p.x, xf.p.y), xf.q.into())
+	}
+}
+
+impl From<nalgebra::Isometry2<f32>> for Transform {
+	fn from(iso: nalgebra::Isometry2<f32>) -> Transform {
+		Transform::new(iso.translation.vector.into(), iso.rotation.into())
+	}
+}
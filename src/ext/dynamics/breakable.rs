@@ -0,0 +1,142 @@
+use std::collections::HashSet;
+use super::super::super::box2d::common::math::*;
+use super::super::super::box2d::common::settings::*;
+use super::super::super::box2d::dynamics::body::*;
+use super::super::super::box2d::dynamics::joints::*;
+use super::super::super::box2d::dynamics::world::*;
+
+struct Breakable {
+	joint: Joint,
+	max_force: f32,
+	max_torque: f32,
+}
+
+/// A joint that exceeded its threshold and was destroyed, or a gear joint
+/// destroyed along with a joint it was attached to. The joint itself no
+/// longer exists, so only a copy of its last state is reported.
+#[derive(Clone, Debug)]
+pub struct BrokenJoint {
+	pub joint_type: JointType,
+	pub user_data: usize,
+	pub body_a: Body,
+	pub body_b: Body,
+
+	/// The reaction force on bodyB at the joint anchor in Newtons.
+	pub reaction_force: Vec2,
+
+	/// The reaction torque on bodyB in N*m.
+	pub reaction_torque: f32,
+}
+
+/// A set of joints that snap under stress. Step the world through `step`
+/// instead of `World::step`: afterwards the reaction forces of the registered
+/// joints are evaluated with the inverse time step, and joints exceeding
+/// their maximum force or torque are destroyed and reported.
+///
+/// Registrations are per joint pointer. LiquidFun reuses the memory of
+/// destroyed joints, so unregister a joint before destroying it elsewhere,
+/// or a joint created in its place before the next step inherits the
+/// registration.
+#[derive(Default)]
+pub struct BreakableJoints {
+	joints: Vec<Breakable>,
+}
+
+impl BreakableJoints {
+
+	pub fn new() -> BreakableJoints {
+		BreakableJoints { joints: Vec::new() }
+	}
+
+	/// Register a joint that breaks when its reaction force exceeds max_force
+	/// or its reaction torque exceeds max_torque. Use MAX_FLOAT to ignore
+	/// either threshold. Registering a joint again replaces its thresholds.
+	pub fn register<J: Into<Joint>>(&mut self, joint: J, max_force: f32, max_torque: f32) {
+		let joint = joint.into();
+		self.unregister(&joint);
+		self.joints.push(Breakable { joint: joint, max_force: max_force, max_torque: max_torque });
+	}
+
+	/// Register a joint that only breaks on force.
+	pub fn register_force<J: Into<Joint>>(&mut self, joint: J, max_force: f32) {
+		self.register(joint, max_force, MAX_FLOAT);
+	}
+
+	/// Stop tracking a joint.
+	/// @return true if the joint was registered.
+	pub fn unregister(&mut self, joint: &Joint) -> bool {
+		let count = self.joints.len();
+		self.joints.retain(|b| b.joint.ptr != joint.ptr);
+		self.joints.len() != count
+	}
+
+	/// Get the number of registered joints.
+	pub fn len(&self) -> usize {
+		self.joints.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.joints.is_empty()
+	}
+
+	/// Take a time step and break overloaded joints.
+	/// Gear joints attached to a breaking joint are destroyed first and
+	/// reported as broken too, whether they are registered or not.
+	/// Joints destroyed by other means since the last step are forgotten.
+	/// @return the joints that broke during this step.
+	pub fn step(&mut self, world: &mut World, time_step: f32, velocity_iterations: i32, position_iterations: i32) -> Vec<BrokenJoint> {
+		world.step(time_step, velocity_iterations, position_iterations);
+
+		let live: HashSet<_> = world.joints().map(|j| j.ptr).collect();
+		self.joints.retain(|b| live.contains(&b.joint.ptr));
+
+		if time_step <= 0.0 {
+			return Vec::new();
+		}
+		let inv_dt = 1.0 / time_step;
+
+		let mut overloaded = Vec::new();
+		self.joints.retain(|b| {
+			let overload = b.joint.get_reaction_force(inv_dt).length() > b.max_force ||
+				b.joint.get_reaction_torque(inv_dt).abs() > b.max_torque;
+			if overload {
+				overloaded.push(b.joint.clone());
+			}
+			!overload
+		});
+
+		let mut broken = Vec::new();
+		let mut destroyed = HashSet::new();
+		for joint in overloaded {
+			if destroyed.contains(&joint.ptr) {
+				continue;
+			}
+
+			// A gear joint must not outlive the joints it is attached to.
+			let gears: Vec<Joint> = world.joints().filter(|j| match j.as_gear_joint() {
+				Some(gear) => gear.get_joint1().ptr == joint.ptr || gear.get_joint2().ptr == joint.ptr,
+				None => false,
+			}).collect();
+			for gear in gears.into_iter().chain(Some(joint)) {
+				self.unregister(&gear);
+				destroyed.insert(gear.ptr);
+				broken.push(BrokenJoint::from(&gear, inv_dt));
+				world.destroy_joint(gear);
+			}
+		}
+		broken
+	}
+}
+
+impl BrokenJoint {
+	fn from(joint: &Joint, inv_dt: f32) -> BrokenJoint {
+		BrokenJoint {
+			joint_type: joint.get_type(),
+			user_data: joint.get_user_data(),
+			body_a: joint.get_body_a(),
+			body_b: joint.get_body_b(),
+			reaction_force: joint.get_reaction_force(inv_dt),
+			reaction_torque: joint.get_reaction_torque(inv_dt),
+		}
+	}
+}
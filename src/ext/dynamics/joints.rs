@@ -0,0 +1,59 @@
+use super::super::super::box2d::dynamics::body::*;
+use super::super::super::box2d::dynamics::joints::*;
+use super::super::super::box2d::dynamics::world::*;
+
+pub struct JointIterator {
+	joint: Option<Joint>,
+	started: bool
+}
+
+impl World {
+	/// Get a world joint iterator.
+	pub fn joints(&self) -> JointIterator {
+		JointIterator { joint: self.get_joint_list(), started: false }
+	}
+}
+
+impl Iterator for JointIterator {
+	type Item = Joint;
+	fn next(&mut self) -> Option<Joint> {
+		if  !self.started {
+			self.started = true;
+		} else {
+			self.joint = match self.joint {
+				Some(ref x) => x.get_next(),
+				None => None
+			}
+		}
+
+		self.joint.clone()
+	}
+}
+
+pub struct JointEdgeIterator {
+	edge: Option<JointEdge>,
+	started: bool
+}
+
+impl Body {
+	/// Get an iterator over the edges of the joints attached to this body.
+	pub fn joint_edges(&self) -> JointEdgeIterator {
+		JointEdgeIterator { edge: self.get_joint_list(), started: false }
+	}
+}
+
+impl Iterator for JointEdgeIterator {
+	type Item = JointEdge;
+	fn next(&mut self) -> Option<JointEdge> {
+		if  !self.started {
+			self.started = true;
+		} else {
+			self.edge = match self.edge {
+				Some(ref x) => x.get_next(),
+				None => None
+			}
+		}
+
+		self.edge.clone()
+	}
+}
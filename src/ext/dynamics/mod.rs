@@ -1,3 +1,6 @@
 pub mod body;
+pub mod breakable;
 pub mod fixture;
+pub mod joints;
+pub mod vehicle;
 pub mod world;
\ No newline at end of file
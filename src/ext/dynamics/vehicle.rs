@@ -0,0 +1,157 @@
+use super::super::super::box2d::collision::shapes::shape::ShapeDef;
+use super::super::super::box2d::common::math::*;
+use super::super::super::box2d::dynamics::body::*;
+use super::super::super::box2d::dynamics::fixture::FixtureDef;
+use super::super::super::box2d::dynamics::joints::wheel_joint::*;
+use super::super::super::box2d::dynamics::world::*;
+
+/// The wheels driven by the vehicle motor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Drive {
+	Front,
+	Rear,
+	All
+}
+
+/// A compact description of a car-style vehicle: a box chassis with a rear
+/// and a front wheel, each attached by a wheel joint acting as suspension.
+#[derive(Debug, Clone)]
+pub struct VehicleDef {
+	/// The world position of the chassis center.
+	pub position: Vec2,
+
+	/// The half-width of the chassis box.
+	pub chassis_half_width: f32,
+
+	/// The half-height of the chassis box. The wheel centers sit at the bottom
+	/// edge of the chassis.
+	pub chassis_half_height: f32,
+
+	/// The density of the chassis, usually in kg/m^2.
+	pub chassis_density: f32,
+
+	/// The distance between the rear and front wheel centers.
+	pub wheel_base: f32,
+
+	/// The wheel radius.
+	pub wheel_radius: f32,
+
+	/// The density of the wheels, usually in kg/m^2.
+	pub wheel_density: f32,
+
+	/// The friction coefficient of the wheels.
+	pub wheel_friction: f32,
+
+	/// Suspension frequency in Hertz, zero disables the spring.
+	pub suspension_frequency_hz: f32,
+
+	/// Suspension damping ratio, one indicates critical damping.
+	pub suspension_damping_ratio: f32,
+
+	/// The maximum torque of each driven wheel motor, usually in N-m.
+	pub max_motor_torque: f32,
+
+	/// The wheels driven by the motor.
+	pub drive: Drive,
+}
+
+impl Default for VehicleDef {
+	fn default() -> VehicleDef {
+		VehicleDef {
+			position: Vec2::zero(),
+			chassis_half_width: 1.5,
+			chassis_half_height: 0.25,
+			chassis_density: 1.0,
+			wheel_base: 2.0,
+			wheel_radius: 0.4,
+			wheel_density: 1.0,
+			wheel_friction: 0.9,
+			suspension_frequency_hz: 4.0,
+			suspension_damping_ratio: 0.7,
+			max_motor_torque: 20.0,
+			drive: Drive::Rear,
+		}
+	}
+}
+
+/// The bodies and joints making up a vehicle created from a VehicleDef.
+/// The wheels and suspensions are ordered rear first, then front.
+#[derive(Debug, Clone)]
+pub struct Vehicle {
+	pub chassis: Body,
+	pub wheels: Vec<Body>,
+	pub suspensions: Vec<WheelJoint>,
+}
+
+impl Vehicle {
+	/// Set the motor speed of the driven wheels in radians per second.
+	/// Negative speeds drive the vehicle towards positive x.
+	pub fn set_motor_speed(&self, speed: f32) {
+		for suspension in self.suspensions.iter().filter(|s| s.is_motor_enabled()) {
+			suspension.set_motor_speed(speed);
+		}
+	}
+
+	/// Set the maximum motor torque of the driven wheels, usually in N-m.
+	pub fn set_max_motor_torque(&self, torque: f32) {
+		for suspension in self.suspensions.iter().filter(|s| s.is_motor_enabled()) {
+			suspension.set_max_motor_torque(torque);
+		}
+	}
+
+	/// Set the spring frequency and damping ratio of all suspensions.
+	pub fn set_suspension(&self, frequency_hz: f32, damping_ratio: f32) {
+		for suspension in &self.suspensions {
+			suspension.set_spring_frequency_hz(frequency_hz);
+			suspension.set_spring_damping_ratio(damping_ratio);
+		}
+	}
+}
+
+impl World {
+	/// Create a vehicle from a compact description. The chassis, the wheels
+	/// and the wheel joints are all regular world objects afterwards.
+	pub fn create_vehicle(&mut self, def: &VehicleDef) -> Vehicle {
+		let chassis = self.create_body(&BodyDef {
+			body_type: BodyType::DynamicBody,
+			position: def.position,
+			..BodyDef::default()
+		});
+		let mut fd = FixtureDef::new(ShapeDef::Box {
+			hx: def.chassis_half_width,
+			hy: def.chassis_half_height,
+			center: Vec2::zero(),
+			angle: 0.0
+		});
+		fd.density = def.chassis_density;
+		chassis.create_fixture(&fd);
+
+		let mut wheels = Vec::new();
+		let mut suspensions = Vec::new();
+		let axles = [(-0.5 * def.wheel_base, def.drive != Drive::Front),
+		             (0.5 * def.wheel_base, def.drive != Drive::Rear)];
+		for &(offset, driven) in axles.iter() {
+			let center = def.position + Vec2::new(offset, -def.chassis_half_height);
+			let wheel = self.create_body(&BodyDef {
+				body_type: BodyType::DynamicBody,
+				position: center,
+				..BodyDef::default()
+			});
+			let mut fd = FixtureDef::new(ShapeDef::Circle { radius: def.wheel_radius, position: Vec2::zero() });
+			fd.density = def.wheel_density;
+			fd.friction = def.wheel_friction;
+			wheel.create_fixture(&fd);
+
+			let mut jd = WheelJointDef::new();
+			jd.initialize(chassis.clone(), wheel.clone(), &center, &Vec2::new(0.0, 1.0));
+			jd.enable_motor = driven;
+			jd.max_motor_torque = if driven { def.max_motor_torque } else { 0.0 };
+			jd.frequency_hz = def.suspension_frequency_hz;
+			jd.damping_ratio = def.suspension_damping_ratio;
+			suspensions.push(self.create_joint(&jd).as_wheel_joint().unwrap());
+			wheels.push(wheel);
+		}
+
+		Vehicle { chassis, wheels, suspensions }
+	}
+}
@@ -1,4 +1,6 @@
 //! Extra idomatic Rust features.
 
 pub mod collision;
+pub mod common;
 pub mod dynamics;
+pub mod particle;
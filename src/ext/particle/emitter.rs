@@ -0,0 +1,134 @@
+use std::f32::consts::PI;
+use super::super::super::box2d::common::math::*;
+use super::super::super::box2d::common::settings::*;
+use super::super::super::box2d::dynamics::world::World;
+use super::super::super::box2d::particle::*;
+use super::super::super::box2d::particle::particle_color::*;
+use super::super::super::box2d::particle::particle_group::*;
+use super::super::super::box2d::particle::particle_system::*;
+
+/// Emit particles from a circular region, like the RadialEmitter of the
+/// LiquidFun testbed. Particles are placed at random inside an ellipse of
+/// the given size around the origin, moving along direction plus speed away
+/// from the origin.
+pub struct Emitter {
+	/// The particle system the particles are created in.
+	pub particle_system: ParticleSystem,
+
+	/// The center of the emitter.
+	pub origin: Vec2,
+
+	/// The width and height of the emitter.
+	pub size: Vec2,
+
+	/// The starting velocity of emitted particles.
+	pub direction: Vec2,
+
+	/// The speed of particles along the direction from the emitter center to
+	/// the particle's starting position.
+	pub speed: f32,
+
+	/// The number of particles emitted per second. Fractional particles are
+	/// carried over to the next emit.
+	pub emit_rate: f32,
+
+	/// The color of emitted particles.
+	pub color: ParticleColor,
+
+	/// The flags of emitted particles.
+	pub flags: ParticleFlags,
+
+	/// The lifetime of emitted particles in seconds. A value <= 0.0 indicates
+	/// particles with infinite lifetime.
+	pub lifetime: f32,
+
+	/// The group emitted particles are added to.
+	pub group: Option<ParticleGroup>,
+
+	emit_remainder: f32,
+	seed: UInt32,
+}
+
+impl Emitter {
+
+	/// Create an emitter of one water particle per second at the origin.
+	pub fn new(particle_system: &ParticleSystem) -> Emitter {
+		Emitter {
+			particle_system: particle_system.clone(),
+			origin: Vec2::zero(),
+			size: Vec2::zero(),
+			direction: Vec2::zero(),
+			speed: 0.0,
+			emit_rate: 1.0,
+			color: ParticleColor::zero(),
+			flags: WATER_PARTICLE,
+			lifetime: 0.0,
+			group: None,
+			emit_remainder: 0.0,
+			seed: 1,
+		}
+	}
+
+	/// Seed the generator of the particle positions, emitters with the same
+	/// seed and settings emit the same particles.
+	pub fn set_seed(&mut self, seed: UInt32) {
+		self.seed = if seed == 0 { 1 } else { seed };
+	}
+
+	/// Emit the particles due in the elapsed time.
+	/// Particles that can't be created because the particle system is full
+	/// are dropped.
+	/// @param world the world owning the particle system.
+	/// @param dt the elapsed time in seconds. Nothing is emitted if the
+	/// emit rate or dt is negative or not finite.
+	/// @return the indices of the created particles. Like all particle
+	/// indices they are only valid until the next World::step, use
+	/// ParticleSystem::get_particle_handle_from_index to follow a particle.
+	pub fn emit(&mut self, world: &mut World, dt: f32) -> Vec<Int32> {
+		// A negative or non-finite amount would never emit again or emit
+		// forever, so it's ignored.
+		let due = self.emit_rate * dt;
+		if due.is_finite() && due > 0.0 {
+			self.emit_remainder += due;
+		}
+
+		let mut pd = ParticleDef {
+			color: self.color,
+			flags: self.flags,
+			lifetime: self.lifetime,
+			group: self.group.clone(),
+			..ParticleDef::default()
+		};
+
+		let mut indices = Vec::new();
+		while self.emit_remainder >= 1.0 {
+			self.emit_remainder -= 1.0;
+
+			// Randomly pick a position within the emitter's radius.
+			let angle = self.random() * 2.0 * PI;
+			let distance = self.random();
+			let position_on_unit_circle = Vec2::new(angle.sin(), angle.cos());
+			pd.position.set(
+				self.origin.x + position_on_unit_circle.x * distance * self.size.x * 0.5,
+				self.origin.y + position_on_unit_circle.y * distance * self.size.y * 0.5);
+			pd.velocity = self.direction;
+			if self.speed != 0.0 {
+				pd.velocity += position_on_unit_circle * self.speed;
+			}
+
+			let index = self.particle_system.create_particle(world, &pd);
+			if index >= 0 {
+				indices.push(index);
+			}
+		}
+		indices
+	}
+
+	/// A xorshift generator, uniform in [0, 1).
+	fn random(&mut self) -> f32 {
+		self.seed ^= self.seed << 13;
+		self.seed ^= self.seed >> 17;
+		self.seed ^= self.seed << 5;
+		(self.seed >> 8) as f32 / (1 << 24) as f32
+	}
+}
@@ -0,0 +1,2 @@
+pub mod emitter;
+pub mod particle_group;
\ No newline at end of file
@@ -0,0 +1,49 @@
+use super::super::super::box2d::dynamics::world::World;
+use super::super::super::box2d::particle::*;
+use super::super::super::box2d::particle::particle_group::*;
+use super::super::super::box2d::particle::particle_system::*;
+
+pub struct ParticleGroupIterator {
+	group: Option<ParticleGroup>,
+	started: bool
+}
+
+impl ParticleSystem {
+	/// Get a particle group iterator.
+	pub fn get_particle_group_iterator(&self) -> ParticleGroupIterator {
+		ParticleGroupIterator { group: self.get_particle_group_list(), started: false }
+	}
+
+	/// Split every particle group having any of the given group flags into its
+	/// disconnected parts. Call this after each World::step, e.g. with
+	/// SOLID_PARTICLE_GROUP | PARTICLE_GROUP_CAN_BE_EMPTY, to split groups
+	/// automatically once particles holding them together are destroyed.
+	/// Empty groups are skipped.
+	/// @return the number of groups created by splitting.
+	pub fn split_particle_groups(&self, world: &mut World, flags: ParticleGroupFlags) -> i32 {
+		let count = self.get_particle_group_count();
+		let groups: Vec<ParticleGroup> = self.get_particle_group_iterator()
+			.filter(|g| g.get_group_flags().intersects(flags) && g.get_particle_count() > 0)
+			.collect();
+		for group in &groups {
+			self.split_particle_group(world, group);
+		}
+		self.get_particle_group_count() - count
+	}
+}
+
+impl Iterator for ParticleGroupIterator {
+	type Item = ParticleGroup;
+	fn next(&mut self) -> Option<ParticleGroup> {
+		if  !self.started {
+			self.started = true;
+		} else {
+			self.group = match self.group {
+				Some(ref x) => x.get_next(),
+				None => None
+			}
+		}
+
+		self.group.clone()
+	}
+}
@@ -18,6 +18,25 @@
 //! 	ground_body_def.user_data = &Vec2::new(6.0, 66.0) as *const Vec2 as usize;
 //! ```
 //!
+//! Math library interop
+//! --------------------
+//!
+//! Enable the `mint`, `glam`, `nalgebra` or `cgmath` cargo features to get `From`/`Into`
+//! conversions between `Vec2`, `Vec3`, `Rot`, `Transform`, `Mat22`, `Mat33` and the
+//! matching types of those crates.
+//!
+//! ```toml
+//! [dependencies]
+//! liquidfun = { version = "*", features = ["glam"] }
+//! ```
+//!
+//! Serialization
+//! -------------
+//!
+//! Enable the `serde` cargo feature to serialize and deserialize definitions such as
+//! `BodyDef`, `FixtureDef`, `ParticleSystemDef`, `ParticleDef` and the joint definitions.
+//! Raw pointers and runtime handles (user data, bodies, particle groups) are skipped.
+//!
 //! Passing structs by value from C++ to Rust
 //! -----------------------------------------
 //! 
@@ -55,6 +74,17 @@
 extern crate libc;
 #[macro_use]
 extern crate bitflags;
+#[cfg(feature = "cgmath")]
+extern crate cgmath;
+#[cfg(feature = "glam")]
+extern crate glam;
+#[cfg(feature = "mint")]
+extern crate mint;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
 
 pub mod box2d;
 pub mod ext;
\ No newline at end of file
@@ -0,0 +1,65 @@
+extern crate liquidfun;
+
+use liquidfun::box2d::collision::*;
+use liquidfun::box2d::collision::dynamic_tree::*;
+use liquidfun::box2d::common::math::*;
+
+fn square(x: f32, y: f32) -> AABB {
+	AABB::new(Vec2::new(x - 0.5, y - 0.5), Vec2::new(x + 0.5, y + 0.5))
+}
+
+#[test]
+fn create_and_destroy_proxies() {
+
+	let mut tree = DynamicTree::new();
+	let a = tree.create_proxy(&square(0.0, 0.0), "a");
+	let b = tree.create_proxy(&square(10.0, 0.0), "b");
+
+	assert_eq!(tree.get_proxy_count(), 2);
+	assert_eq!(tree.get_user_data(b), Some(&"b"));
+	assert!(tree.get_height() >= 1);
+	tree.validate();
+
+	assert_eq!(tree.destroy_proxy(a), Some("a"));
+	assert_eq!(tree.destroy_proxy(a), None);
+	assert_eq!(tree.get_proxy_count(), 1);
+}
+
+#[test]
+fn query_and_ray_cast() {
+
+	let mut tree = DynamicTree::new();
+	let a = tree.create_proxy(&square(0.0, 0.0), 1);
+	tree.create_proxy(&square(10.0, 0.0), 2);
+
+	let mut found = Vec::new();
+	tree.query(&square(0.2, 0.2), |proxy_id| {
+		found.push(*tree.get_user_data(proxy_id).unwrap());
+		true
+	});
+	assert_eq!(found, vec![1]);
+
+	let input = RayCastInput { p1: Vec2::new(-5.0, 0.0), p2: Vec2::new(15.0, 0.0), max_fraction: 1.0 };
+	let mut hits = 0;
+	tree.ray_cast(&input, |input, _| {
+		hits += 1;
+		input.max_fraction
+	});
+	assert_eq!(hits, 2);
+
+	assert!(tree.move_proxy(a, &square(20.0, 0.0), &Vec2::new(20.0, 0.0)));
+	let fat_aabb = tree.get_fat_aabb(a);
+	assert!(fat_aabb.lower_bound.x <= 19.5 && fat_aabb.upper_bound.x >= 20.5);
+
+	tree.rebuild_bottom_up();
+	tree.validate();
+}
+
+#[test]
+#[should_panic(expected = "stop")]
+fn query_callback_panics_are_resumed() {
+
+	let mut tree = DynamicTree::new();
+	tree.create_proxy(&square(0.0, 0.0), 1);
+	tree.query(&square(0.0, 0.0), |_| panic!("stop"));
+}
@@ -0,0 +1,87 @@
+extern crate liquidfun;
+#[cfg(feature = "cgmath")]
+extern crate cgmath;
+#[cfg(feature = "glam")]
+extern crate glam;
+#[cfg(feature = "mint")]
+extern crate mint;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+
+#[allow(unused_imports)]
+use liquidfun::box2d::common::math::*;
+
+#[allow(dead_code)]
+fn assert_transform_eq(a: Transform, b: Transform) {
+	assert!((a.p - b.p).length() < 1e-5, "{:?} != {:?}", a, b);
+	assert!((a.q.get_angle() - b.q.get_angle()).abs() < 1e-5, "{:?} != {:?}", a, b);
+}
+
+#[cfg(feature = "mint")]
+#[test]
+fn mint_conversions() {
+
+	let v: mint::Vector2<f32> = Vec2::new(1.0, 2.0).into();
+	assert_eq!(Vec2::from(v), Vec2::new(1.0, 2.0));
+
+	let xf = Transform::new(Vec2::new(1.0, 2.0), Rot::new(0.5));
+	let m: mint::ColumnMatrix3<f32> = xf.into();
+	assert_eq!(m.z.x, 1.0);
+	assert_transform_eq(Transform::from(m), xf);
+
+	let m = Mat22::from_scalars(1.0, 2.0, 3.0, 4.0);
+	assert_eq!(Mat22::from(mint::ColumnMatrix2::from(m)), m);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn glam_conversions() {
+
+	let v: glam::Vec2 = Vec2::new(1.0, 2.0).into();
+	assert_eq!(v, glam::Vec2::new(1.0, 2.0));
+	assert_eq!(Vec2::from(v), Vec2::new(1.0, 2.0));
+
+	let xf = Transform::new(Vec2::new(1.0, 2.0), Rot::new(0.5));
+	let a: glam::Affine2 = xf.into();
+	let p = a.transform_point2(glam::Vec2::new(3.0, 4.0));
+	assert!((Vec2::from(p) - xf.mul_vec2(&Vec2::new(3.0, 4.0))).length() < 1e-5);
+	assert_transform_eq(Transform::from(a), xf);
+
+	let m = Mat33::new(Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0), Vec3::new(7.0, 8.0, 9.0));
+	assert_eq!(Mat33::from(glam::Mat3::from(m)), m);
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn nalgebra_conversions() {
+
+	let p: nalgebra::Point2<f32> = Vec2::new(1.0, 2.0).into();
+	assert_eq!(Vec2::from(p), Vec2::new(1.0, 2.0));
+
+	let xf = Transform::new(Vec2::new(1.0, 2.0), Rot::new(0.5));
+	let iso: nalgebra::Isometry2<f32> = xf.into();
+	let q = iso * nalgebra::Point2::new(3.0, 4.0);
+	assert!((Vec2::from(q) - xf.mul_vec2(&Vec2::new(3.0, 4.0))).length() < 1e-5);
+	assert_transform_eq(Transform::from(iso), xf);
+
+	let m = Mat22::from_scalars(1.0, 2.0, 3.0, 4.0);
+	let n: nalgebra::Matrix2<f32> = m.into();
+	assert_eq!(n[(0, 1)], 2.0);
+	assert_eq!(Mat22::from(n), m);
+}
+
+#[cfg(feature = "cgmath")]
+#[test]
+fn cgmath_conversions() {
+
+	use cgmath::Transform as CgTransform;
+
+	let v: cgmath::Vector2<f32> = Vec2::new(1.0, 2.0).into();
+	assert_eq!(Vec2::from(v), Vec2::new(1.0, 2.0));
+
+	let xf = Transform::new(Vec2::new(1.0, 2.0), Rot::new(0.5));
+	let d: cgmath::Decomposed<cgmath::Vector2<f32>, cgmath::Basis2<f32>> = xf.into();
+	let p = d.transform_point(cgmath::Point2::new(3.0, 4.0));
+	assert!((Vec2::from(p) - xf.mul_vec2(&Vec2::new(3.0, 4.0))).length() < 1e-5);
+	assert_transform_eq(Transform::from(d), xf);
+}
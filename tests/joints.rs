@@ -1,9 +1,12 @@
 extern crate liquidfun;
 
 use liquidfun::box2d::collision::shapes::polygon_shape::*;
+use liquidfun::box2d::common::math::*;
+use liquidfun::box2d::common::settings::*;
 use liquidfun::box2d::dynamics::body::*;
 use liquidfun::box2d::dynamics::joints::*;
 use liquidfun::box2d::dynamics::world::World;
+use liquidfun::ext::dynamics::breakable::*;
 
 #[test]
 fn create_revolute_joint() {
@@ -20,15 +23,651 @@ fn create_revolute_joint() {
 	body_b.create_fixture_from_shape(&shape, 0.0);
 
 	let mut jd = revolute_joint::RevoluteJointDef::new();
-	jd.0.body_a = Some(body_a);
-	jd.0.body_b = Some(body_b);
-	jd.1.local_anchor_a.set(0.0, 0.0);
-	jd.1.local_anchor_b.set(0.0, 0.0);
-	jd.1.reference_angle = 0.0;
-	jd.1.motor_speed = 1.0;
-	jd.1.max_motor_torque = 1e7;
-	jd.1.enable_motor = true;
-	let joint = world.create_revolute_joint(&jd);
-
-	assert_eq!(world.get_joint_count(), 1);
-}
\ No newline at end of file
+	jd.body_a = Some(body_a);
+	jd.body_b = Some(body_b);
+	jd.local_anchor_a.set(0.0, 0.0);
+	jd.local_anchor_b.set(0.0, 0.0);
+	jd.reference_angle = 0.0;
+	jd.motor_speed = 1.0;
+	jd.max_motor_torque = 1e7;
+	jd.enable_motor = true;
+	let joint = world.create_joint(&jd).as_revolute_joint().unwrap();
+
+	assert_eq!(world.get_joint_count(), 1);
+	assert_eq!(joint.get_motor_speed(), 1.0);
+	assert_eq!(joint.get_max_motor_torque(), 1e7);
+}
+
+#[test]
+fn create_prismatic_joint() {
+
+	let mut world = World::default();
+
+	let bd = BodyDef::default();
+	let body_a = world.create_body(&bd);
+	let body_b = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, ..BodyDef::default() });
+
+	let mut shape = PolygonShape::new();
+	shape.set_as_box(1.0, 1.0);
+	body_a.create_fixture_from_shape(&shape, 0.0);
+	body_b.create_fixture_from_shape(&shape, 1.0);
+
+	let mut jd = prismatic_joint::PrismaticJointDef::new();
+	jd.initialize(body_a, body_b, &Vec2::new(0.0, 0.0), &Vec2::new(0.0, 1.0));
+	jd.enable_limit = true;
+	jd.lower_translation = -5.0;
+	jd.upper_translation = 2.5;
+	jd.enable_motor = true;
+	jd.max_motor_force = 1e4;
+	jd.motor_speed = 1.0;
+	let joint = world.create_joint(&jd).as_prismatic_joint().unwrap();
+
+	assert_eq!(world.get_joint_count(), 1);
+	assert_eq!(joint.get_local_axis_a(), Vec2::new(0.0, 1.0));
+	assert!(joint.is_limit_enabled());
+	assert_eq!(joint.get_lower_limit(), -5.0);
+	assert_eq!(joint.get_upper_limit(), 2.5);
+
+	joint.set_limits(-1.0, 1.0);
+	assert_eq!(joint.get_upper_limit(), 1.0);
+	joint.set_max_motor_force(500.0);
+	assert_eq!(joint.get_max_motor_force(), 500.0);
+
+	for _ in 0..60 {
+		world.step(1.0 / 60.0, 6, 2);
+	}
+
+	assert!(joint.get_joint_translation() > 0.0);
+	assert!(joint.get_joint_translation() <= 1.0 + 0.01);
+	assert!(joint.get_motor_force(60.0).abs() <= 500.0 + 0.01);
+}
+
+#[test]
+fn create_distance_joint() {
+
+	let mut world = World::default();
+
+	let ground = world.create_body(&BodyDef::default());
+	let body = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, position: Vec2::new(0.0, -4.0), ..BodyDef::default() });
+
+	let mut shape = PolygonShape::new();
+	shape.set_as_box(0.5, 0.5);
+	body.create_fixture_from_shape(&shape, 1.0);
+
+	let mut jd = distance_joint::DistanceJointDef::new();
+	jd.initialize(ground, body, &Vec2::new(0.0, 0.0), &Vec2::new(0.0, -4.0));
+	jd.frequency_hz = 4.0;
+	jd.damping_ratio = 0.5;
+	let joint = world.create_joint(&jd).as_distance_joint().unwrap();
+
+	assert_eq!(world.get_joint_count(), 1);
+	assert_eq!(joint.get_length(), 4.0);
+	assert_eq!(joint.get_local_anchor_b(), Vec2::zero());
+	assert_eq!(joint.get_frequency(), 4.0);
+	assert_eq!(joint.get_damping_ratio(), 0.5);
+
+	joint.set_length(2.0);
+	joint.set_frequency(0.0);
+	joint.set_damping_ratio(0.0);
+	assert_eq!(joint.get_length(), 2.0);
+	assert_eq!(joint.get_frequency(), 0.0);
+}
+
+#[test]
+fn create_rope_joint() {
+
+	let mut world = World::default();
+
+	let ground = world.create_body(&BodyDef::default());
+	let body = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, position: Vec2::new(0.0, -5.0), ..BodyDef::default() });
+
+	let mut shape = PolygonShape::new();
+	shape.set_as_box(0.5, 0.5);
+	body.create_fixture_from_shape(&shape, 1.0);
+
+	let mut jd = rope_joint::RopeJointDef::new();
+	jd.body_a = Some(ground);
+	jd.body_b = Some(body.clone());
+	jd.local_anchor_a = Vec2::zero();
+	jd.local_anchor_b = Vec2::zero();
+	jd.max_length = 3.0;
+	let joint = world.create_joint(&jd).as_rope_joint().unwrap();
+
+	assert_eq!(world.get_joint_count(), 1);
+	assert_eq!(joint.get_max_length(), 3.0);
+
+	world.step(1.0 / 60.0, 8, 3);
+	assert_eq!(joint.get_limit_state(), LimitState::AtUpperLimit);
+
+	for _ in 0..120 {
+		world.step(1.0 / 60.0, 8, 3);
+	}
+	assert!(body.get_position().length() <= 3.0 + 0.1);
+
+	joint.set_max_length(5.0);
+	assert_eq!(joint.get_max_length(), 5.0);
+}
+
+#[test]
+fn create_mouse_joint() {
+
+	let mut world = World::new(&Vec2::zero());
+
+	let ground = world.create_body(&BodyDef::default());
+	let body = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, ..BodyDef::default() });
+
+	let mut shape = PolygonShape::new();
+	shape.set_as_box(0.5, 0.5);
+	body.create_fixture_from_shape(&shape, 1.0);
+
+	let mut jd = mouse_joint::MouseJointDef::new();
+	jd.body_a = Some(ground);
+	jd.body_b = Some(body.clone());
+	jd.target = Vec2::zero();
+	jd.max_force = 1000.0;
+	let joint = world.create_joint(&jd).as_mouse_joint().unwrap();
+
+	assert_eq!(world.get_joint_count(), 1);
+	assert_eq!(joint.get_frequency(), 5.0);
+	assert_eq!(joint.get_damping_ratio(), 0.7);
+
+	joint.set_target(&Vec2::new(2.0, 1.0));
+	assert_eq!(joint.get_target(), Vec2::new(2.0, 1.0));
+	joint.set_max_force(500.0);
+	assert_eq!(joint.get_max_force(), 500.0);
+
+	for _ in 0..180 {
+		world.step(1.0 / 60.0, 8, 3);
+	}
+
+	assert!(body.get_position().distance(&Vec2::new(2.0, 1.0)) < 0.1);
+}
+
+#[test]
+fn create_wheel_joint() {
+
+	let mut world = World::default();
+
+	let chassis = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, position: Vec2::new(0.0, 1.0), ..BodyDef::default() });
+	let wheel = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, ..BodyDef::default() });
+
+	let mut shape = PolygonShape::new();
+	shape.set_as_box(0.5, 0.5);
+	chassis.create_fixture_from_shape(&shape, 1.0);
+	wheel.create_fixture_from_shape(&shape, 1.0);
+
+	let mut jd = wheel_joint::WheelJointDef::new();
+	jd.initialize(chassis, wheel, &Vec2::zero(), &Vec2::new(0.0, 1.0));
+	jd.enable_motor = true;
+	jd.max_motor_torque = 10.0;
+	jd.motor_speed = -2.0;
+	let joint = world.create_joint(&jd).as_wheel_joint().unwrap();
+
+	assert_eq!(world.get_joint_count(), 1);
+	assert_eq!(joint.get_local_axis_a(), Vec2::new(0.0, 1.0));
+	assert_eq!(joint.get_local_anchor_a(), Vec2::new(0.0, -1.0));
+	assert_eq!(joint.get_spring_frequency_hz(), 2.0);
+	assert_eq!(joint.get_spring_damping_ratio(), 0.7);
+	assert!(joint.is_motor_enabled());
+	assert_eq!(joint.get_motor_speed(), -2.0);
+
+	joint.set_spring_frequency_hz(4.0);
+	joint.set_spring_damping_ratio(1.0);
+	joint.set_max_motor_torque(20.0);
+	assert_eq!(joint.get_spring_frequency_hz(), 4.0);
+	assert_eq!(joint.get_spring_damping_ratio(), 1.0);
+	assert_eq!(joint.get_max_motor_torque(), 20.0);
+
+	assert_eq!(joint.get_joint_translation(), 0.0);
+	world.step(1.0 / 60.0, 8, 3);
+	assert!(joint.get_motor_torque(60.0).abs() <= 20.0 + 0.01);
+}
+
+#[test]
+fn create_weld_joint() {
+
+	let mut world = World::default();
+
+	let ground = world.create_body(&BodyDef::default());
+	let body = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, position: Vec2::new(1.0, 0.0), ..BodyDef::default() });
+
+	let mut shape = PolygonShape::new();
+	shape.set_as_box(0.5, 0.5);
+	body.create_fixture_from_shape(&shape, 1.0);
+
+	let mut jd = weld_joint::WeldJointDef::new();
+	jd.initialize(ground, body.clone(), &Vec2::new(0.5, 0.0));
+	let joint = world.create_joint(&jd).as_weld_joint().unwrap();
+
+	assert_eq!(world.get_joint_count(), 1);
+	assert_eq!(joint.get_local_anchor_b(), Vec2::new(-0.5, 0.0));
+	assert_eq!(joint.get_reference_angle(), 0.0);
+
+	joint.set_frequency(5.0);
+	joint.set_damping_ratio(0.7);
+	assert_eq!(joint.get_frequency(), 5.0);
+	assert_eq!(joint.get_damping_ratio(), 0.7);
+
+	for _ in 0..60 {
+		world.step(1.0 / 60.0, 8, 3);
+	}
+
+	assert!(body.get_position().distance(&Vec2::new(1.0, 0.0)) < 0.1);
+}
+
+#[test]
+fn create_friction_joint() {
+
+	let mut world = World::new(&Vec2::zero());
+
+	let ground = world.create_body(&BodyDef::default());
+	let body = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, linear_velocity: Vec2::new(1.0, 0.0), ..BodyDef::default() });
+
+	let mut shape = PolygonShape::new();
+	shape.set_as_box(0.5, 0.5);
+	body.create_fixture_from_shape(&shape, 1.0);
+
+	let mut jd = friction_joint::FrictionJointDef::new();
+	jd.initialize(ground, body.clone(), &Vec2::zero());
+	jd.max_force = 10.0;
+	jd.max_torque = 10.0;
+	let joint = world.create_joint(&jd).as_friction_joint().unwrap();
+
+	assert_eq!(world.get_joint_count(), 1);
+	assert_eq!(joint.get_max_force(), 10.0);
+
+	joint.set_max_force(20.0);
+	joint.set_max_torque(5.0);
+	assert_eq!(joint.get_max_force(), 20.0);
+	assert_eq!(joint.get_max_torque(), 5.0);
+
+	for _ in 0..60 {
+		world.step(1.0 / 60.0, 8, 3);
+	}
+
+	assert!(body.get_linear_velocity().length() < 0.01);
+}
+
+#[test]
+fn create_motor_joint() {
+
+	let mut world = World::new(&Vec2::zero());
+
+	let ground = world.create_body(&BodyDef::default());
+	let body = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, position: Vec2::new(0.0, 2.0), ..BodyDef::default() });
+
+	let mut shape = PolygonShape::new();
+	shape.set_as_box(0.5, 0.5);
+	body.create_fixture_from_shape(&shape, 1.0);
+
+	let mut jd = motor_joint::MotorJointDef::new();
+	jd.initialize(ground, body.clone());
+	jd.max_force = 1000.0;
+	jd.max_torque = 1000.0;
+	let joint = world.create_joint(&jd).as_motor_joint().unwrap();
+
+	assert_eq!(world.get_joint_count(), 1);
+	assert_eq!(joint.get_linear_offset(), Vec2::new(0.0, 2.0));
+	assert_eq!(joint.get_correction_factor(), 0.3);
+
+	joint.set_linear_offset(&Vec2::new(3.0, 2.0));
+	joint.set_angular_offset(1.0);
+	joint.set_correction_factor(0.5);
+	assert_eq!(joint.get_linear_offset(), Vec2::new(3.0, 2.0));
+	assert_eq!(joint.get_angular_offset(), 1.0);
+	assert_eq!(joint.get_correction_factor(), 0.5);
+	assert_eq!(joint.get_max_force(), 1000.0);
+	assert_eq!(joint.get_max_torque(), 1000.0);
+
+	for _ in 0..180 {
+		world.step(1.0 / 60.0, 8, 3);
+	}
+
+	assert!(body.get_position().distance(&Vec2::new(3.0, 2.0)) < 0.1);
+	assert!((body.get_angle() - 1.0).abs() < 0.1);
+}
+
+#[test]
+fn create_pulley_joint() {
+
+	let mut world = World::default();
+
+	let mut shape = PolygonShape::new();
+	shape.set_as_box(0.5, 0.5);
+
+	let body_a = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, position: Vec2::new(-2.0, 5.0), ..BodyDef::default() });
+	let body_b = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, position: Vec2::new(2.0, 5.0), ..BodyDef::default() });
+	body_a.create_fixture_from_shape(&shape, 2.0);
+	body_b.create_fixture_from_shape(&shape, 1.0);
+
+	let mut jd = pulley_joint::PulleyJointDef::new();
+	jd.initialize(body_a.clone(), body_b.clone(),
+		&Vec2::new(-2.0, 10.0), &Vec2::new(2.0, 10.0),
+		&Vec2::new(-2.0, 5.0), &Vec2::new(2.0, 5.0),
+		1.5);
+	let joint = world.create_joint(&jd).as_pulley_joint().unwrap();
+
+	assert_eq!(world.get_joint_count(), 1);
+	assert_eq!(joint.get_ground_anchor_a(), Vec2::new(-2.0, 10.0));
+	assert_eq!(joint.get_ground_anchor_b(), Vec2::new(2.0, 10.0));
+	assert_eq!(joint.get_length_a(), 5.0);
+	assert_eq!(joint.get_length_b(), 5.0);
+	assert_eq!(joint.get_ratio(), 1.5);
+
+	for _ in 0..30 {
+		world.step(1.0 / 60.0, 8, 3);
+	}
+
+	// the heavier body pulls the lighter one up
+	assert!(joint.get_current_length_a() > 5.0);
+	assert!(joint.get_current_length_b() < 5.0);
+	let constant = joint.get_current_length_a() + 1.5 * joint.get_current_length_b();
+	assert!((constant - 12.5).abs() < 0.05);
+}
+
+#[test]
+fn create_gear_joint() {
+
+	let mut world = World::default();
+
+	let ground = world.create_body(&BodyDef::default());
+
+	let mut shape = PolygonShape::new();
+	shape.set_as_box(0.5, 0.5);
+
+	let wheel = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, position: Vec2::new(0.0, 5.0), ..BodyDef::default() });
+	wheel.create_fixture_from_shape(&shape, 1.0);
+	let rack = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, position: Vec2::new(2.0, 5.0), ..BodyDef::default() });
+	rack.create_fixture_from_shape(&shape, 1.0);
+
+	let mut rd = revolute_joint::RevoluteJointDef::new();
+	rd.initialize(ground.clone(), wheel.clone(), &Vec2::new(0.0, 5.0));
+	rd.enable_motor = true;
+	rd.motor_speed = 1.0;
+	rd.max_motor_torque = 1000.0;
+	let revolute = world.create_joint(&rd).as_revolute_joint().unwrap();
+
+	let mut pd = prismatic_joint::PrismaticJointDef::new();
+	pd.initialize(ground, rack.clone(), &Vec2::new(2.0, 5.0), &Vec2::new(0.0, 1.0));
+	let prismatic = world.create_joint(&pd).as_prismatic_joint().unwrap();
+
+	let mut jd = gear_joint::GearJointDef::new();
+	jd.body_a = Some(wheel);
+	jd.body_b = Some(rack);
+	jd.joint1 = Some(revolute.clone().into());
+	jd.joint2 = Some(Joint::from(prismatic.clone()));
+	jd.ratio = 2.0;
+	let joint = world.create_joint(&jd).as_gear_joint().unwrap();
+
+	assert_eq!(world.get_joint_count(), 3);
+	assert_eq!(joint.get_ratio(), 2.0);
+	assert_eq!(joint.get_joint1().ptr, Joint::from(revolute.clone()).ptr);
+	assert_eq!(joint.get_joint2().ptr, Joint::from(prismatic.clone()).ptr);
+
+	joint.set_ratio(-1.0);
+	assert_eq!(joint.get_ratio(), -1.0);
+
+	for _ in 0..30 {
+		world.step(1.0 / 60.0, 8, 3);
+	}
+
+	// angle + ratio * translation stays constant
+	let angle = rd.body_b.as_ref().unwrap().get_angle();
+	assert!(angle > 0.1);
+	assert!((angle - prismatic.get_joint_translation()).abs() < 0.05);
+}
+
+#[test]
+#[should_panic(expected = "gear joint joint1 is a WeldJoint")]
+fn gear_joint_requires_revolute_or_prismatic_joints() {
+
+	let mut world = World::default();
+
+	let ground = world.create_body(&BodyDef::default());
+	let body = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, position: Vec2::new(1.0, 0.0), ..BodyDef::default() });
+
+	let mut wd = weld_joint::WeldJointDef::new();
+	wd.initialize(ground.clone(), body.clone(), &Vec2::new(0.5, 0.0));
+	let weld = world.create_joint(&wd);
+
+	let mut jd = gear_joint::GearJointDef::new();
+	jd.body_a = Some(ground);
+	jd.body_b = Some(body);
+	jd.joint1 = Some(weld.clone());
+	jd.joint2 = Some(weld);
+	world.create_joint(&jd);
+}
+
+#[test]
+#[should_panic(expected = "joint requires body_a and body_b")]
+fn joint_requires_bodies() {
+
+	let mut world = World::default();
+	world.create_joint(&revolute_joint::RevoluteJointDef::default());
+}
+
+#[test]
+#[should_panic(expected = "joint requires two different bodies")]
+fn joint_requires_different_bodies() {
+
+	let mut world = World::default();
+	let body = world.create_body(&BodyDef::default());
+
+	let mut jd = revolute_joint::RevoluteJointDef::new();
+	jd.body_a = Some(body.clone());
+	jd.body_b = Some(body);
+	world.create_joint(&jd);
+}
+
+#[test]
+fn generic_joint() {
+
+	let mut world = World::default();
+
+	let ground = world.create_body(&BodyDef::default());
+	let body = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, position: Vec2::new(0.0, -2.0), ..BodyDef::default() });
+
+	let mut shape = PolygonShape::new();
+	shape.set_as_box(0.5, 0.5);
+	body.create_fixture_from_shape(&shape, 1.0);
+
+	let mut jd = distance_joint::DistanceJointDef::new();
+	jd.user_data = 42;
+	jd.collide_connected = true;
+	jd.initialize(ground.clone(), body.clone(), &Vec2::new(0.0, 0.0), &Vec2::new(0.0, -2.0));
+	let joint = world.create_joint(&jd);
+
+	assert_eq!(joint.get_type(), JointType::DistanceJoint);
+	assert_eq!(joint.get_body_a().ptr, ground.ptr);
+	assert_eq!(joint.get_body_b().ptr, body.ptr);
+	assert_eq!(joint.get_anchor_a(), Vec2::new(0.0, 0.0));
+	assert_eq!(joint.get_anchor_b(), Vec2::new(0.0, -2.0));
+	assert_eq!(joint.get_user_data(), 42);
+	assert!(joint.get_collide_connected());
+	assert!(joint.is_active());
+	assert!(joint.get_next().is_none());
+
+	assert!(joint.as_revolute_joint().is_none());
+	assert_eq!(joint.as_distance_joint().unwrap().get_length(), 2.0);
+
+	world.step(1.0 / 60.0, 8, 3);
+
+	// the joint holds the body against gravity
+	let force = joint.get_reaction_force(60.0);
+	assert!(force.y > 0.0);
+	assert_eq!(joint.get_reaction_torque(60.0), 0.0);
+}
+
+#[test]
+fn joint_iterators() {
+
+	let mut world = World::default();
+
+	let ground = world.create_body(&BodyDef::default());
+	let body_a = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, ..BodyDef::default() });
+	let body_b = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, ..BodyDef::default() });
+
+	assert_eq!(world.joints().count(), 0);
+	assert_eq!(ground.joint_edges().count(), 0);
+
+	let mut rd = revolute_joint::RevoluteJointDef::new();
+	rd.initialize(ground.clone(), body_a.clone(), &Vec2::zero());
+	world.create_joint(&rd);
+
+	let mut wd = weld_joint::WeldJointDef::new();
+	wd.initialize(ground.clone(), body_b.clone(), &Vec2::zero());
+	world.create_joint(&wd);
+
+	let mut dd = distance_joint::DistanceJointDef::new();
+	dd.initialize(body_a.clone(), body_b.clone(), &Vec2::zero(), &Vec2::new(1.0, 0.0));
+	world.create_joint(&dd);
+
+	let mut types: Vec<JointType> = world.joints().map(|j| j.get_type()).collect();
+	types.sort_by_key(|t| *t as i32);
+	assert_eq!(types, vec![JointType::RevoluteJoint, JointType::DistanceJoint, JointType::WeldJoint]);
+
+	let others: Vec<_> = ground.joint_edges().map(|e| e.get_other().ptr).collect();
+	assert_eq!(others.len(), 2);
+	assert!(others.contains(&body_a.ptr));
+	assert!(others.contains(&body_b.ptr));
+
+	for edge in body_a.joint_edges() {
+		let joint = edge.get_joint();
+		assert!(joint.get_body_a().ptr == body_a.ptr || joint.get_body_b().ptr == body_a.ptr);
+	}
+	assert_eq!(body_a.joint_edges().filter_map(|e| e.get_joint().as_revolute_joint()).count(), 1);
+}
+
+#[test]
+fn revolute_joint_runtime() {
+
+	let mut world = World::new(&Vec2::zero());
+
+	let ground = world.create_body(&BodyDef::default());
+	let body = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, position: Vec2::new(1.0, 0.0), ..BodyDef::default() });
+
+	let mut shape = PolygonShape::new();
+	shape.set_as_box(0.5, 0.5);
+	body.create_fixture_from_shape(&shape, 1.0);
+
+	let mut jd = revolute_joint::RevoluteJointDef::new();
+	jd.initialize(ground, body.clone(), &Vec2::zero());
+	let joint = world.create_joint(&jd).as_revolute_joint().unwrap();
+
+	assert_eq!(joint.get_local_anchor_a(), Vec2::zero());
+	assert_eq!(joint.get_local_anchor_b(), Vec2::new(-1.0, 0.0));
+	assert_eq!(joint.get_reference_angle(), 0.0);
+	assert_eq!(joint.get_joint_angle(), 0.0);
+	assert!(!joint.is_limit_enabled());
+	assert!(!joint.is_motor_enabled());
+
+	joint.enable_limit(true);
+	joint.set_limits(-0.25, 0.5);
+	assert!(joint.is_limit_enabled());
+	assert_eq!(joint.get_lower_limit(), -0.25);
+	assert_eq!(joint.get_upper_limit(), 0.5);
+
+	joint.enable_motor(true);
+	joint.set_motor_speed(2.0);
+	joint.set_max_motor_torque(100.0);
+	assert!(joint.is_motor_enabled());
+	assert_eq!(joint.get_motor_speed(), 2.0);
+	assert_eq!(joint.get_max_motor_torque(), 100.0);
+
+	world.step(1.0 / 60.0, 8, 3);
+	assert!(joint.get_joint_speed() > 0.0);
+
+	for _ in 0..120 {
+		world.step(1.0 / 60.0, 8, 3);
+	}
+
+	// the motor drives the body into the upper limit
+	assert!((joint.get_joint_angle() - 0.5).abs() < 0.05);
+	assert!(joint.get_motor_torque(60.0) > 0.0);
+	assert!(joint.get_motor_torque(60.0) <= 100.0 + 0.01);
+}
+
+#[test]
+fn breakable_joints() {
+
+	let mut world = World::default();
+
+	let ground = world.create_body(&BodyDef::default());
+	let mut shape = PolygonShape::new();
+	shape.set_as_box(0.5, 0.5);
+
+	let mut welds = Vec::new();
+	for &x in [1.0, 3.0].iter() {
+		let body = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, position: Vec2::new(x, 0.0), ..BodyDef::default() });
+		body.create_fixture_from_shape(&shape, 1.0);
+		let mut jd = weld_joint::WeldJointDef::new();
+		jd.initialize(ground.clone(), body, &Vec2::new(x - 0.5, 0.0));
+		welds.push(world.create_joint(&jd).as_weld_joint().unwrap());
+	}
+
+	// the bodies weigh about 10 N each
+	let mut breakable = BreakableJoints::new();
+	breakable.register_force(welds[0].clone(), 1.0);
+	breakable.register(welds[1].clone(), 1000.0, 1000.0);
+	assert_eq!(breakable.len(), 2);
+
+	let broken = breakable.step(&mut world, 1.0 / 60.0, 8, 3);
+	assert_eq!(broken.len(), 1);
+	assert_eq!(broken[0].joint_type, JointType::WeldJoint);
+	assert!(broken[0].reaction_force.length() > 1.0);
+	assert_eq!(world.get_joint_count(), 1);
+	assert_eq!(breakable.len(), 1);
+
+	for _ in 0..60 {
+		assert!(breakable.step(&mut world, 1.0 / 60.0, 8, 3).is_empty());
+	}
+	assert_eq!(world.get_joint_count(), 1);
+
+	// joints destroyed elsewhere are forgotten
+	world.destroy_joint(welds[1].clone().into());
+	breakable.step(&mut world, 1.0 / 60.0, 8, 3);
+	assert!(breakable.is_empty());
+}
+
+#[test]
+fn breaking_joint_destroys_gear_joints() {
+
+	let mut world = World::default();
+
+	let ground = world.create_body(&BodyDef::default());
+	let mut shape = PolygonShape::new();
+	shape.set_as_box(0.5, 0.5);
+
+	let wheel = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, position: Vec2::new(0.0, 5.0), ..BodyDef::default() });
+	wheel.create_fixture_from_shape(&shape, 1.0);
+	let rack = world.create_body(&BodyDef { body_type: BodyType::DynamicBody, position: Vec2::new(2.0, 5.0), ..BodyDef::default() });
+	rack.create_fixture_from_shape(&shape, 1.0);
+
+	let mut rd = revolute_joint::RevoluteJointDef::new();
+	rd.initialize(ground.clone(), wheel.clone(), &Vec2::new(0.0, 5.0));
+	let revolute = world.create_joint(&rd);
+
+	let mut pd = prismatic_joint::PrismaticJointDef::new();
+	pd.initialize(ground, rack.clone(), &Vec2::new(2.0, 5.0), &Vec2::new(0.0, 1.0));
+	let prismatic = world.create_joint(&pd);
+
+	let mut jd = gear_joint::GearJointDef::new();
+	jd.body_a = Some(wheel);
+	jd.body_b = Some(rack);
+	jd.joint1 = Some(revolute.clone());
+	jd.joint2 = Some(prismatic.clone());
+	let gear = world.create_joint(&jd);
+
+	// the wheel weighs about 10 N
+	let mut breakable = BreakableJoints::new();
+	breakable.register_force(revolute, 1.0);
+	breakable.register_force(gear, MAX_FLOAT);
+
+	let broken = breakable.step(&mut world, 1.0 / 60.0, 8, 3);
+	assert_eq!(broken.len(), 2);
+	assert_eq!(broken[0].joint_type, JointType::GearJoint);
+	assert_eq!(broken[1].joint_type, JointType::RevoluteJoint);
+	assert_eq!(world.get_joint_count(), 1);
+	assert_eq!(world.joints().next().unwrap().ptr, prismatic.ptr);
+	assert!(breakable.is_empty());
+}
@@ -0,0 +1,147 @@
+extern crate liquidfun;
+
+use liquidfun::box2d::collision::*;
+use liquidfun::box2d::common::math::*;
+use liquidfun::box2d::dynamics::body::*;
+use liquidfun::box2d::dynamics::world::*;
+
+fn assert_close(a: f32, b: f32) {
+	assert!((a - b).abs() < 1e-5, "{} != {}", a, b);
+}
+
+#[test]
+fn mat22_inverse_and_solve() {
+
+	let m = Mat22::from_scalars(4.0, 3.0, 6.0, 3.0);
+	let b = Vec2::new(1.0, 2.0);
+
+	let x = m.solve(&b);
+	assert_eq!(m.mul_vec2(&x), b);
+	assert_eq!(m.mul(&m.get_inverse()), Mat22::identity());
+}
+
+#[test]
+fn mat33_solve() {
+
+	let m = Mat33::new(Vec3::new(2.0, 0.0, 1.0), Vec3::new(0.0, 3.0, 0.0), Vec3::new(1.0, 0.0, 2.0));
+	let b = Vec3::new(1.0, 2.0, 3.0);
+
+	let x = m.solve33(&b);
+	let y = m.mul_vec3(&x);
+	assert_close(y.x, b.x);
+	assert_close(y.y, b.y);
+	assert_close(y.z, b.z);
+
+	let x = m.solve22(&Vec2::new(1.0, 2.0));
+	assert_eq!(m.mul22(&x), Vec2::new(1.0, 2.0));
+}
+
+#[test]
+fn transform_round_trip() {
+
+	let xf = Transform::new(Vec2::new(1.0, 2.0), Rot::new(0.5));
+	let v = Vec2::new(3.0, -4.0);
+
+	let w = xf.mul_t_vec2(&xf.mul_vec2(&v));
+	assert_close(w.x, v.x);
+	assert_close(w.y, v.y);
+	assert_close(xf.q.get_angle(), 0.5);
+
+	let identity = xf.mul_t(&xf);
+	assert_close(identity.p.x, 0.0);
+	assert_close(identity.p.y, 0.0);
+	assert_close(identity.q.get_angle(), 0.0);
+}
+
+#[test]
+fn sweep_get_transform() {
+
+	let sweep = Sweep { c0: Vec2::new(0.0, 0.0), c: Vec2::new(2.0, 0.0), a0: 0.0, a: 1.0, ..Sweep::default() };
+	let xf = sweep.get_transform(0.5);
+	assert_close(xf.p.x, 1.0);
+	assert_close(xf.q.get_angle(), 0.5);
+}
+
+#[test]
+fn aabb_combine_contains_and_ray_cast() {
+
+	let mut a = AABB::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0));
+	let b = AABB::new(Vec2::new(2.0, 2.0), Vec2::new(3.0, 3.0));
+	assert!(!a.contains(&b));
+
+	a.combine(&b);
+	assert!(a.is_valid());
+	assert!(a.contains(&b));
+	assert_eq!(a.get_center(), Vec2::new(1.5, 1.5));
+
+	let input = RayCastInput { p1: Vec2::new(-1.0, 1.0), p2: Vec2::new(5.0, 1.0), max_fraction: 1.0 };
+	let output = a.ray_cast(&input).unwrap();
+	assert_eq!(output.normal, Vec2::new(-1.0, 0.0));
+	assert_close(output.fraction, 1.0 / 6.0);
+
+	let miss = RayCastInput { p1: Vec2::new(-1.0, 5.0), p2: Vec2::new(5.0, 5.0), max_fraction: 1.0 };
+	assert!(a.ray_cast(&miss).is_none());
+}
+
+#[test]
+fn body_get_transform() {
+
+	let mut world = World::default();
+	let mut body_def = BodyDef::default();
+	body_def.position.set(1.0, 2.0);
+	body_def.angle = 0.25;
+	let body = world.create_body(&body_def);
+
+	let xf = body.get_transform();
+	assert_eq!(xf.p, Vec2::new(1.0, 2.0));
+	assert_close(xf.q.get_angle(), 0.25);
+}
+
+#[test]
+fn vec2_operators() {
+
+	let a = Vec2::new(1.0, 2.0);
+	let b = Vec2::from((3.0, 4.0));
+
+	assert_eq!(a + b, Vec2::new(4.0, 6.0));
+	assert_eq!(b - a, Vec2::new(2.0, 2.0));
+	assert_eq!(-a, Vec2::new(-1.0, -2.0));
+	assert_eq!(a * 2.0, 2.0 * a);
+	assert_eq!(b / 2.0, Vec2::new(1.5, 2.0));
+
+	let mut c = a;
+	c += b;
+	c -= a;
+	c *= 2.0;
+	c /= 4.0;
+	assert_eq!(c, Vec2::from([1.5, 2.0]));
+
+	let t: (f32, f32) = c.into();
+	assert_eq!(t, (1.5, 2.0));
+}
+
+#[test]
+fn vec2_methods() {
+
+	let mut v = Vec2::new(3.0, 4.0);
+	assert_eq!(v.length(), 5.0);
+	assert_eq!(v.length_squared(), 25.0);
+	assert_eq!(v.normalize(), 5.0);
+	assert_close(v.length(), 1.0);
+
+	let a = Vec2::new(1.0, 0.0);
+	let b = Vec2::new(0.0, 1.0);
+	assert_eq!(a.dot(&b), 0.0);
+	assert_eq!(a.cross(&b), 1.0);
+	assert_eq!(a.cross_scalar(2.0), Vec2::new(0.0, -2.0));
+	assert_eq!(Vec2::scalar_cross(2.0, &a), Vec2::new(0.0, 2.0));
+	assert_eq!(a.skew().dot(&b), a.cross(&b));
+	assert_eq!(a.distance(&Vec2::new(4.0, 4.0)), 5.0);
+	assert_eq!(a.lerp(&b, 0.5), Vec2::new(0.5, 0.5));
+	assert!(a.is_valid());
+	assert!(!Vec2::new(::std::f32::NAN, 0.0).is_valid());
+
+	let r = a.rotate(::std::f32::consts::PI / 2.0);
+	assert_close(r.x, 0.0);
+	assert_close(r.y, 1.0);
+}
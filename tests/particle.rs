@@ -1,9 +1,14 @@
 extern crate liquidfun;
 
+use liquidfun::box2d::collision::shapes::polygon_shape::*;
+use liquidfun::box2d::collision::shapes::shape::*;
+use liquidfun::box2d::dynamics::body::*;
 use liquidfun::box2d::dynamics::world;
 use liquidfun::box2d::particle::*;
+use liquidfun::box2d::particle::particle_group::*;
 use liquidfun::box2d::particle::particle_system::*;
 use liquidfun::box2d::common::math::*;
+use liquidfun::ext::particle::emitter::*;
 
 #[test]
 fn create_a_zero_particle_color() {
@@ -13,26 +18,26 @@ fn create_a_zero_particle_color() {
 
 #[test]
 fn create_and_destroy_a_particle() {
-	let world = world::World::default();
+	let mut world = world::World::default();
 	let particle_system_def = ParticleSystemDef::default();
 	let particle_system = world.create_particle_system(&particle_system_def);
 
 	let pd = ParticleDef::default();
 	assert_eq!(particle_system.get_particle_count(), 0);
-	let temp_index = particle_system.create_particle(&pd);
+	let temp_index = particle_system.create_particle(&mut world, &pd);
 	assert_eq!(particle_system.get_particle_count(), 1);
-	particle_system.destroy_particle(temp_index);
+	particle_system.destroy_particle(&mut world, temp_index);
 }
 
 #[test]
 fn set_and_get_particle_flags() {
-   let world = world::World::default();
+   let mut world = world::World::default();
    let particle_system_def = ParticleSystemDef::default();
    let particle_system = world.create_particle_system(&particle_system_def);
 
    let mut pd = ParticleDef::default();
    pd.flags = ZOMBIE_PARTICLE;
-   let particle_index = particle_system.create_particle(&pd);
+   let particle_index = particle_system.create_particle(&mut world, &pd);
    let flags = particle_system.get_particle_flags(particle_index);
    assert!(flags.is_some());
    assert_eq!(flags.unwrap(), ZOMBIE_PARTICLE);
@@ -40,14 +45,482 @@ fn set_and_get_particle_flags() {
 
 #[test]
 fn get_position_buffer() {
-	let world = world::World::default();
+	let mut world = world::World::default();
 	let particle_system_def = ParticleSystemDef::default();
 	let particle_system = world.create_particle_system(&particle_system_def);
 
    	let mut pd = ParticleDef::default();
    	pd.position.set(6.0, 6.0);
-   	particle_system.create_particle(&pd);
-   	let position_buffer = particle_system.get_position_buffer();
+   	particle_system.create_particle(&mut world, &pd);
+   	let position_buffer = particle_system.get_position_buffer(&world);
       assert_eq!(position_buffer.len(), 1);
    	assert_eq!(position_buffer[0], Vec2 { x: 6.0, y: 6.0});
-}
\ No newline at end of file
+}
+#[test]
+fn create_particle_group_from_shape() {
+	let mut world = world::World::default();
+	let particle_system_def = ParticleSystemDef { radius: 0.1, ..ParticleSystemDef::default() };
+	let particle_system = world.create_particle_system(&particle_system_def);
+
+	let mut shape = PolygonShape::new();
+	shape.set_as_box(1.0, 0.5);
+	let mut gd = ParticleGroupDef::new(&shape);
+	gd.position.set(0.0, 5.0);
+	particle_system.create_particle_group(&mut world, &gd);
+
+	let count = particle_system.get_particle_count();
+	assert!(count > 0);
+	for p in particle_system.get_position_buffer(&world) {
+		assert!(p.x.abs() <= 1.0 && (p.y - 5.0).abs() <= 0.5);
+	}
+
+	// particles are added to an existing group
+	gd.shape = Some(ShapeDef::Circle { radius: 0.5, position: Vec2::zero() });
+	gd.group = Some(particle_system.create_particle_group(&mut world, &ParticleGroupDef::default()));
+	particle_system.create_particle_group(&mut world, &gd);
+	assert!(particle_system.get_particle_count() > count);
+}
+
+#[test]
+fn create_particle_group_from_positions() {
+	let mut world = world::World::default();
+	let particle_system_def = ParticleSystemDef::default();
+	let particle_system = world.create_particle_system(&particle_system_def);
+
+	let mut gd = ParticleGroupDef::default();
+	gd.position.set(1.0, 2.0);
+	gd.position_data = vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0)];
+	particle_system.create_particle_group(&mut world, &gd);
+
+	assert_eq!(particle_system.get_position_buffer(&world), &[Vec2::new(1.0, 2.0), Vec2::new(2.0, 2.0), Vec2::new(1.0, 3.0)]);
+}
+
+#[test]
+fn particle_group_runtime() {
+	let mut world = world::World::default();
+	let particle_system_def = ParticleSystemDef { radius: 0.1, ..ParticleSystemDef::default() };
+	let particle_system = world.create_particle_system(&particle_system_def);
+
+	let mut shape = PolygonShape::new();
+	shape.set_as_box(1.0, 1.0);
+	let mut gd = ParticleGroupDef::new(&shape);
+	gd.position.set(0.0, 5.0);
+	gd.user_data = 7;
+	let group = particle_system.create_particle_group(&mut world, &gd);
+
+	let count = group.get_particle_count();
+	assert_eq!(count, particle_system.get_particle_count());
+	assert_eq!(group.get_buffer_index(), 0);
+	assert!(group.contains_particle(0));
+	assert!(!group.contains_particle(count));
+	assert_eq!(group.get_all_particle_flags(), WATER_PARTICLE);
+	assert_eq!(group.get_user_data(), 7);
+	assert!(group.get_mass() > 0.0);
+	assert!(group.get_inertia() > 0.0);
+	assert!(group.get_center().distance(&Vec2::new(0.0, 5.0)) < 0.1);
+	assert_eq!(group.get_transform().p, Vec2::new(0.0, 5.0));
+	assert!(particle_system.get_group_buffer().iter().all(|g| g.as_ref().map(|g| g.ptr()) == Some(group.ptr())));
+
+	// the group statistics are refreshed by the next step
+	let mass = group.get_mass();
+	group.apply_linear_impulse(&mut world, &Vec2::new(mass, 0.0));
+	world.step(1.0 / 60.0, 8, 3);
+	assert!((group.get_linear_velocity().x - 1.0).abs() < 0.05);
+
+	gd.shape = None;
+	gd.position_data = vec![Vec2::zero(), Vec2::new(1.0, 0.0)];
+	let other = particle_system.create_particle_group(&mut world, &gd);
+	assert_eq!(other.get_buffer_index(), count);
+	assert_eq!(particle_system.get_particle_group_count(), 2);
+	assert_eq!(particle_system.get_particle_group_iterator().count(), 2);
+
+	other.destroy_particles(&mut world, false);
+	world.step(1.0 / 60.0, 8, 3);
+	assert_eq!(particle_system.get_particle_count(), count);
+	assert_eq!(particle_system.get_particle_group_count(), 1);
+	assert_eq!(particle_system.get_particle_group_list().unwrap().ptr(), group.ptr());
+}
+
+#[test]
+fn join_and_split_particle_groups() {
+	let mut world = world::World::default();
+	let particle_system_def = ParticleSystemDef { radius: 0.1, ..ParticleSystemDef::default() };
+	let particle_system = world.create_particle_system(&particle_system_def);
+
+	let mut gd = ParticleGroupDef::new(ShapeDef::Circle { radius: 0.5, position: Vec2::zero() });
+	gd.group_flags = SOLID_PARTICLE_GROUP;
+	let group_a = particle_system.create_particle_group(&mut world, &gd);
+	gd.position.set(5.0, 0.0);
+	gd.group_flags = PARTICLE_GROUP_CAN_BE_EMPTY;
+	let group_b = particle_system.create_particle_group(&mut world, &gd);
+	let count = particle_system.get_particle_count();
+
+	particle_system.join_particle_groups(&mut world, &group_a, group_b);
+	assert_eq!(particle_system.get_particle_group_count(), 1);
+	assert_eq!(group_a.get_particle_count(), count);
+	assert_eq!(group_a.get_group_flags(), SOLID_PARTICLE_GROUP | PARTICLE_GROUP_CAN_BE_EMPTY);
+
+	// the joined circles are far apart, so splitting separates them again
+	particle_system.split_particle_group(&mut world, &group_a);
+	assert_eq!(particle_system.get_particle_group_count(), 2);
+	world.step(1.0 / 60.0, 8, 3);
+	assert_eq!(particle_system.get_particle_group_iterator().map(|g| g.get_particle_count()).sum::<i32>(), count);
+
+	gd.position.set(-5.0, 0.0);
+	gd.group_flags = ParticleGroupFlags::empty();
+	gd.shapes = vec![ShapeDef::Circle { radius: 0.5, position: Vec2::new(0.0, 5.0) }];
+	particle_system.create_particle_group(&mut world, &gd);
+	world.step(1.0 / 60.0, 8, 3);
+	assert_eq!(particle_system.split_particle_groups(&mut world, SOLID_PARTICLE_GROUP), 0);
+	assert_eq!(particle_system.split_particle_groups(&mut world, ParticleGroupFlags::empty()), 0);
+
+	// empty groups flagged PARTICLE_GROUP_CAN_BE_EMPTY survive the step
+	for group in particle_system.get_particle_group_iterator() {
+		group.set_group_flags(PARTICLE_GROUP_CAN_BE_EMPTY);
+		group.destroy_particles(&mut world, false);
+	}
+	world.step(1.0 / 60.0, 8, 3);
+	assert_eq!(particle_system.get_particle_count(), 0);
+	assert_eq!(particle_system.get_particle_group_count(), 3);
+}
+
+#[test]
+fn particle_buffers() {
+	let mut world = world::World::default();
+	let particle_system_def = ParticleSystemDef::default();
+	let particle_system = world.create_particle_system(&particle_system_def);
+	assert!(particle_system.get_velocity_buffer(&world).is_empty());
+
+	let mut pd = ParticleDef::default();
+	pd.velocity.set(1.0, 0.0);
+	pd.color = particle_color::ParticleColor::new(255, 0, 0, 255);
+	pd.user_data = 3;
+	pd.lifetime = 2.0;
+	let first = particle_system.create_particle(&mut world, &pd);
+	pd.flags = WALL_PARTICLE;
+	pd.lifetime = 1.0;
+	let second = particle_system.create_particle(&mut world, &pd);
+
+	assert_eq!(particle_system.get_velocity_buffer(&world), &[Vec2::new(1.0, 0.0), Vec2::new(1.0, 0.0)]);
+	assert_eq!(particle_system.get_color_buffer(&world)[first as usize].rgba(), [255, 0, 0, 255]);
+	assert_eq!(particle_system.get_user_data_buffer(&world), &[3, 3]);
+	assert_eq!(particle_system.get_flags_buffer(&world), &[0, WALL_PARTICLE.bits()]);
+	assert!(particle_system.get_group_buffer().iter().all(|g| g.is_none()));
+	assert_eq!(particle_system.get_weight_buffer(&world).len(), 2);
+	assert!(particle_system.get_expiration_time_buffer(&world)[first as usize] > particle_system.get_expiration_time_buffer(&world)[second as usize]);
+	assert_eq!(particle_system.get_index_by_expiration_time_buffer(&world), &[first, second]);
+
+	particle_system.get_velocity_buffer_mut(&mut world)[first as usize].set(0.0, 2.0);
+	particle_system.get_color_buffer_mut(&mut world)[second as usize].set(0, 0, 255, 255);
+	particle_system.get_user_data_buffer_mut(&mut world)[second as usize] = 4;
+	world.step(1.0 / 60.0, 8, 3);
+	assert!(particle_system.get_velocity_buffer(&world)[first as usize].y > 1.0);
+	assert_eq!(particle_system.get_color_buffer(&world)[second as usize], particle_color::ParticleColor::new(0, 0, 255, 255));
+	assert_eq!(particle_system.get_user_data_buffer(&world), &[3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn particle_buffers_borrow_the_owning_world() {
+	let mut world = world::World::default();
+	let particle_system = world.create_particle_system(&ParticleSystemDef::default());
+	particle_system.create_particle(&mut world, &ParticleDef::default());
+
+	let body = world.create_body(&BodyDef::default());
+	let other = body.get_world();
+	particle_system.get_position_buffer(&other);
+}
+
+#[test]
+fn user_supplied_particle_buffers() {
+	let mut world = world::World::default();
+	let particle_system_def = ParticleSystemDef { max_count: 4, ..ParticleSystemDef::default() };
+	let particle_system = world.create_particle_system(&particle_system_def);
+
+	let mut pd = ParticleDef::default();
+	pd.position.set(1.0, 2.0);
+	pd.user_data = 5;
+	particle_system.create_particle(&mut world, &pd);
+
+	// existing particles are copied into the new buffers
+	particle_system.set_position_buffer(&mut world, Vec::with_capacity(4));
+	particle_system.set_user_data_buffer(&mut world, vec![0; 8]);
+	assert_eq!(particle_system.get_position_buffer(&world), &[Vec2::new(1.0, 2.0)]);
+	assert_eq!(particle_system.get_user_data_buffer(&world), &[5]);
+
+	particle_system.set_flags_buffer(&mut world, Vec::with_capacity(4));
+	particle_system.set_velocity_buffer(&mut world, Vec::with_capacity(4));
+	particle_system.set_color_buffer(&mut world, Vec::with_capacity(4));
+	for _ in 0..5 {
+		particle_system.create_particle(&mut world, &pd);
+	}
+	assert_eq!(particle_system.get_particle_count(), 4);
+	world.step(1.0 / 60.0, 8, 3);
+	assert!(particle_system.get_position_buffer(&world).iter().all(|p| p.y < 2.0));
+}
+
+#[test]
+#[should_panic]
+fn user_supplied_particle_buffers_need_the_owning_world() {
+	let mut world = world::World::default();
+	let particle_system_def = ParticleSystemDef { max_count: 4, ..ParticleSystemDef::default() };
+	let particle_system = world.create_particle_system(&particle_system_def);
+
+	let body = world.create_body(&BodyDef::default());
+	let mut other = body.get_world();
+	particle_system.set_position_buffer(&mut other, Vec::with_capacity(4));
+}
+
+#[test]
+#[should_panic]
+fn user_supplied_particle_buffer_too_small() {
+	let mut world = world::World::default();
+	let particle_system_def = ParticleSystemDef { max_count: 4, ..ParticleSystemDef::default() };
+	let particle_system = world.create_particle_system(&particle_system_def);
+	particle_system.set_position_buffer(&mut world, Vec::with_capacity(2));
+}
+
+#[test]
+fn particle_mutation() {
+	let mut world = world::World::new(&Vec2::zero());
+	let particle_system_def = ParticleSystemDef::default();
+	let particle_system = world.create_particle_system(&particle_system_def);
+
+	let pd = ParticleDef::default();
+	let first = particle_system.create_particle(&mut world, &pd);
+	let mut pd = ParticleDef::default();
+	pd.position.set(10.0, 0.0);
+	let second = particle_system.create_particle(&mut world, &pd);
+
+	particle_system.set_particle_flags(&mut world, first, WALL_PARTICLE);
+	assert_eq!(particle_system.get_particle_flags(first), Some(WALL_PARTICLE));
+	particle_system.set_particle_flags(&mut world, first, WATER_PARTICLE);
+
+	particle_system.set_particle_velocity(&mut world, first, &Vec2::new(0.0, 1.0));
+	assert_eq!(particle_system.get_velocity_buffer(&world)[first as usize], Vec2::new(0.0, 1.0));
+
+	assert!(particle_system.get_particle_lifetime(second) <= 0.0);
+	particle_system.set_particle_lifetime(&mut world, second, 2.0);
+	assert!((particle_system.get_particle_lifetime(second) - 2.0).abs() < 0.1);
+
+	// a batched impulse is spread over the total mass of the range
+	particle_system.set_particle_velocity(&mut world, first, &Vec2::zero());
+	particle_system.particle_apply_linear_impulse(&mut world, second, &Vec2::new(1.0, 0.0));
+	let vx = particle_system.get_velocity_buffer(&world)[second as usize].x;
+	assert!(vx > 0.0);
+	particle_system.apply_linear_impulse(&mut world, first, second + 1, &Vec2::new(2.0, 0.0));
+	assert_eq!(particle_system.get_velocity_buffer(&world)[first as usize].x, vx);
+
+	particle_system.particle_apply_force(&mut world, first, &Vec2::new(0.0, 1.0));
+	particle_system.apply_force(&mut world, first, second + 1, &Vec2::new(0.0, -2.0));
+	world.step(1.0 / 60.0, 8, 3);
+	let velocities = particle_system.get_velocity_buffer(&world);
+	assert!(velocities[first as usize].y.abs() < 1e-6);
+	assert!(velocities[second as usize].y < 0.0);
+}
+
+#[test]
+#[should_panic]
+fn particle_mutation_checks_the_index() {
+	let mut world = world::World::default();
+	let particle_system = world.create_particle_system(&ParticleSystemDef::default());
+	let index = particle_system.create_particle(&mut world, &ParticleDef::default());
+	particle_system.set_particle_velocity(&mut world, index + 1, &Vec2::zero());
+}
+
+#[test]
+#[should_panic]
+fn particle_mutation_checks_the_range() {
+	let mut world = world::World::default();
+	let particle_system = world.create_particle_system(&ParticleSystemDef::default());
+	let index = particle_system.create_particle(&mut world, &ParticleDef::default());
+	particle_system.apply_linear_impulse(&mut world, index, index + 2, &Vec2::new(1.0, 0.0));
+}
+
+#[test]
+fn particle_handles_follow_their_particle() {
+	let mut world = world::World::new(&Vec2::zero());
+	let particle_system_def = ParticleSystemDef::default();
+	let particle_system = world.create_particle_system(&particle_system_def);
+
+	let mut pd = ParticleDef::default();
+	for i in 0..3 {
+		pd.position.set(i as f32, 0.0);
+		pd.user_data = i;
+		particle_system.create_particle(&mut world, &pd);
+	}
+	let destroyed = particle_system.get_particle_handle_from_index(&mut world, 1);
+	let tagged = particle_system.get_particle_handle_from_index(&mut world, 2);
+	assert_eq!(destroyed.index(), Some(1));
+	assert_eq!(tagged.index(), Some(2));
+
+	// destroying a particle compacts the buffers
+	particle_system.destroy_particle(&mut world, 1);
+	world.step(1.0 / 60.0, 8, 3);
+	assert_eq!(destroyed.index(), None);
+	assert_eq!(tagged.index(), Some(1));
+	assert_eq!(particle_system.get_user_data_buffer(&world)[1], 2);
+
+	// LiquidFun reuses the handle of the destroyed particle
+	let first = particle_system.get_particle_handle_from_index(&mut world, 0);
+	assert_eq!(first.index(), Some(0));
+	assert_eq!(destroyed.index(), None);
+	assert_eq!(tagged.index(), Some(1));
+
+	drop(world);
+	assert_eq!(tagged.index(), None);
+}
+
+#[test]
+#[should_panic]
+fn particle_handles_need_a_particle() {
+	let mut world = world::World::default();
+	let particle_system = world.create_particle_system(&ParticleSystemDef::default());
+	particle_system.create_particle(&mut world, &ParticleDef::default());
+	particle_system.get_particle_handle_from_index(&mut world, 1);
+}
+
+#[test]
+fn destroy_particles_in_shape_and_by_age() {
+	let mut world = world::World::new(&Vec2::zero());
+	let particle_system_def = ParticleSystemDef { max_count: 3, ..ParticleSystemDef::default() };
+	let particle_system = world.create_particle_system(&particle_system_def);
+	assert_eq!(particle_system.get_max_particle_count(), 3);
+	assert!(particle_system.get_destruction_by_age());
+	particle_system.set_destruction_by_age(false);
+
+	let mut pd = ParticleDef::default();
+	for x in 0..3 {
+		pd.position.set(x as f32 * 5.0, 0.0);
+		particle_system.create_particle(&mut world, &pd);
+	}
+	assert_eq!(particle_system.create_particle(&mut world, &pd), -1);
+
+	// the oldest particle is recycled once the system is full
+	particle_system.set_destruction_by_age(true);
+	assert!(particle_system.get_destruction_by_age());
+	pd.position.set(20.0, 0.0);
+	particle_system.create_particle(&mut world, &pd);
+	world.step(1.0 / 60.0, 8, 3);
+	assert_eq!(particle_system.get_particle_count(), 3);
+	assert!(particle_system.get_position_buffer(&world).iter().all(|p| p.x > 0.0));
+
+	particle_system.destroy_oldest_particle(&mut world, 0, false);
+	world.step(1.0 / 60.0, 8, 3);
+	assert_eq!(particle_system.get_particle_count(), 2);
+
+	particle_system.set_max_particle_count(4);
+	assert_eq!(particle_system.get_max_particle_count(), 4);
+
+	let mut shape = PolygonShape::new();
+	shape.set_as_box(1.0, 1.0);
+	let xf = Transform::new(Vec2::new(20.0, 0.0), Rot::identity());
+	assert_eq!(particle_system.destroy_particles_in_shape(&mut world, &shape, &xf, false), 1);
+	world.step(1.0 / 60.0, 8, 3);
+	assert_eq!(particle_system.get_particle_count(), 1);
+}
+
+#[test]
+#[should_panic]
+fn destroy_oldest_particle_checks_the_index() {
+	let mut world = world::World::default();
+	let particle_system = world.create_particle_system(&ParticleSystemDef::default());
+	particle_system.create_particle(&mut world, &ParticleDef::default());
+	particle_system.destroy_oldest_particle(&mut world, 1, false);
+}
+
+#[test]
+fn emit_particles() {
+	let mut world = world::World::default();
+	let particle_system_def = ParticleSystemDef { max_count: 8, ..ParticleSystemDef::default() };
+	let particle_system = world.create_particle_system(&particle_system_def);
+	let group = particle_system.create_particle_group(&mut world, &ParticleGroupDef { group_flags: PARTICLE_GROUP_CAN_BE_EMPTY, ..ParticleGroupDef::default() });
+
+	let mut emitter = Emitter::new(&particle_system);
+	emitter.origin.set(0.0, 10.0);
+	emitter.size.set(2.0, 1.0);
+	emitter.direction.set(1.0, 0.0);
+	emitter.emit_rate = 3.0;
+	emitter.lifetime = 5.0;
+	emitter.group = Some(group.clone());
+
+	// fractional particles carry over
+	assert_eq!(emitter.emit(&mut world, 0.5).len(), 1);
+	let indices = emitter.emit(&mut world, 0.5);
+	assert_eq!(indices.len(), 2);
+	assert_eq!(particle_system.get_particle_count(), 3);
+	assert_eq!(group.get_particle_count(), 3);
+	for p in particle_system.get_position_buffer(&world) {
+		assert!(p.x.abs() <= 1.0 && (p.y - 10.0).abs() <= 0.5);
+	}
+	assert!(particle_system.get_velocity_buffer(&world).iter().all(|v| *v == Vec2::new(1.0, 0.0)));
+	assert!(particle_system.get_particle_lifetime(indices[0]) > 4.0);
+
+	// particles beyond max_count are dropped
+	particle_system.set_destruction_by_age(false);
+	assert_eq!(emitter.emit(&mut world, 10.0).len(), 5);
+	assert_eq!(particle_system.get_particle_count(), 8);
+}
+
+#[test]
+fn emit_ignores_invalid_time_steps() {
+	let mut world = world::World::default();
+	let particle_system = world.create_particle_system(&ParticleSystemDef::default());
+	let mut emitter = Emitter::new(&particle_system);
+	assert!(emitter.emit(&mut world, -1.0).is_empty());
+	assert!(emitter.emit(&mut world, ::std::f32::INFINITY).is_empty());
+	assert!(emitter.emit(&mut world, ::std::f32::NAN).is_empty());
+
+	// the ignored steps don't hold back later emits
+	assert_eq!(emitter.emit(&mut world, 1.0).len(), 1);
+	assert_eq!(particle_system.get_particle_count(), 1);
+}
+
+#[test]
+fn particle_contacts_pairs_and_triads() {
+	let mut world = world::World::default();
+	let particle_system_def = ParticleSystemDef { radius: 0.1, ..ParticleSystemDef::default() };
+	let particle_system = world.create_particle_system(&particle_system_def);
+
+	let ground = world.create_body(&BodyDef::default());
+	let mut shape = PolygonShape::new();
+	shape.set_as_box(5.0, 0.5);
+	let fixture = ground.create_fixture_from_shape(&shape, 0.0);
+
+	let mut box_shape = PolygonShape::new();
+	box_shape.set_as_box(0.5, 0.5);
+	let mut gd = ParticleGroupDef::new(&box_shape);
+	gd.flags = SPRING_PARTICLE;
+	gd.position.set(-2.0, 0.9);
+	particle_system.create_particle_group(&mut world, &gd);
+	gd.flags = ELASTIC_PARTICLE;
+	gd.position.set(2.0, 0.9);
+	particle_system.create_particle_group(&mut world, &gd);
+	world.step(1.0 / 60.0, 8, 3);
+
+	let count = particle_system.get_particle_count();
+	let contacts = particle_system.get_contacts(&world);
+	assert!(!contacts.is_empty());
+	for c in contacts {
+		assert!(c.index_a < count && c.index_b < count);
+		assert!(c.weight >= 0.0 && c.weight <= 1.0);
+		assert!((c.normal.length() - 1.0).abs() < 0.01);
+		assert!(c.get_flags().intersects(SPRING_PARTICLE | ELASTIC_PARTICLE));
+	}
+
+	let body_contacts = particle_system.get_body_contacts(&world);
+	assert!(!body_contacts.is_empty());
+	for c in body_contacts {
+		assert!(c.index < count);
+		assert_eq!(c.get_body().ptr, ground.ptr);
+		assert_eq!(c.get_fixture().ptr, fixture.ptr);
+		assert!((c.normal.length() - 1.0).abs() < 0.01);
+	}
+
+	let pairs = particle_system.get_pairs(&world);
+	assert!(!pairs.is_empty());
+	assert!(pairs.iter().all(|p| p.flags & SPRING_PARTICLE.bits() != 0 && p.distance > 0.0));
+	let triads = particle_system.get_triads(&world);
+	assert!(!triads.is_empty());
+	assert!(triads.iter().all(|t| t.flags & ELASTIC_PARTICLE.bits() != 0 && t.index_c < count));
+}
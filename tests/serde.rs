@@ -0,0 +1,116 @@
+#![cfg(feature = "serde")]
+
+extern crate liquidfun;
+extern crate serde_json;
+
+use liquidfun::box2d::collision::shapes::polygon_shape::*;
+use liquidfun::box2d::collision::shapes::shape::*;
+use liquidfun::box2d::common::math::*;
+use liquidfun::box2d::common::settings::*;
+use liquidfun::box2d::dynamics::body::*;
+use liquidfun::box2d::dynamics::fixture::*;
+use liquidfun::box2d::dynamics::joints::revolute_joint::*;
+use liquidfun::box2d::dynamics::world::*;
+use liquidfun::box2d::particle::*;
+use liquidfun::box2d::particle::particle_color::ParticleColor;
+use liquidfun::box2d::particle::particle_group::*;
+use liquidfun::box2d::particle::particle_system::*;
+
+#[test]
+fn body_def_round_trip() {
+
+	let mut body_def = BodyDef::default();
+	body_def.body_type = BodyType::DynamicBody;
+	body_def.position.set(1.0, 2.0);
+	body_def.user_data = 66;
+
+	let json = serde_json::to_string(&body_def).unwrap();
+	let parsed: BodyDef = serde_json::from_str(&json).unwrap();
+	assert_eq!(parsed.position, Vec2::new(1.0, 2.0));
+	assert_eq!(parsed.user_data, 0);
+
+	let partial: BodyDef = serde_json::from_str(r#"{ "body_type": "KinematicBody", "angle": 1.5 }"#).unwrap();
+	assert_eq!(partial.angle, 1.5);
+	assert_eq!(partial.gravity_scale, 1.0);
+}
+
+#[test]
+fn fixture_def_from_data() {
+
+	let json = r#"{
+		"shape": { "Box": { "hx": 1.0, "hy": 1.0, "center": { "x": 0.0, "y": 0.0 }, "angle": 0.0 } },
+		"density": 1.0,
+		"filter": { "group_index": -1 }
+	}"#;
+	let fixture_def: FixtureDef = serde_json::from_str(json).unwrap();
+	assert_eq!(fixture_def.filter.mask_bits, 0xFFFF);
+
+	let mut world = World::default();
+	let body = world.create_body(&BodyDef::default());
+	let fixture = body.create_fixture(&fixture_def);
+	assert_eq!(from_shape(fixture.get_shape()).get_vertex_count(), 4);
+
+	let mut polygon = PolygonShape::new();
+	polygon.set_as_box(1.0, 2.0);
+	let shape_def = ShapeDef::from(&polygon);
+	let json = serde_json::to_string(&shape_def).unwrap();
+	assert_eq!(serde_json::from_str::<ShapeDef>(&json).unwrap(), shape_def);
+
+	let json = r#"{ "Polygon": { "vertices": [{ "x": 0.0, "y": 0.0 }, { "x": 1.0, "y": 0.0 }] } }"#;
+	let shape_def: ShapeDef = serde_json::from_str(json).unwrap();
+	assert_eq!(shape_def, ShapeDef::Polygon { vertices: vec![Vec2::zero(), Vec2::new(1.0, 0.0)], radius: POLYGON_RADIUS });
+	assert!(shape_def.to_shape().is_err());
+}
+
+#[test]
+fn particle_def_flags_as_names() {
+
+	let mut pd = ParticleDef::default();
+	pd.flags = ELASTIC_PARTICLE | VISCOUS_PARTICLE;
+	pd.color = ParticleColor::new(1, 2, 3, 4);
+
+	let json = serde_json::to_string(&pd).unwrap();
+	assert!(json.contains(r#""flags":["ELASTIC_PARTICLE","VISCOUS_PARTICLE"]"#));
+	assert!(json.contains(r#""color":[1,2,3,4]"#));
+
+	let parsed: ParticleDef = serde_json::from_str(&json).unwrap();
+	assert_eq!(parsed.flags, ELASTIC_PARTICLE | VISCOUS_PARTICLE);
+
+	assert!(serde_json::from_str::<ParticleDef>(r#"{ "flags": ["LAVA_PARTICLE"] }"#).is_err());
+}
+
+#[test]
+fn revolute_joint_def_round_trip() {
+
+	let mut jd = RevoluteJointDef::new();
+	jd.collide_connected = true;
+	jd.enable_motor = true;
+	jd.motor_speed = 2.0;
+
+	let json = serde_json::to_string(&jd).unwrap();
+	let parsed: RevoluteJointDef = serde_json::from_str(&json).unwrap();
+	assert!(parsed.collide_connected);
+	assert!(parsed.body_a.is_none());
+	assert_eq!(parsed.motor_speed, 2.0);
+}
+
+#[test]
+fn particle_group_def_from_data() {
+
+	let json = r#"{
+		"flags": ["ELASTIC_PARTICLE"],
+		"group_flags": ["SOLID_PARTICLE_GROUP", "RIGID_PARTICLE_GROUP"],
+		"shape": { "Circle": { "radius": 1.0, "position": { "x": 0.0, "y": 0.0 } } },
+		"color": [255, 0, 0, 255]
+	}"#;
+	let gd: ParticleGroupDef = serde_json::from_str(json).unwrap();
+	assert_eq!(gd.flags, ELASTIC_PARTICLE);
+	assert_eq!(gd.group_flags, SOLID_PARTICLE_GROUP | RIGID_PARTICLE_GROUP);
+	assert_eq!(gd.strength, 1.0);
+	assert!(gd.group.is_none());
+
+	let mut world = World::default();
+	let particle_system = world.create_particle_system(&ParticleSystemDef { radius: 0.1, ..ParticleSystemDef::default() });
+	particle_system.create_particle_group(&mut world, &gd);
+	assert!(particle_system.get_particle_count() > 0);
+}
@@ -1,6 +1,8 @@
 extern crate liquidfun;
 
 use liquidfun::box2d::collision::shapes::chain_shape::*;
+use liquidfun::box2d::collision::shapes::polygon_shape::{self, PolygonShape};
+use liquidfun::box2d::collision::shapes::shape::*;
 use liquidfun::box2d::common::math::*;
 
 #[test]
@@ -22,4 +24,43 @@ fn create_chain() {
 	let vertices = chain.get_vertices();
 	assert_eq!(vertices.len(), 4);
 	assert_eq!(vertices, vs);
+}
+
+#[test]
+fn validate_shape_defs() {
+
+	let triangle = vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0)];
+	assert!(ShapeDef::Polygon { vertices: triangle.clone(), radius: 0.01 }.to_shape().is_ok());
+	assert!(ShapeDef::Polygon { vertices: triangle[..2].to_vec(), radius: 0.01 }.to_shape().is_err());
+	assert!(ShapeDef::Polygon { vertices: vec![Vec2::zero(); 9], radius: 0.01 }.validate().is_err());
+
+	let line = vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(2.0, 0.0)];
+	assert!(ShapeDef::Polygon { vertices: line.clone(), radius: 0.01 }.validate().is_err());
+
+	assert!(ShapeDef::Chain { vertices: line.clone() }.validate().is_ok());
+	assert!(ShapeDef::Chain { vertices: line[..1].to_vec() }.validate().is_err());
+	assert!(ShapeDef::Chain { vertices: vec![Vec2::zero(), Vec2::zero()] }.validate().is_err());
+
+	assert!(ShapeDef::Box { hx: 0.0, hy: 1.0, center: Vec2::zero(), angle: 0.0 }.validate().is_err());
+	assert!(ShapeDef::Circle { radius: ::std::f32::NAN, position: Vec2::zero() }.validate().is_err());
+}
+
+#[test]
+fn polygon_shape_def_keeps_radius() {
+
+	let mut polygon = PolygonShape::new();
+	polygon.set_as_box(1.0, 2.0);
+	polygon.set_radius(0.5);
+
+	let shape_def = ShapeDef::from(&polygon);
+	match shape_def {
+		ShapeDef::Polygon { ref vertices, radius } => {
+			assert_eq!(vertices.len(), 4);
+			assert_eq!(radius, 0.5);
+		},
+		_ => panic!("expected a polygon"),
+	}
+
+	let shape = shape_def.to_shape().unwrap();
+	assert_eq!(polygon_shape::from_shape(shape.handle()).get_radius(), 0.5);
 }
\ No newline at end of file
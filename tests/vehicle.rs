@@ -0,0 +1,62 @@
+extern crate liquidfun;
+
+use liquidfun::box2d::collision::shapes::polygon_shape::*;
+use liquidfun::box2d::common::math::*;
+use liquidfun::box2d::dynamics::body::*;
+use liquidfun::box2d::dynamics::world::World;
+use liquidfun::ext::dynamics::vehicle::*;
+
+fn create_ground(world: &mut World) {
+	let ground = world.create_body(&BodyDef::default());
+	let mut shape = PolygonShape::new();
+	shape.set_as_box_oriented(50.0, 0.5, &Vec2::new(0.0, -0.5), 0.0);
+	ground.create_fixture_from_shape(&shape, 0.0);
+}
+
+#[test]
+fn create_vehicle() {
+
+	let mut world = World::default();
+
+	let def = VehicleDef { position: Vec2::new(0.0, 1.0), drive: Drive::All, ..VehicleDef::default() };
+	let vehicle = world.create_vehicle(&def);
+
+	assert_eq!(world.get_body_count(), 3);
+	assert_eq!(world.get_joint_count(), 2);
+	assert_eq!(vehicle.wheels.len(), 2);
+	assert_eq!(*vehicle.wheels[0].get_position(), Vec2::new(-1.0, 0.75));
+	assert_eq!(*vehicle.wheels[1].get_position(), Vec2::new(1.0, 0.75));
+	assert!(vehicle.suspensions.iter().all(|s| s.is_motor_enabled()));
+	assert_eq!(vehicle.suspensions[0].get_spring_frequency_hz(), def.suspension_frequency_hz);
+
+	let rear = world.create_vehicle(&VehicleDef { position: Vec2::new(10.0, 1.0), ..VehicleDef::default() });
+	assert!(rear.suspensions[0].is_motor_enabled());
+	assert!(!rear.suspensions[1].is_motor_enabled());
+}
+
+#[test]
+fn drive_vehicle() {
+
+	let mut world = World::default();
+	create_ground(&mut world);
+
+	let vehicle = world.create_vehicle(&VehicleDef { position: Vec2::new(0.0, 1.0), ..VehicleDef::default() });
+
+	// let the suspension settle before driving
+	for _ in 0..60 {
+		world.step(1.0 / 60.0, 8, 3);
+	}
+	let start = vehicle.chassis.get_position().x;
+
+	vehicle.set_motor_speed(-20.0);
+	// only the rear wheel is driven
+	assert_eq!(vehicle.suspensions[0].get_motor_speed(), -20.0);
+	assert_eq!(vehicle.suspensions[1].get_motor_speed(), 0.0);
+	for _ in 0..120 {
+		world.step(1.0 / 60.0, 8, 3);
+	}
+
+	assert!(vehicle.chassis.get_position().x > start + 1.0);
+	// the chassis weight compresses the suspension
+	assert!(vehicle.suspensions[0].get_joint_translation() > 0.0);
+}